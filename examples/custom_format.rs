@@ -49,8 +49,10 @@ impl promptuity::prompts::SelectFormatter for SelectFormatter {
         }
     }
 
-    fn option_label(&self, label: String, active: bool) -> String {
-        if active {
+    fn option_label(&self, label: String, active: bool, disabled: bool) -> String {
+        if disabled {
+            Styled::new(label).fg(Color::DarkGrey).to_string()
+        } else if active {
             Styled::new(label)
                 .fg(Color::Magenta)
                 .underline()
@@ -90,8 +92,10 @@ impl promptuity::prompts::MultiSelectFormatter for MultiSelectFormatter {
         }
     }
 
-    fn option_label(&self, label: String, active: bool, _selected: bool) -> String {
-        if active {
+    fn option_label(&self, label: String, active: bool, _selected: bool, disabled: bool) -> String {
+        if disabled {
+            Styled::new(label).fg(Color::DarkGrey).to_string()
+        } else if active {
             Styled::new(label)
                 .fg(Color::Magenta)
                 .underline()
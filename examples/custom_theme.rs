@@ -67,20 +67,20 @@ impl SimpleTheme {
     }
 
     fn fmt_body_active(&self, body: PromptBody) -> String {
-        match body {
-            PromptBody::Raw(raw) => {
+        match body.visible_content() {
+            Some(raw) => {
                 format!("\n{}", raw)
             }
-            _ => String::new(),
+            None => String::new(),
         }
     }
 
     fn fmt_body_submit(&self, body: PromptBody) -> String {
-        match body {
-            PromptBody::Raw(raw) => {
+        match body.visible_content() {
+            Some(raw) => {
                 format!("\n{}", Styled::new(raw).fg(Color::Green))
             }
-            _ => String::new(),
+            None => String::new(),
         }
     }
 }
@@ -156,7 +156,7 @@ impl<W: std::io::Write> Theme<W> for SimpleTheme {
                 output.push_str(&self.fmt_hint(payload.hint));
                 self.prev_lines = output.lines().count() as u16;
             }
-            PromptState::Error(msg) | PromptState::Fatal(msg) => {
+            PromptState::Error(msg) | PromptState::Warning(msg) | PromptState::Fatal(msg) => {
                 output.push_str(&self.fmt_message(payload.message));
                 output.push_str(&self.fmt_input_active(payload.input, payload.placeholder));
                 output.push_str(&self.fmt_body_active(payload.body));
@@ -171,7 +171,8 @@ impl<W: std::io::Write> Theme<W> for SimpleTheme {
                 output.push_str(&self.fmt_hint(payload.hint));
                 self.prev_lines = 0;
             }
-            PromptState::Cancel => {
+            // `PromptState::Cancel`, and any state added in future versions.
+            _ => {
                 output.push_str(&self.fmt_message(payload.message));
                 self.prev_lines = 0;
             }
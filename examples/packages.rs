@@ -1,25 +1,11 @@
 use std::thread;
 use std::time::Duration;
 
-use indicatif::{ProgressBar, ProgressStyle};
 use promptuity::prompts::{Input, MultiSelect, MultiSelectOption};
 use promptuity::style::{Color, Styled};
 use promptuity::themes::FancyTheme;
 use promptuity::{Error, Promptuity, Term};
 
-fn make_progress_bar(message: &'static str, writer: impl std::io::Write) -> ProgressBar {
-    let pb = ProgressBar::new_spinner();
-    pb.enable_steady_tick(Duration::from_millis(100));
-    pb.wrap_write(writer);
-    pb.set_style(
-        ProgressStyle::with_template("{spinner:.cyan}  {msg}")
-            .unwrap()
-            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏"),
-    );
-    pb.set_message(message);
-    pb
-}
-
 fn prompt() -> Result<(), Error> {
     let mut term = Term::default();
     let mut theme = FancyTheme::default();
@@ -118,11 +104,13 @@ fn prompt() -> Result<(), Error> {
     let message =
         p.prompt(Input::new("Please enter a summary for this change").with_placeholder("Summary"))?;
 
-    let pb = make_progress_bar("Bumping versinos...", p.term().writer());
-    thread::sleep(Duration::from_secs(4));
-    pb.finish_and_clear();
+    let mut spinner = p.spinner("Bumping versions...")?;
+    for _ in 0..40 {
+        thread::sleep(Duration::from_millis(100));
+        spinner.tick()?;
+    }
+    spinner.finish("Bumped versions")?;
 
-    p.step("Bumped versions")?;
     p.log(
         Styled::new(format!(
             "update {} packages",
@@ -17,7 +17,7 @@ fn main() -> Result<(), Error> {
                 if value.chars().all(|c| c.is_alphanumeric()) {
                     Ok(())
                 } else {
-                    Err("Invalid format".into())
+                    Err("Invalid format")
                 }
             }),
     )?;
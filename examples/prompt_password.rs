@@ -14,7 +14,7 @@ fn main() -> Result<(), Error> {
             .with_hint("Please enter more than 6 alphanumeric characters.")
             .with_validator(|value: &String| {
                 if value.len() < 6 {
-                    Err("Password must be at least 6 characters long".into())
+                    Err("Password must be at least 6 characters long")
                 } else {
                     Ok(())
                 }
@@ -0,0 +1,28 @@
+use promptuity::prompts::Textarea;
+use promptuity::themes::MinimalTheme;
+use promptuity::{Error, Promptuity, Term};
+
+fn main() -> Result<(), Error> {
+    let mut term = Term::default();
+    let mut theme = MinimalTheme::default();
+    let mut p = Promptuity::new(&mut term, &mut theme);
+
+    p.term().clear()?;
+    p.begin()?;
+    let message = p.prompt(
+        Textarea::new("Please enter a commit message")
+            .with_description("The first line is used as the summary.")
+            .with_validator(|value: &String| {
+                if value.lines().next().unwrap_or_default().chars().count() <= 50 {
+                    Ok(())
+                } else {
+                    Err("The summary must be 50 characters or less.")
+                }
+            }),
+    )?;
+    p.finish()?;
+
+    println!("\nresult: {:?}", message);
+
+    Ok(())
+}
@@ -68,7 +68,7 @@ fn main() -> Result<(), Error> {
     let password = p.prompt(
         Password::new("Set a password for your account").with_validator(|value: &String| {
             if value.len() < 6 {
-                Err("Password must be at least 6 characters long".into())
+                Err("Password must be at least 6 characters long")
             } else {
                 Ok(())
             }
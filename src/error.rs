@@ -1,5 +1,8 @@
 use thiserror::Error;
 
+/// A boxed error used as the underlying cause of [`Error::Config`] and [`Error::Prompt`].
+pub type BoxedError = Box<dyn std::error::Error + Send + Sync>;
+
 /// The error type for promptuity.
 #[derive(Error, Debug)]
 pub enum Error {
@@ -9,10 +12,168 @@ pub enum Error {
     /// An error representing the cancellation of prompt execution.
     #[error("Operation canceled")]
     Cancel,
-    /// An error representing invalid configuration in a prompt.
-    #[error("Config error: {0}")]
-    Config(String),
+    /// An error representing invalid configuration in a prompt.  
+    /// `field` and `prompt` identify the source of the error when known. [`crate::Promptuity`] fills in `prompt` from [`crate::Prompt::name`] when it is missing.  
+    /// This used to be the tuple variant `Config(String)`. Create it with [`Error::config`] instead of `Error::Config(message)`, and match it with `Error::Config { message, .. }`.
+    #[error("Config error: {}{message}", fmt_context(.prompt, .field))]
+    Config {
+        /// The description of the invalid configuration.
+        message: String,
+        /// The name of the field that caused the error.
+        field: Option<String>,
+        /// The name of the prompt that caused the error.
+        prompt: Option<String>,
+        /// The underlying cause of the error, returned from [`std::error::Error::source`].
+        #[source]
+        source: Option<BoxedError>,
+    },
     /// An unknown error originating from the prompt.
-    #[error("Prompt error: {0}")]
-    Prompt(String),
+    #[error("Prompt error: {message}")]
+    Prompt {
+        /// The description of the error.
+        message: String,
+        /// The underlying cause of the error, returned from [`std::error::Error::source`].
+        #[source]
+        source: Option<BoxedError>,
+    },
+}
+
+impl Error {
+    /// Creates a new [`Error::Config`] with the given message.
+    pub fn config(message: impl Into<String>) -> Self {
+        Self::Config {
+            message: message.into(),
+            field: None,
+            prompt: None,
+            source: None,
+        }
+    }
+
+    /// Creates a new [`Error::Config`] with the given message and underlying cause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error as _;
+    /// use promptuity::Error;
+    ///
+    /// let cause = "abc".parse::<u8>().unwrap_err();
+    /// let err = Error::config_with_source("invalid default value.", cause);
+    /// assert!(err.source().is_some());
+    /// assert!(Error::config("invalid default value.").source().is_none());
+    /// ```
+    pub fn config_with_source(message: impl Into<String>, source: impl Into<BoxedError>) -> Self {
+        Self::Config {
+            message: message.into(),
+            field: None,
+            prompt: None,
+            source: Some(source.into()),
+        }
+    }
+
+    /// Creates a new [`Error::Prompt`] with the given message.
+    pub fn prompt(message: impl Into<String>) -> Self {
+        Self::Prompt {
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Creates a new [`Error::Prompt`] with the given message and underlying cause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error as _;
+    /// use promptuity::Error;
+    ///
+    /// let cause = std::io::Error::new(std::io::ErrorKind::NotFound, "history file not found");
+    /// let err = Error::prompt_with_source("failed to load history.", cause);
+    /// assert_eq!(err.source().unwrap().to_string(), "history file not found");
+    /// assert!(Error::prompt("failed to load history.").source().is_none());
+    /// ```
+    pub fn prompt_with_source(message: impl Into<String>, source: impl Into<BoxedError>) -> Self {
+        Self::Prompt {
+            message: message.into(),
+            source: Some(source.into()),
+        }
+    }
+}
+
+fn fmt_context(prompt: &Option<String>, field: &Option<String>) -> String {
+    match (prompt, field) {
+        (Some(prompt), Some(field)) => format!("{prompt}.{field}: "),
+        (Some(ctx), None) | (None, Some(ctx)) => format!("{ctx}: "),
+        (None, None) => String::new(),
+    }
+}
+
+/// The error type returned from [`crate::Validator`] and [`crate::Prompt::validate`].
+///
+/// A [`ValidationError::Error`] is displayed and the prompt stays active, while a [`ValidationError::Fatal`] finishes the prompt with [`crate::PromptState::Fatal`] and makes [`crate::Promptuity::prompt`] return [`Error::Prompt`].  
+/// Both `String` and `&str` convert into [`ValidationError::Error`], so validators can keep returning plain messages.
+///
+/// # Examples
+///
+/// ```
+/// use promptuity::prompts::Input;
+/// use promptuity::ValidationError;
+///
+/// let _ = Input::new("Project name").with_validator(|v: &String| {
+///     if v.is_empty() {
+///         Err(ValidationError::new("Please enter a name."))
+///     } else if std::path::Path::new(v).exists() {
+///         Err(ValidationError::fatal(format!("{} already exists.", v)))
+///     } else {
+///         Ok(())
+///     }
+/// });
+///
+/// assert!(ValidationError::fatal("abc").is_fatal());
+/// assert_eq!(ValidationError::from("abc"), ValidationError::new("abc"));
+/// ```
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// A recoverable error. The prompt displays the message and waits for another input.
+    #[error("{0}")]
+    Error(String),
+    /// An unrecoverable error. The prompt is finished and the message is returned as [`Error::Prompt`].
+    #[error("{0}")]
+    Fatal(String),
+}
+
+impl ValidationError {
+    /// Creates a new recoverable [`ValidationError::Error`] with the given message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self::Error(message.into())
+    }
+
+    /// Creates a new unrecoverable [`ValidationError::Fatal`] with the given message.
+    pub fn fatal(message: impl Into<String>) -> Self {
+        Self::Fatal(message.into())
+    }
+
+    /// Returns `true` if the error is [`ValidationError::Fatal`].
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, Self::Fatal(_))
+    }
+
+    /// Returns the message of the error.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Error(msg) | Self::Fatal(msg) => msg,
+        }
+    }
+}
+
+impl From<String> for ValidationError {
+    fn from(message: String) -> Self {
+        Self::Error(message)
+    }
+}
+
+impl From<&str> for ValidationError {
+    fn from(message: &str) -> Self {
+        Self::Error(message.into())
+    }
 }
@@ -22,7 +22,19 @@
 //! }
 //! ```
 
+/// [`Event`] re-exports from [`crossterm::event`].
+pub use crossterm::event::Event;
 /// [`KeyCode`] re-exports from [`crossterm::event`].
 pub use crossterm::event::KeyCode;
+/// [`KeyEvent`] re-exports from [`crossterm::event`].
+pub use crossterm::event::KeyEvent;
+/// [`KeyEventKind`] re-exports from [`crossterm::event`].
+pub use crossterm::event::KeyEventKind;
 /// [`KeyModifiers`] re-exports from [`crossterm::event`].
 pub use crossterm::event::KeyModifiers;
+/// [`MouseButton`] re-exports from [`crossterm::event`].
+pub use crossterm::event::MouseButton;
+/// [`MouseEvent`] re-exports from [`crossterm::event`].
+pub use crossterm::event::MouseEvent;
+/// [`MouseEventKind`] re-exports from [`crossterm::event`].
+pub use crossterm::event::MouseEventKind;
@@ -97,12 +97,17 @@
 //!
 //! Prompt interruptions can be handled as [`Error::Cancel`]. In the above examples, no message is displayed in the event of an interruption.
 
+#![warn(missing_docs)]
+
 pub mod event;
 pub mod pagination;
 pub mod prompts;
 pub mod style;
 pub mod themes;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
 mod error;
 mod prompt;
 mod term;
@@ -10,38 +10,32 @@
 //! ## Examples
 //!
 //! ```
-//! use promptuity::pagination::{Page, paginate};
+//! use promptuity::pagination::paginate;
 //!
 //! let page_size = 5;
 //! let items = vec![1, 2, 3, 4, 5, 6, 7, 8];
 //!
-//! assert_eq!(paginate(page_size, &items, 1), Page {
-//!     first: true,
-//!     last: false,
-//!     items: &[1, 2, 3, 4, 5],
-//!     cursor: 1,
-//!     total: 8,
-//! });
+//! let page = paginate(page_size, &items, 1);
+//! assert_eq!((page.first, page.last), (true, false));
+//! assert_eq!(page.items, &[1, 2, 3, 4, 5]);
+//! assert_eq!((page.offset, page.cursor, page.total), (0, 1, 8));
 //!
-//! assert_eq!(paginate(page_size, &items, 3), Page {
-//!     first: false,
-//!     last: false,
-//!     items: &[2, 3, 4, 5, 6],
-//!     cursor: 2,
-//!     total: 8,
-//! });
+//! let page = paginate(page_size, &items, 3);
+//! assert_eq!((page.first, page.last), (false, false));
+//! assert_eq!(page.items, &[2, 3, 4, 5, 6]);
+//! assert_eq!((page.offset, page.cursor, page.total), (1, 2, 8));
 //!
-//! assert_eq!(paginate(page_size, &items, 7), Page {
-//!     first: false,
-//!     last: true,
-//!     items: &[4, 5, 6, 7, 8],
-//!     cursor: 4,
-//!     total: 8,
-//! });
+//! let page = paginate(page_size, &items, 7);
+//! assert_eq!((page.first, page.last), (false, true));
+//! assert_eq!(page.items, &[4, 5, 6, 7, 8]);
+//! assert_eq!((page.offset, page.cursor, page.total), (3, 4, 8));
 //! ```
 
 /// A page of items.
+///
+/// Pages are created by [`paginate`]. Fields may be added in future versions, so it cannot be constructed with a struct literal outside this crate.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub struct Page<'a, T> {
     /// A flag indicating whether this is the first page.
     pub first: bool,
@@ -49,12 +43,93 @@ pub struct Page<'a, T> {
     pub last: bool,
     /// The items in this page.
     pub items: &'a [T],
+    /// The index of the first item of this page in the original items.
+    pub offset: usize,
     /// The cursor position in this page.
     pub cursor: usize,
     /// The total number of items.
     pub total: usize,
 }
 
+impl<'a, T> Page<'a, T> {
+    /// Returns the index in the original items of the item at `index` in this page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::pagination::paginate;
+    ///
+    /// let items = vec!["a", "b", "c", "d", "e", "f"];
+    /// let page = paginate(3, &items, 4);
+    ///
+    /// assert_eq!(page.items, &["d", "e", "f"]);
+    /// assert_eq!(page.global_index_of(0), 3);
+    /// ```
+    pub fn global_index_of(&self, index: usize) -> usize {
+        self.offset + index
+    }
+
+    /// Returns an iterator over the items in this page along with their index in the original items.
+    pub fn items_with_global_index(&self) -> impl Iterator<Item = (usize, &'a T)> + '_ {
+        self.items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (self.global_index_of(i), item))
+    }
+
+    /// Returns a label describing the range of this page, such as `3–8 of 40`.  
+    /// The range is 1-based and inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::pagination::paginate;
+    ///
+    /// let items = (1..=40).collect::<Vec<_>>();
+    /// let page = paginate(6, &items, 5);
+    ///
+    /// assert_eq!(page.position_label(), "3–8 of 40");
+    /// ```
+    pub fn position_label(&self) -> String {
+        let start = if self.items.is_empty() {
+            0
+        } else {
+            self.offset + 1
+        };
+        format!(
+            "{}–{} of {}",
+            start,
+            self.offset + self.items.len(),
+            self.total
+        )
+    }
+
+    /// Returns a scrollbar of `height` rows, where `true` marks the rows of the thumb.  
+    /// The thumb is sized by the proportion of items in this page, and is always at least one row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::pagination::paginate;
+    ///
+    /// let items = (1..=8).collect::<Vec<_>>();
+    /// let page = paginate(4, &items, 7);
+    ///
+    /// assert_eq!(page.scrollbar(4), vec![false, false, true, true]);
+    /// ```
+    pub fn scrollbar(&self, height: usize) -> Vec<bool> {
+        if self.total == 0 {
+            return vec![false; height];
+        }
+        let round = |n: usize| (n * 2 + self.total) / (self.total * 2);
+        let size = round(height * self.items.len()).clamp(1, height.max(1));
+        let start = round(height * self.offset).min(height.saturating_sub(size));
+        (0..height)
+            .map(|row| row >= start && row < start + size)
+            .collect()
+    }
+}
+
 /// Paginates the given items.
 pub fn paginate<T>(size: usize, items: &[T], current: usize) -> Page<'_, T> {
     let (begin, end, cursor) = if items.len() <= size {
@@ -72,6 +147,7 @@ pub fn paginate<T>(size: usize, items: &[T], current: usize) -> Page<'_, T> {
         first: begin == 0,
         last: end == items.len(),
         items: &items[begin..end],
+        offset: begin,
         cursor,
         total: items.len(),
     }
@@ -104,6 +180,7 @@ mod tests {
                     first: true,
                     last: false,
                     items: &items_1_5,
+                    offset: 0,
                     cursor: 0,
                     total: 15,
                 },
@@ -116,6 +193,7 @@ mod tests {
                     first: true,
                     last: false,
                     items: &items_1_5,
+                    offset: 0,
                     cursor: 2,
                     total: 15,
                 },
@@ -128,6 +206,7 @@ mod tests {
                     first: false,
                     last: false,
                     items: &items_4_8,
+                    offset: 3,
                     cursor: 2,
                     total: 15,
                 },
@@ -140,6 +219,7 @@ mod tests {
                     first: false,
                     last: false,
                     items: &items_9_13,
+                    offset: 8,
                     cursor: 2,
                     total: 15,
                 },
@@ -152,6 +232,7 @@ mod tests {
                     first: false,
                     last: true,
                     items: &items_11_15,
+                    offset: 10,
                     cursor: 2,
                     total: 15,
                 },
@@ -164,6 +245,7 @@ mod tests {
                     first: false,
                     last: true,
                     items: &items_11_15,
+                    offset: 10,
                     cursor: 3,
                     total: 15,
                 },
@@ -176,6 +258,7 @@ mod tests {
                     first: false,
                     last: true,
                     items: &items_11_15,
+                    offset: 10,
                     cursor: 4,
                     total: 15,
                 },
@@ -188,6 +271,7 @@ mod tests {
                     first: true,
                     last: true,
                     items: &items_1_5,
+                    offset: 0,
                     cursor: 3,
                     total: 5,
                 },
@@ -200,6 +284,7 @@ mod tests {
                     first: true,
                     last: true,
                     items: &items_1_2,
+                    offset: 0,
                     cursor: 1,
                     total: 2,
                 },
@@ -209,4 +294,58 @@ mod tests {
             assert_eq!(expected, paginate(size, &items, current));
         }
     }
+
+    #[test]
+    fn test_position_label() {
+        let items = range(1..15);
+        assert_eq!(paginate(5, &items, 0).position_label(), "1–5 of 15");
+        assert_eq!(paginate(5, &items, 7).position_label(), "6–10 of 15");
+        assert_eq!(paginate(5, &items, 14).position_label(), "11–15 of 15");
+        assert_eq!(
+            paginate(5, &Vec::<usize>::new(), 0).position_label(),
+            "0–0 of 0"
+        );
+    }
+
+    #[test]
+    fn test_scrollbar() {
+        let items = range(1..15);
+        assert_eq!(
+            paginate(5, &items, 0).scrollbar(5),
+            vec![true, true, false, false, false]
+        );
+        assert_eq!(
+            paginate(5, &items, 7).scrollbar(5),
+            vec![false, false, true, true, false]
+        );
+        assert_eq!(
+            paginate(5, &items, 14).scrollbar(5),
+            vec![false, false, false, true, true]
+        );
+        assert_eq!(paginate(5, &range(1..3), 0).scrollbar(3), vec![true; 3]);
+        assert_eq!(
+            paginate(5, &Vec::<usize>::new(), 0).scrollbar(2),
+            vec![false; 2]
+        );
+    }
+
+    #[test]
+    fn test_global_index() {
+        let items = range(1..15);
+
+        let first = paginate(5, &items, 1);
+        assert_eq!(first.offset, 0);
+        assert_eq!(first.global_index_of(first.cursor), 1);
+
+        let middle = paginate(5, &items, 7);
+        assert_eq!(middle.offset, 5);
+        assert_eq!(middle.global_index_of(middle.cursor), 7);
+
+        let last = paginate(5, &items, 14);
+        assert_eq!(last.offset, 10);
+        assert_eq!(
+            last.items_with_global_index().collect::<Vec<_>>(),
+            vec![(10, &11), (11, &12), (12, &13), (13, &14), (14, &15)]
+        );
+    }
 }
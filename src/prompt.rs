@@ -1,7 +1,31 @@
+use std::io::Write;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthChar;
 
 use crate::event::*;
-use crate::{Error, RenderSnapshot, Terminal, Theme};
+use crate::prompts::SpinnerHandle;
+use crate::{
+    Error, IntroMetadata, RawModeGuard, RenderMiddleware, RenderSnapshot, Term, Terminal, Theme,
+    ValidationError,
+};
+
+/// A struct representing a character of [`InputCursor`], returned by [`InputCursor::iter_chars`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorChar {
+    /// The character.
+    pub char: char,
+    /// The byte offset of the character in the value.
+    pub byte_offset: usize,
+    /// The index of the character in the value.
+    pub char_index: usize,
+    /// A flag indicating whether the cursor is on the character.
+    pub is_cursor: bool,
+    /// The display width of the character.
+    pub display_width: u8,
+}
 
 /// A struct to represent the input cursor.
 ///
@@ -32,7 +56,7 @@ use crate::{Error, RenderSnapshot, Terminal, Theme};
 /// assert_eq!(cursor.col(), 4);
 /// ```
 ///
-/// Supports multi-byte characters. (Does not support emojis)
+/// Supports multi-byte characters. Emojis made of multiple characters are supported in grapheme cluster mode, see [`InputCursor::with_grapheme_clusters`].
 ///
 /// ```
 /// use promptuity::InputCursor;
@@ -47,10 +71,35 @@ use crate::{Error, RenderSnapshot, Terminal, Theme};
 /// assert_eq!(cursor.cursor(), 2);
 /// assert_eq!(cursor.col(), 4);
 /// ```
-#[derive(Debug, Clone)]
+///
+/// The display width of each character can be customized with [`InputCursor::with_char_width_fn`].
+#[derive(Clone)]
 pub struct InputCursor {
     value: String,
     cursor: usize,
+    char_width_fn: Arc<dyn Fn(char) -> u8 + Send + Sync>,
+    grapheme_clusters: bool,
+    max_length: Option<usize>,
+}
+
+fn is_whitespace(segment: &str) -> bool {
+    segment.chars().next().is_some_and(char::is_whitespace)
+}
+
+// Characters whose width cannot be determined, such as control characters, take one column.
+fn default_char_width(c: char) -> u8 {
+    u8::try_from(c.width().unwrap_or(1)).unwrap_or(1)
+}
+
+impl std::fmt::Debug for InputCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InputCursor")
+            .field("value", &self.value)
+            .field("cursor", &self.cursor)
+            .field("grapheme_clusters", &self.grapheme_clusters)
+            .field("max_length", &self.max_length)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for InputCursor {
@@ -62,7 +111,13 @@ impl Default for InputCursor {
 impl InputCursor {
     /// Creates a new [`InputCursor`] with the given value and cursor position.
     pub fn new(value: String, cursor: usize) -> Self {
-        Self { value, cursor }
+        Self {
+            value,
+            cursor,
+            char_width_fn: Arc::new(default_char_width),
+            grapheme_clusters: false,
+            max_length: None,
+        }
     }
 
     /// Creates a new [`InputCursor`] with the given value.  
@@ -79,27 +134,182 @@ impl InputCursor {
     /// ```
     pub fn from(value: String) -> Self {
         let cursor = value.char_indices().count();
-        Self { value, cursor }
+        Self::new(value, cursor)
+    }
+
+    /// Sets the function that returns the display width of a character.  
+    /// Defaults to the width given by `unicode-width`. Useful for fonts with non-standard widths, such as Powerline or Nerd Fonts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use promptuity::InputCursor;
+    ///
+    /// // Treat Powerline symbols as double width.
+    /// let cursor = InputCursor::from("\u{e0b0}a".into()).with_char_width_fn(Arc::new(|c| {
+    ///     if ('\u{e0a0}'..='\u{e0d4}').contains(&c) {
+    ///         2
+    ///     } else {
+    ///         1
+    ///     }
+    /// }));
+    /// assert_eq!(cursor.col(), 3);
+    /// ```
+    pub fn with_char_width_fn(
+        mut self,
+        f: Arc<dyn Fn(char) -> u8 + Send + Sync + 'static>,
+    ) -> Self {
+        self.char_width_fn = f;
+        self
+    }
+
+    /// Sets whether to edit the input by grapheme clusters instead of characters.  
+    /// In grapheme cluster mode, the cursor position counts clusters, so an emoji such as `👩‍👩‍👧` is moved over and deleted as a whole. The display width of a cluster is the widest of its characters.  
+    /// The cursor position is converted to keep pointing at the same place in the value. Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::InputCursor;
+    ///
+    /// let mut cursor = InputCursor::from("a👩‍👩‍👧".into()).with_grapheme_clusters(true);
+    /// assert_eq!(cursor.cursor(), 2);
+    /// assert_eq!(cursor.col(), 3);
+    ///
+    /// cursor.delete_left_char();
+    /// assert_eq!(cursor.value(), "a");
+    /// ```
+    pub fn with_grapheme_clusters(mut self, enabled: bool) -> Self {
+        let offset = self.byte_offset(self.cursor);
+        self.grapheme_clusters = enabled;
+        self.cursor = self.index_of(offset);
+        self
+    }
+
+    /// Sets the maximum length of the value.  
+    /// Once the value reaches the limit, [`InputCursor::insert`] ignores further characters. The length is counted in the same units as the cursor position: characters, or grapheme clusters in grapheme cluster mode.  
+    /// A value set with [`InputCursor::set_value`] is not truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::InputCursor;
+    ///
+    /// let mut cursor = InputCursor::default().with_max_length(3);
+    /// for c in "abcd".chars() {
+    ///     cursor.insert(c);
+    /// }
+    /// assert_eq!(cursor.value(), "abc");
+    /// assert!(cursor.is_full());
+    /// ```
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Returns whether the value has reached the maximum length set by [`InputCursor::with_max_length`].
+    pub fn is_full(&self) -> bool {
+        self.max_length
+            .is_some_and(|max_length| self.len() >= max_length)
+    }
+
+    fn char_width(&self, c: char) -> usize {
+        usize::from((self.char_width_fn)(c))
+    }
+
+    /// Returns the editing units of the value with their byte offsets: characters, or grapheme clusters in grapheme cluster mode.
+    fn segments(&self) -> Vec<(usize, &str)> {
+        if self.grapheme_clusters {
+            self.value.grapheme_indices(true).collect()
+        } else {
+            self.value
+                .char_indices()
+                .map(|(i, c)| (i, &self.value[i..i + c.len_utf8()]))
+                .collect()
+        }
+    }
+
+    fn segment_width(&self, segment: &str) -> usize {
+        segment
+            .chars()
+            .map(|c| self.char_width(c))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the byte offset of the segment at `index`, or the length of the value if it is out of range.
+    fn byte_offset(&self, index: usize) -> usize {
+        self.segments()
+            .get(index)
+            .map_or(self.value.len(), |(i, _)| *i)
     }
 
-    fn chars(&self) -> std::str::CharIndices {
-        self.value.char_indices()
+    /// Returns the index of the segment starting at or after the byte offset.
+    fn index_of(&self, offset: usize) -> usize {
+        self.segments()
+            .iter()
+            .take_while(|(i, _)| *i < offset)
+            .count()
     }
 
-    fn len(&self) -> usize {
-        self.chars().count()
+    /// Returns an iterator over the characters of the input, along with their positions and display widths.  
+    /// Useful when applying per-character styling in custom themes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::{CursorChar, InputCursor};
+    ///
+    /// let mut cursor = InputCursor::from("a日".into());
+    /// cursor.move_left();
+    ///
+    /// let chars = cursor.iter_chars().collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     chars,
+    ///     vec![
+    ///         CursorChar { char: 'a', byte_offset: 0, char_index: 0, is_cursor: false, display_width: 1 },
+    ///         CursorChar { char: '日', byte_offset: 1, char_index: 1, is_cursor: true, display_width: 2 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn iter_chars(&self) -> impl Iterator<Item = CursorChar> + '_ {
+        let cursor = self.byte_offset(self.cursor)..self.byte_offset(self.cursor + 1);
+        self.value
+            .char_indices()
+            .enumerate()
+            .map(move |(char_index, (byte_offset, c))| CursorChar {
+                char: c,
+                byte_offset,
+                char_index,
+                is_cursor: cursor.contains(&byte_offset),
+                display_width: (self.char_width_fn)(c),
+            })
     }
 
-    fn char_at(&self, index: usize) -> Option<(usize, char)> {
-        self.chars().nth(index)
+    /// Returns the length of the value in editing units: characters, or grapheme clusters in grapheme cluster mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::InputCursor;
+    ///
+    /// let cursor = InputCursor::from("e\u{301}a".into());
+    /// assert_eq!(cursor.len(), 3);
+    /// assert_eq!(cursor.with_grapheme_clusters(true).len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.segments().len()
     }
 
     /// Returns the column position of the cursor.
     pub fn col(&self) -> u16 {
         let col = self
-            .chars()
+            .segments()
+            .iter()
             .take(self.cursor)
-            .map(|(_, c)| c.width().unwrap_or(0))
+            .map(|(_, s)| self.segment_width(s))
             .sum::<usize>();
         u16::try_from(col).unwrap_or(0)
     }
@@ -140,18 +350,18 @@ impl InputCursor {
     /// assert_eq!(cursor.split(), ("Hel".into(), "l".into(), "o".into()));
     /// ```
     pub fn split(&self) -> (String, String, String) {
-        let (left, mut cursor, right) = self.chars().enumerate().fold(
+        let (left, mut cursor, right) = self.segments().into_iter().enumerate().fold(
             (String::new(), String::new(), String::new()),
-            |(mut left, mut cursor, mut right), (i, (_, c))| {
+            |(mut left, mut cursor, mut right), (i, (_, s))| {
                 match i.cmp(&self.cursor) {
                     std::cmp::Ordering::Less => {
-                        left.push(c);
+                        left.push_str(s);
                     }
                     std::cmp::Ordering::Equal => {
-                        cursor.push(c);
+                        cursor.push_str(s);
                     }
                     std::cmp::Ordering::Greater => {
-                        right.push(c);
+                        right.push_str(s);
                     }
                 }
                 (left, cursor, right)
@@ -165,6 +375,85 @@ impl InputCursor {
         (left, cursor, right)
     }
 
+    /// Returns a tuple like [`InputCursor::split`], truncated to fit within the specified display width.
+    /// The cursor is always kept visible, and truncated sides are indicated by `…`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::InputCursor;
+    ///
+    /// let mut cursor = InputCursor::from("Hello World".into());
+    /// assert_eq!(cursor.visible_window(8), ("… World".into(), " ".into(), "".into()));
+    ///
+    /// cursor.move_home();
+    /// assert_eq!(cursor.visible_window(8), ("".into(), "H".into(), "ello W…".into()));
+    /// ```
+    pub fn visible_window(&self, width: u16) -> (String, String, String) {
+        let width = width as usize;
+        let mut chars = self
+            .segments()
+            .into_iter()
+            .map(|(_, s)| s)
+            .collect::<Vec<_>>();
+        if self.cursor >= chars.len() {
+            chars.push(" ");
+        }
+
+        let cursor = std::cmp::min(self.cursor, chars.len() - 1);
+        let widths = chars
+            .iter()
+            .map(|s| self.segment_width(s))
+            .collect::<Vec<_>>();
+
+        if widths.iter().sum::<usize>() <= width {
+            return self.split();
+        }
+
+        let ellipsis = |truncated: bool| if truncated { 1 } else { 0 };
+
+        // Find the leftmost start position that keeps the cursor visible.
+        let start = if widths[..=cursor].iter().sum::<usize>() < width {
+            0
+        } else {
+            let budget = width.saturating_sub(1 + ellipsis(cursor.saturating_add(1) < chars.len()));
+            let mut start = cursor;
+            let mut used = widths[cursor];
+            while start > 0 && used + widths[start - 1] <= budget {
+                start -= 1;
+                used += widths[start];
+            }
+            start
+        };
+
+        // Extend the end position as far as the remaining width allows.
+        let budget = width.saturating_sub(ellipsis(start > 0));
+        let mut end = cursor + 1;
+        let mut used = widths[start..end].iter().sum::<usize>();
+        if used + widths[end..].iter().sum::<usize>() <= budget {
+            end = chars.len();
+        } else {
+            while end < chars.len() && used + widths[end] < budget {
+                used += widths[end];
+                end += 1;
+            }
+        }
+
+        let mut left = if start > 0 {
+            "…".to_string()
+        } else {
+            String::new()
+        };
+        left.push_str(&chars[start..cursor].concat());
+
+        let mut right = chars[cursor + 1..end].concat();
+        if end < chars.len() {
+            right.push('…');
+        }
+
+        (left, chars[cursor].to_string(), right)
+    }
+
     /// Returns whether the input is empty or not.
     pub fn is_empty(&self) -> bool {
         self.value.trim().is_empty()
@@ -190,45 +479,87 @@ impl InputCursor {
         self.cursor = self.len();
     }
 
-    /// Inserts a character at the cursor position.
+    /// Inserts a string at the cursor position and moves the cursor after it.  
+    /// The cursor advances by the number of inserted characters, or grapheme clusters in grapheme cluster mode, the same as repeated calls to [`InputCursor::insert`].  
+    /// With a maximum length set by [`InputCursor::with_max_length`], characters beyond the limit are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::InputCursor;
+    ///
+    /// let mut cursor = InputCursor::new("東京".into(), 1);
+    /// cursor.insert_str("🗼タワー");
+    /// assert_eq!(cursor.value(), "東🗼タワー京");
+    /// assert_eq!(cursor.cursor(), 5);
+    /// assert_eq!(cursor.col(), 10);
+    ///
+    /// let mut cursor = InputCursor::new("ad".into(), 1).with_max_length(3);
+    /// cursor.insert_str("bc");
+    /// assert_eq!(cursor.value(), "abd");
+    /// assert_eq!(cursor.cursor(), 2);
+    /// ```
+    pub fn insert_str(&mut self, s: &str) {
+        if self.max_length.is_some() {
+            s.chars().for_each(|c| self.insert(c));
+            return;
+        }
+        let offset = self.byte_offset(self.cursor);
+        self.value.insert_str(offset, s);
+        self.cursor = self.index_of(offset + s.len());
+    }
+
+    /// Inserts a character at the cursor position.  
+    /// In grapheme cluster mode, a character that joins the preceding cluster (e.g. a combining mark) does not advance the cursor.  
+    /// The character is ignored if it would exceed the maximum length.
     pub fn insert(&mut self, chr: char) {
-        match self.char_at(self.cursor) {
-            Some((i, _)) => {
-                self.value.insert(i, chr);
-            }
-            None => {
-                self.value.push(chr);
-            }
+        let offset = self.byte_offset(self.cursor);
+        self.value.insert(offset, chr);
+        if self
+            .max_length
+            .is_some_and(|max_length| self.len() > max_length)
+        {
+            self.value.remove(offset);
+            return;
         }
-        self.cursor = self.cursor.saturating_add(1);
+        self.cursor = self.index_of(offset + chr.len_utf8());
     }
 
-    /// Deletes the character to the left of the cursor.
+    /// Deletes the character to the left of the cursor.  
+    /// In grapheme cluster mode, the entire cluster is deleted.
     pub fn delete_left_char(&mut self) {
         if self.cursor == 0 {
             return;
         }
 
         let cursor = self.cursor.saturating_sub(1);
-        let found = self.char_at(cursor).map(|(i, _)| i);
-        if let Some(i) = found {
-            self.value.remove(i);
-            self.cursor = cursor;
-        }
+        let range = self.byte_offset(cursor)..self.byte_offset(self.cursor);
+        self.value.replace_range(range, "");
+        self.cursor = cursor;
     }
 
-    fn prev_word_index(&mut self) -> usize {
+    /// Returns the index of the first character of the word to the left of the cursor.  
+    /// Words are separated by whitespace: whitespace immediately to the left of the cursor is skipped, then the preceding non-whitespace characters are treated as the word.  
+    /// Returns `0` if the word starts at the beginning of the input, or if there is no word to the left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::InputCursor;
+    ///
+    /// let cursor = InputCursor::from("foo bar  ".into());
+    /// assert_eq!(cursor.word_start_before_cursor(), 4);
+    ///
+    /// let cursor = InputCursor::new("foo bar".into(), 2);
+    /// assert_eq!(cursor.word_start_before_cursor(), 0);
+    /// ```
+    pub fn word_start_before_cursor(&self) -> usize {
         let mut found_word = false;
-        let chars = self
-            .chars()
-            .enumerate()
-            .collect::<Vec<_>>()
-            .into_iter()
-            .take(self.cursor)
-            .rev();
+        let segments = self.segments();
+        let segments = segments.iter().enumerate().take(self.cursor).rev();
 
-        for (i, (_, c)) in chars {
-            if c.is_whitespace() {
+        for (i, (_, s)) in segments {
+            if is_whitespace(s) {
                 if found_word {
                     return i.saturating_add(1);
                 }
@@ -240,37 +571,101 @@ impl InputCursor {
         0
     }
 
-    /// Deletes the word to the left of the cursor.
-    pub fn delete_left_word(&mut self) {
-        let start = self.prev_word_index();
-        let mut value = String::new();
-        for (i, (_, c)) in self.chars().enumerate() {
-            if i < start || self.cursor <= i {
-                value.push(c);
+    /// Returns the index just past the last character of the word to the right of the cursor.  
+    /// Words are separated by whitespace: whitespace immediately to the right of the cursor is skipped, then the following non-whitespace characters are treated as the word.  
+    /// Returns the length of the input if the word ends at the end of the input, or if there is no word to the right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::InputCursor;
+    ///
+    /// let cursor = InputCursor::new("foo  bar baz".into(), 3);
+    /// assert_eq!(cursor.word_end_after_cursor(), 8);
+    ///
+    /// let cursor = InputCursor::new("foo bar".into(), 5);
+    /// assert_eq!(cursor.word_end_after_cursor(), 7);
+    /// ```
+    pub fn word_end_after_cursor(&self) -> usize {
+        let mut found_word = false;
+
+        for (i, (_, s)) in self.segments().iter().enumerate().skip(self.cursor) {
+            if is_whitespace(s) {
+                if found_word {
+                    return i;
+                }
+            } else {
+                found_word = true;
             }
         }
-        self.value = value;
+
+        self.len()
+    }
+
+    /// Moves the cursor to the beginning of the word to the left, as returned by [`InputCursor::word_start_before_cursor`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::InputCursor;
+    ///
+    /// let mut cursor = InputCursor::from("foo bar baz".into());
+    /// cursor.move_prev_word();
+    /// assert_eq!(cursor.cursor(), 8);
+    /// cursor.move_prev_word();
+    /// assert_eq!(cursor.cursor(), 4);
+    /// ```
+    pub fn move_prev_word(&mut self) {
+        self.cursor = self.word_start_before_cursor();
+    }
+
+    /// Moves the cursor just past the end of the word to the right, as returned by [`InputCursor::word_end_after_cursor`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::InputCursor;
+    ///
+    /// let mut cursor = InputCursor::new("foo bar baz".into(), 0);
+    /// cursor.move_next_word();
+    /// assert_eq!(cursor.cursor(), 3);
+    /// cursor.move_next_word();
+    /// assert_eq!(cursor.cursor(), 7);
+    /// ```
+    pub fn move_next_word(&mut self) {
+        self.cursor = self.word_end_after_cursor();
+    }
+
+    /// Deletes the word to the left of the cursor.
+    pub fn delete_left_word(&mut self) {
+        let start = self.word_start_before_cursor();
+        let range = self.byte_offset(start)..self.byte_offset(self.cursor);
+        self.value.replace_range(range, "");
         self.cursor = start;
     }
 
-    /// Deletes the character to the right of the cursor.
+    /// Deletes the word to the right of the cursor.
+    pub fn delete_right_word(&mut self) {
+        let end = self.word_end_after_cursor();
+        let range = self.byte_offset(self.cursor)..self.byte_offset(end);
+        self.value.replace_range(range, "");
+    }
+
+    /// Deletes the character to the right of the cursor.  
+    /// In grapheme cluster mode, the entire cluster is deleted.
     pub fn delete_right_char(&mut self) {
         if self.cursor >= self.len() {
             return;
         }
 
-        let found = self.char_at(self.cursor).map(|(i, _)| i);
-        if let Some(i) = found {
-            self.value.remove(i);
-        }
+        let range = self.byte_offset(self.cursor)..self.byte_offset(self.cursor + 1);
+        self.value.replace_range(range, "");
     }
 
     /// Deletes characters to the right of the cursor up to the end of the line.
     pub fn delete_rest_line(&mut self) {
-        let found = self.char_at(self.cursor).map(|(i, _)| i);
-        if let Some(i) = found {
-            self.value = self.value[..i].to_string();
-        }
+        let offset = self.byte_offset(self.cursor);
+        self.value.truncate(offset);
     }
 
     /// Deletes the entire line.
@@ -280,10 +675,250 @@ impl InputCursor {
     }
 }
 
+/// A struct representing a single key binding.
+///
+/// When `modifiers` is `None`, the binding matches the key code regardless of the modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    /// The key code to match.
+    pub code: KeyCode,
+    /// The modifiers to match, or `None` to match any modifiers.
+    pub modifiers: Option<KeyModifiers>,
+}
+
+impl KeyBinding {
+    /// Creates a new [`KeyBinding`] matching the exact key code and modifiers.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers: Some(modifiers),
+        }
+    }
+
+    /// Creates a new [`KeyBinding`] matching the key code with any modifiers.
+    pub fn any(code: KeyCode) -> Self {
+        Self {
+            code,
+            modifiers: None,
+        }
+    }
+
+    /// Creates a new [`KeyBinding`] matching the character with `Ctrl` pressed.
+    pub fn ctrl(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    /// Returns `true` if the binding matches the key press.
+    pub fn matches(&self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        self.code == code && self.modifiers.unwrap_or(modifiers) == modifiers
+    }
+}
+
+impl From<(KeyCode, KeyModifiers)> for KeyBinding {
+    fn from((code, modifiers): (KeyCode, KeyModifiers)) -> Self {
+        Self::new(code, modifiers)
+    }
+}
+
+/// A trait for looking up a key press in a list of [`KeyBinding`].
+pub trait KeyBindingList {
+    /// Returns `true` if any of the bindings matches the key press.
+    fn contains_key(&self, code: KeyCode, modifiers: KeyModifiers) -> bool;
+}
+
+impl KeyBindingList for [KeyBinding] {
+    fn contains_key(&self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        self.iter().any(|b| b.matches(code, modifiers))
+    }
+}
+
+/// A struct representing the key bindings used by the built-in prompts.
+///
+/// Each field lists the keys that trigger the action. Prompts only look up the actions they support.  
+/// [`KeyBindings::default`] returns the bindings the built-in prompts have always used.
+///
+/// # Examples
+///
+/// ```
+/// use promptuity::event::KeyCode;
+/// use promptuity::prompts::Number;
+/// use promptuity::{KeyBinding, KeyBindings};
+///
+/// let mut kb = KeyBindings::vi();
+/// kb.submit.push(KeyBinding::any(KeyCode::Tab));
+///
+/// let _ = Number::new("How many?").with_keybindings(kb);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings {
+    /// Submits the prompt.
+    pub submit: Vec<KeyBinding>,
+    /// Cancels the prompt.
+    pub cancel: Vec<KeyBinding>,
+    /// Moves the cursor to the left, or chooses the first label of [`crate::prompts::Toggle`].
+    pub move_left: Vec<KeyBinding>,
+    /// Moves the cursor to the right, or chooses the second label of [`crate::prompts::Toggle`].
+    pub move_right: Vec<KeyBinding>,
+    /// Moves the cursor to the beginning of the line, or the selection to the first option.
+    pub move_home: Vec<KeyBinding>,
+    /// Moves the cursor to the end of the line, or the selection to the last option.
+    pub move_end: Vec<KeyBinding>,
+    /// Moves the cursor to the beginning of the previous word.
+    pub move_prev_word: Vec<KeyBinding>,
+    /// Moves the cursor to the end of the next word.
+    pub move_next_word: Vec<KeyBinding>,
+    /// Moves the selection up.
+    pub move_up: Vec<KeyBinding>,
+    /// Moves the selection down.
+    pub move_down: Vec<KeyBinding>,
+    /// Moves the selection up by a page.
+    pub page_up: Vec<KeyBinding>,
+    /// Moves the selection down by a page.
+    pub page_down: Vec<KeyBinding>,
+    /// Moves the focused option of [`crate::prompts::Sort`] up.
+    pub move_item_up: Vec<KeyBinding>,
+    /// Moves the focused option of [`crate::prompts::Sort`] down.
+    pub move_item_down: Vec<KeyBinding>,
+    /// Deletes the character to the left of the cursor.
+    pub delete_left_char: Vec<KeyBinding>,
+    /// Deletes the word to the left of the cursor.
+    pub delete_left_word: Vec<KeyBinding>,
+    /// Deletes the character under the cursor.
+    pub delete_right_char: Vec<KeyBinding>,
+    /// Deletes from the cursor to the end of the line.
+    pub delete_rest_line: Vec<KeyBinding>,
+    /// Deletes the entire line.
+    pub delete_line: Vec<KeyBinding>,
+    /// Increments the value of [`crate::prompts::Number`].
+    pub increment: Vec<KeyBinding>,
+    /// Decrements the value of [`crate::prompts::Number`].
+    pub decrement: Vec<KeyBinding>,
+    /// Increments the value of [`crate::prompts::Number`] by the big step.
+    pub increment_big: Vec<KeyBinding>,
+    /// Decrements the value of [`crate::prompts::Number`] by the big step.
+    pub decrement_big: Vec<KeyBinding>,
+    /// Toggles the focused option of [`crate::prompts::MultiSelect`], switches [`crate::prompts::Toggle`], or submits [`crate::prompts::Select`].
+    pub toggle: Vec<KeyBinding>,
+    /// Toggles all options of [`crate::prompts::MultiSelect`].
+    pub toggle_all: Vec<KeyBinding>,
+    /// Inverts the selection of [`crate::prompts::MultiSelect`].
+    pub invert: Vec<KeyBinding>,
+    /// Selects "Yes" in [`crate::prompts::Confirm`].
+    pub choose_yes: Vec<KeyBinding>,
+    /// Selects "No" in [`crate::prompts::Confirm`].
+    pub choose_no: Vec<KeyBinding>,
+    /// Submits "Yes" in [`crate::prompts::Confirm`].
+    pub submit_yes: Vec<KeyBinding>,
+    /// Submits "No" in [`crate::prompts::Confirm`].
+    pub submit_no: Vec<KeyBinding>,
+    /// Completes the input of [`crate::prompts::Path`], cycling through the candidates when repeated.
+    pub complete: Vec<KeyBinding>,
+}
+
+impl KeyBindings {
+    /// Returns the Emacs-style key bindings, which are the same as [`KeyBindings::default`].
+    pub fn emacs() -> Self {
+        use KeyBinding as K;
+        Self {
+            submit: vec![K::any(KeyCode::Enter)],
+            cancel: vec![K::any(KeyCode::Esc), K::ctrl('c')],
+            move_left: vec![K::any(KeyCode::Left), K::ctrl('b')],
+            move_right: vec![K::any(KeyCode::Right), K::ctrl('f')],
+            move_home: vec![K::any(KeyCode::Home), K::ctrl('a')],
+            move_end: vec![K::any(KeyCode::End), K::ctrl('e')],
+            move_prev_word: vec![
+                K::new(KeyCode::Char('b'), KeyModifiers::ALT),
+                K::new(KeyCode::Left, KeyModifiers::ALT),
+                K::new(KeyCode::Left, KeyModifiers::CONTROL),
+            ],
+            move_next_word: vec![
+                K::new(KeyCode::Char('f'), KeyModifiers::ALT),
+                K::new(KeyCode::Right, KeyModifiers::ALT),
+                K::new(KeyCode::Right, KeyModifiers::CONTROL),
+            ],
+            move_up: vec![
+                K::any(KeyCode::Up),
+                K::any(KeyCode::Char('k')),
+                K::ctrl('p'),
+            ],
+            move_down: vec![
+                K::any(KeyCode::Down),
+                K::any(KeyCode::Char('j')),
+                K::ctrl('n'),
+            ],
+            page_up: vec![K::any(KeyCode::PageUp)],
+            page_down: vec![K::any(KeyCode::PageDown)],
+            move_item_up: vec![K::new(KeyCode::Up, KeyModifiers::SHIFT)],
+            move_item_down: vec![K::new(KeyCode::Down, KeyModifiers::SHIFT)],
+            delete_left_char: vec![K::any(KeyCode::Backspace), K::ctrl('h')],
+            delete_left_word: vec![K::ctrl('w')],
+            delete_right_char: vec![K::any(KeyCode::Delete), K::ctrl('d')],
+            delete_rest_line: vec![K::ctrl('k')],
+            delete_line: vec![K::ctrl('u')],
+            increment: vec![K::any(KeyCode::Up)],
+            decrement: vec![K::any(KeyCode::Down)],
+            increment_big: vec![K::new(KeyCode::Up, KeyModifiers::SHIFT)],
+            decrement_big: vec![K::new(KeyCode::Down, KeyModifiers::SHIFT)],
+            toggle: vec![K::new(KeyCode::Char(' '), KeyModifiers::NONE)],
+            toggle_all: vec![K::new(KeyCode::Char('a'), KeyModifiers::NONE)],
+            invert: vec![K::new(KeyCode::Char('i'), KeyModifiers::NONE)],
+            choose_yes: vec![
+                K::any(KeyCode::Left),
+                K::any(KeyCode::Char('h')),
+                K::ctrl('p'),
+            ],
+            choose_no: vec![
+                K::any(KeyCode::Right),
+                K::any(KeyCode::Char('l')),
+                K::ctrl('n'),
+            ],
+            submit_yes: vec![
+                K::new(KeyCode::Char('y'), KeyModifiers::NONE),
+                K::new(KeyCode::Char('Y'), KeyModifiers::NONE),
+            ],
+            submit_no: vec![
+                K::new(KeyCode::Char('n'), KeyModifiers::NONE),
+                K::new(KeyCode::Char('N'), KeyModifiers::NONE),
+            ],
+            complete: vec![K::any(KeyCode::Tab)],
+        }
+    }
+
+    /// Returns the Vi-style key bindings.
+    ///
+    /// Select prompts are navigated with `hjkl` only, and [`crate::prompts::Number`] is incremented and decremented with `+` and `-`. When a sign can be typed at the start of a [`crate::prompts::Number`], typing it takes precedence.
+    pub fn vi() -> Self {
+        use KeyBinding as K;
+        Self {
+            move_up: vec![K::any(KeyCode::Up), K::any(KeyCode::Char('k'))],
+            move_down: vec![K::any(KeyCode::Down), K::any(KeyCode::Char('j'))],
+            increment: vec![
+                K::any(KeyCode::Up),
+                K::new(KeyCode::Char('+'), KeyModifiers::NONE),
+            ],
+            decrement: vec![
+                K::any(KeyCode::Down),
+                K::new(KeyCode::Char('-'), KeyModifiers::NONE),
+            ],
+            choose_yes: vec![K::any(KeyCode::Left), K::any(KeyCode::Char('h'))],
+            choose_no: vec![K::any(KeyCode::Right), K::any(KeyCode::Char('l'))],
+            ..Self::emacs()
+        }
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::emacs()
+    }
+}
+
 /// A struct representing the state of the prompt.
 ///
-/// Controls the rendering of the prompt and the flow of actions like submission.
+/// Controls the rendering of the prompt and the flow of actions like submission.  
+/// New states may be added in future versions, so matches outside this crate need a wildcard arm.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum PromptState {
     /// An active state that accepts user inputs like key presses.
     Active,
@@ -293,6 +928,8 @@ pub enum PromptState {
     Cancel,
     /// A state for recoverable errors. e.g. validation errors.
     Error(String),
+    /// A state for non-blocking warnings. Submitting again in this state finalizes the value.
+    Warning(String),
     /// A state for unrecoverable errors.
     Fatal(String),
 }
@@ -304,15 +941,35 @@ impl std::fmt::Display for PromptState {
             PromptState::Submit => write!(f, "Submit"),
             PromptState::Cancel => write!(f, "Cancel"),
             PromptState::Error(msg) => write!(f, "Error({})", msg),
+            PromptState::Warning(msg) => write!(f, "Warning({})", msg),
             PromptState::Fatal(msg) => write!(f, "Fatal({})", msg),
         }
     }
 }
 
+impl From<ValidationError> for PromptState {
+    fn from(err: ValidationError) -> Self {
+        match err {
+            ValidationError::Error(msg) => PromptState::Error(msg),
+            ValidationError::Fatal(msg) => PromptState::Fatal(msg),
+        }
+    }
+}
+
+/// The result of [`Promptuity::prompt_flow`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Flow<O> {
+    /// The prompt was submitted with the value.
+    Next(O),
+    /// The user asked to return to the previous prompt.
+    Back,
+}
+
 /// A struct representing the input of the prompt.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub enum PromptInput {
     /// Do not use Input.
+    #[default]
     None,
     /// Use [`InputCursor`].
     Cursor(InputCursor),
@@ -320,49 +977,210 @@ pub enum PromptInput {
     Raw(String),
 }
 
-impl Default for PromptInput {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 /// A struct representing the body of the prompt.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub enum PromptBody {
     /// Do not use Body.
+    #[default]
     None,
     /// Display Body as a string.
     Raw(String),
+    /// Display a window of `visible_lines` lines of `content`, starting at `scroll_offset`.  
+    /// [`Promptuity`] manages `scroll_offset`, scrolling with <kbd>Shift</kbd> or <kbd>Alt</kbd> + <kbd>↑</kbd> / <kbd>↓</kbd>, so prompts can return the full content with an offset of `0`.
+    Scrollable {
+        /// The full content of the body.
+        content: String,
+        /// The index of the first visible line.
+        scroll_offset: usize,
+        /// The maximum number of lines to display.
+        visible_lines: usize,
+    },
 }
 
-impl Default for PromptBody {
-    fn default() -> Self {
-        Self::None
+impl PromptBody {
+    /// Returns the text to display, or `None` if there is no body.  
+    /// For [`PromptBody::Scrollable`], only the lines within the visible window are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::PromptBody;
+    ///
+    /// let body = PromptBody::Scrollable {
+    ///     content: "a\nb\nc\nd".into(),
+    ///     scroll_offset: 1,
+    ///     visible_lines: 2,
+    /// };
+    /// assert_eq!(body.visible_content(), Some("b\nc".into()));
+    /// assert_eq!(PromptBody::None.visible_content(), None);
+    /// ```
+    pub fn visible_content(&self) -> Option<String> {
+        match self {
+            PromptBody::None => None,
+            PromptBody::Raw(s) => Some(s.clone()),
+            PromptBody::Scrollable {
+                content,
+                scroll_offset,
+                visible_lines,
+            } => Some(
+                content
+                    .lines()
+                    .skip(*scroll_offset)
+                    .take(*visible_lines)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+        }
     }
 }
 
 /// A trait for performing prompt validation.
+///
+/// Closures returning `Result<(), E>` are validators as long as `E` converts into [`ValidationError`], which includes `String` and `&str`.
+///
+/// # Migration
+///
+/// [`Validator::validate`] and [`Prompt::validate`] used to return `Result<(), String>`. Existing implementations only need the new return type, converting their messages with `.into()`:
+///
+/// ```
+/// use promptuity::{ValidationError, Validator};
+///
+/// struct MinLength(usize);
+///
+/// impl Validator<String> for MinLength {
+///     fn validate(&self, value: &String) -> Result<(), ValidationError> {
+///         if value.len() < self.0 {
+///             Err(format!("Must be at least {} characters", self.0).into())
+///         } else {
+///             Ok(())
+///         }
+///     }
+/// }
+///
+/// assert_eq!(MinLength(3).validate(&"ab".into()), Err("Must be at least 3 characters".into()));
+/// ```
 pub trait Validator<T> {
-    fn validate(&self, value: &T) -> Result<(), String>;
-}
+    /// Validates the value, returning an error if it is invalid. See [`ValidationError`].
+    fn validate(&self, value: &T) -> Result<(), ValidationError>;
 
-impl<T, F> Validator<T> for F
-where
-    F: Fn(&T) -> Result<(), String>,
+    /// Prepares the state that [`Validator::validate`] reuses, such as a compiled pattern.  
+    /// The built-in prompts call it once from [`Prompt::setup`], before the first validation. Return [`Error::Config`] when the validator is misconfigured. Does nothing by default.
+    fn prepare(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<T, F, E> Validator<T> for F
+where
+    F: Fn(&T) -> Result<(), E>,
+    E: Into<ValidationError>,
 {
-    fn validate(&self, value: &T) -> Result<(), String> {
-        self(value)
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        self(value).map_err(Into::into)
+    }
+}
+
+impl<T> Validator<T> for Box<dyn Validator<T>> {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        (**self).validate(value)
+    }
+
+    fn prepare(&mut self) -> Result<(), Error> {
+        (**self).prepare()
     }
 }
 
+/// A boxed [`Validator`], as stored by the built-in prompts.
+pub type DynValidator<T> = Box<dyn Validator<T>>;
+
+mod private {
+    pub trait Sealed<T> {}
+
+    impl<T, V: super::Validator<T>> Sealed<T> for V {}
+}
+
+/// An extension trait for [`Validator`].
+///
+/// This trait is sealed and implemented for all validators.
+///
+/// # Examples
+///
+/// Boxing a validator:
+///
+/// ```
+/// use promptuity::prompts::Input;
+/// use promptuity::{DynValidator, ValidatorExt};
+///
+/// let not_empty: DynValidator<String> = (|v: &String| {
+///     if v.is_empty() {
+///         Err("Required")
+///     } else {
+///         Ok(())
+///     }
+/// })
+/// .into_boxed();
+///
+/// let _ = Input::new("Name").with_validator(not_empty);
+/// ```
+///
+/// Sharing a validator across prompts with [`std::sync::Arc`]:
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use promptuity::prompts::Input;
+/// use promptuity::Validator;
+///
+/// let shared: Arc<dyn Validator<String> + Send + Sync> = Arc::new(|v: &String| {
+///     if v.len() < 3 {
+///         Err("Too short")
+///     } else {
+///         Ok(())
+///     }
+/// });
+///
+/// let v = Arc::clone(&shared);
+/// let _ = Input::new("First").with_validator(move |s: &String| v.validate(s));
+/// let v = Arc::clone(&shared);
+/// let _ = Input::new("Second").with_validator(move |s: &String| v.validate(s));
+///
+/// assert!(shared.validate(&"ab".to_string()).is_err());
+/// ```
+pub trait ValidatorExt<T>: Validator<T> + private::Sealed<T> {
+    /// Boxes the validator into a [`DynValidator`].
+    fn into_boxed(self) -> DynValidator<T>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+impl<T, V: Validator<T>> ValidatorExt<T> for V {}
+
 /// A struct representing the payload required for prompt rendering.
 #[derive(Debug, Default)]
 pub struct RenderPayload {
+    /// The input area of the prompt.
     pub input: PromptInput,
+    /// The body area of the prompt.
     pub body: PromptBody,
+    /// The message of the prompt.
     pub message: String,
+    /// The hint message of the prompt.
     pub hint: Option<String>,
+    /// The placeholder displayed when there is no input.
     pub placeholder: Option<String>,
+    /// The icon of the prompt. If `None`, the theme's default icon is used.
+    pub icon: Option<String>,
+    /// The multi-line description displayed before the input or options.
+    pub description: Option<String>,
+    /// The advisory validation message displayed while the prompt is active.
+    pub validation_hint: Option<String>,
+    /// The row displayed above the body when there are more items before the current page.
+    pub page_indicator_before: Option<String>,
+    /// The row displayed below the body when there are more items after the current page.
+    pub page_indicator_after: Option<String>,
 }
 
 impl RenderPayload {
@@ -387,6 +1205,35 @@ impl RenderPayload {
         self.body = body;
         self
     }
+
+    /// Sets the icon for the payload.  
+    /// Themes may display it in place of their default icon.
+    pub fn icon(mut self, icon: Option<String>) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Sets the description for the payload.  
+    /// Themes display it between the message and the input, except in the submitted state.
+    pub fn description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// Sets the validation hint for the payload.  
+    /// Themes display it in a muted color while the prompt is active. Unlike [`PromptState::Error`], it does not block submission.
+    pub fn validation_hint(mut self, validation_hint: Option<String>) -> Self {
+        self.validation_hint = validation_hint;
+        self
+    }
+
+    /// Sets the page indicators for the payload.  
+    /// Themes display them above and below the body while the prompt is active.
+    pub fn page_indicators(mut self, before: Option<String>, after: Option<String>) -> Self {
+        self.page_indicator_before = before;
+        self.page_indicator_after = after;
+        self
+    }
 }
 
 /// A trait representing the behavior of a prompt.
@@ -395,21 +1242,81 @@ pub trait Prompt {
     type Output;
 
     /// Sets up the prompt.  
-    /// A lifecycle method for validating and initializing settings.
+    /// A lifecycle method for validating and initializing settings.  
+    /// It is called once before the first render, so it is also a good place to prepare resources that [`Prompt::validate`] reuses on every submission.
     fn setup(&mut self) -> Result<(), Error> {
         Ok(())
     }
 
     /// Validates the prompt.  
-    /// If returning an error, please return the error message as a `String`.
-    fn validate(&self) -> Result<(), String> {
+    /// A `String` message converts into a recoverable [`ValidationError::Error`]. Return [`ValidationError::Fatal`] to finish the prompt with [`PromptState::Fatal`].
+    fn validate(&self) -> Result<(), ValidationError> {
         Ok(())
     }
 
+    /// Returns the icon that represents the type of the prompt.  
+    /// Used when [`RenderPayload`] does not specify an icon. Themes may display it in place of their default icon.
+    fn icon(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns a warning message for the value about to be submitted.  
+    /// Unlike [`Prompt::validate`], warnings do not block submission: the first submission displays the warning as [`PromptState::Warning`], and submitting again finalizes the value.
+    fn warn(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the name of the prompt type, such as `"Select"`.  
+    /// Used to identify the prompt in [`Error::Config`] errors returned from [`Prompt::setup`]. Defaults to `"Prompt"`.
+    fn name(&self) -> &'static str {
+        "Prompt"
+    }
+
+    /// Applies the session-level page size set by [`Promptuity::with_default_page_size`].  
+    /// Called before [`Prompt::setup`]. Paginated prompts should ignore it when the page size has been set explicitly. Does nothing by default.
+    fn set_page_size(&mut self, _page_size: usize) {}
+
+    /// Applies the terminal width in columns.  
+    /// Called before each [`Prompt::render`], so prompts can fit their lines to the terminal. Does nothing by default.
+    fn set_width(&mut self, _width: u16) {}
+
+    /// Returns how long [`Promptuity`] waits after the last key press or paste before running live validation.  
+    /// While waiting, [`Prompt::set_validation_paused`] is called with `true` before each render, and the prompt is rendered again once the input settles. Returns `None` by default.
+    fn validation_debounce(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Applies whether live validation is paused because the input is still changing. See [`Prompt::validation_debounce`].  
+    /// Called before each [`Prompt::render`]. Validation at the time of submission is not affected. Does nothing by default.
+    fn set_validation_paused(&mut self, _paused: bool) {}
+
+    /// Applies the session-level cancel keys set by [`Promptuity::with_cancel_keys`].  
+    /// Called before [`Prompt::setup`]. Prompts that can be cancelled should replace their cancel keys with the given keys. Does nothing by default.
+    fn set_cancel_keys(&mut self, _keys: &[KeyBinding]) {}
+
     /// Handles key presses.  
     /// Allows changing the internal state of the prompt in response to key inputs.
     fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState;
 
+    /// Handles text pasted at once with bracketed paste.  
+    /// By default, each character is passed to [`Prompt::handle`] as a key press until a state other than [`PromptState::Active`] is returned. Control characters such as newlines are skipped, except for tabs, which are passed as typed. Prompts with text input should override it to insert the whole text at once.
+    fn handle_paste(&mut self, text: String) -> PromptState {
+        let mut state = PromptState::Active;
+        for c in text.chars().filter(|c| *c == '\t' || !c.is_control()) {
+            state = self.handle(KeyCode::Char(c), KeyModifiers::NONE);
+            if state != PromptState::Active {
+                break;
+            }
+        }
+        state
+    }
+
+    /// Handles a mouse event captured with [`Promptuity::with_mouse`].  
+    /// `row` is the index of the body line under the mouse, or `None` if the pointer is above the body or the theme does not report its position with [`crate::Theme::body_offset`]. Rows past the end of the body are not filtered out. Does nothing by default.
+    fn handle_mouse(&mut self, _event: MouseEvent, _row: Option<usize>) -> PromptState {
+        PromptState::Active
+    }
+
     /// Submits the prompt.  
     /// Called as a result of [`Prompt::handle`] returning [`PromptState::Submit`], it returns the final value.
     fn submit(&mut self) -> Self::Output;
@@ -471,19 +1378,82 @@ pub trait Prompt {
 /// # Ok(())
 /// # }
 /// ```
+///
+/// ## Cancellation
+///
+/// When a prompt is cancelled, [`Promptuity::prompt`] returns [`Error::Cancel`]. By default, it calls [`Promptuity::finish`] before returning, which renders the outro and deactivates Raw Mode.  
+/// With [`Promptuity::with_finish_on_cancel`] set to `false`, the session is left open so the caller can decide what to do, such as running more prompts or calling `finish` itself. If the session is dropped without `finish`, Raw Mode is deactivated by the `Drop` implementation.
+///
+/// ```no_run
+/// # use promptuity::themes::MinimalTheme;
+/// # use promptuity::prompts::Input;
+/// # use promptuity::{Error, Promptuity, Term};
+/// # fn main() -> Result<(), promptuity::Error> {
+/// # let mut term = Term::default();
+/// # let mut theme = MinimalTheme::default();
+/// # let mut p = Promptuity::new(&mut term, &mut theme);
+/// p.with_finish_on_cancel(false).begin()?;
+///
+/// let name = match p.prompt(Input::new("Please enter your username").as_mut()) {
+///     Ok(name) => name,
+///     Err(Error::Cancel) => "anonymous".into(),
+///     Err(e) => return Err(e),
+/// };
+///
+/// p.with_outro(format!("Hello, {name}!")).finish()?;
+/// # Ok(())
+/// # }
+/// ```
 pub struct Promptuity<'a, W: std::io::Write> {
     term: &'a mut dyn Terminal<W>,
     theme: &'a mut dyn Theme<W>,
     state: PromptState,
-    intro: Option<String>,
+    intro: Option<IntroMetadata>,
     outro: Option<String>,
+    cancel_exit_code: i32,
+    error_exit_code: i32,
+    finish_on_cancel: bool,
+    accessibility_mode: bool,
+    accessibility_log: Option<&'a mut dyn std::io::Write>,
+    non_interactive: bool,
+    timeout: Option<Duration>,
+    submit_on_timeout: bool,
+    back_keys: Vec<KeyBinding>,
+    cancel_keys: Option<Vec<KeyBinding>>,
+    mouse: bool,
+    render_middlewares: Vec<Box<dyn RenderMiddleware>>,
+    default_page_size: Option<usize>,
+    scroll_offset: usize,
+    scrollable: bool,
+    last_input: Option<Instant>,
+    back: bool,
+    started: bool,
     finished: bool,
+    cancelled: bool,
 }
 
 impl<'a, W: std::io::Write> Drop for Promptuity<'a, W> {
     fn drop(&mut self) {
-        if !self.finished {
-            self.term.disable_raw().expect("Failed to disable raw mode");
+        if self.finished {
+            return;
+        }
+
+        // The theme may be in an unknown state (e.g. after a panic), so only plain text is written
+        // and errors are ignored to avoid panicking while unwinding.
+        if self.started {
+            let _ = self.term.disable_bracketed_paste();
+            if self.mouse {
+                let _ = self.term.disable_mouse_capture();
+            }
+        }
+        let _ = self.term.disable_raw();
+        if self.started {
+            let _ = self.term.cursor_show();
+            // A cancellation returned to the caller is a legitimate end of the session.
+            if !self.cancelled {
+                let _ = self.term.write("\nPrompt session interrupted\n");
+            }
+            let _ = self.term.flush();
         }
     }
 }
@@ -497,19 +1467,98 @@ impl<'a, W: std::io::Write> Promptuity<'a, W> {
             state: PromptState::Active,
             intro: None,
             outro: None,
+            cancel_exit_code: 130,
+            error_exit_code: 1,
+            finish_on_cancel: true,
+            accessibility_mode: false,
+            accessibility_log: None,
+            non_interactive: false,
+            timeout: None,
+            submit_on_timeout: false,
+            back_keys: vec![KeyBinding::any(KeyCode::BackTab)],
+            cancel_keys: None,
+            mouse: false,
+            render_middlewares: Vec::new(),
+            default_page_size: None,
+            scroll_offset: 0,
+            last_input: None,
+            back: false,
+            scrollable: false,
+            started: false,
             finished: false,
+            cancelled: false,
         }
     }
 
+    /// Creates a new [`PromptSession`] that writes to the given writer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::themes::MinimalTheme;
+    /// use promptuity::Promptuity;
+    ///
+    /// let mut session = Promptuity::with_writer(std::io::stdout(), MinimalTheme::default());
+    /// let mut p = session.promptuity();
+    /// ```
+    pub fn with_writer<T: Theme<W>>(writer: W, theme: T) -> PromptSession<W, T> {
+        PromptSession::new(Term::new(writer), theme)
+    }
+
     /// Returns the currently used terminal.
     pub fn term(&mut self) -> &mut dyn Terminal<W> {
         self.term
     }
 
+    /// Starts a spinner drawn by the theme, for indicating the progress of long-running work between prompts.  
+    /// In non-interactive mode, the frames are not rendered and only the final message is displayed. See [`SpinnerHandle`].
+    pub fn spinner(
+        &mut self,
+        message: impl std::fmt::Display,
+    ) -> Result<SpinnerHandle<'_, W>, Error> {
+        SpinnerHandle::new(
+            self.term,
+            self.theme,
+            message.to_string(),
+            !self.non_interactive,
+        )
+    }
+
+    /// Enables Raw Mode until the returned [`RawModeGuard`] is dropped.  
+    /// Useful for custom terminal interactions, such as reading keys manually, outside of [`Promptuity::prompt`]. Inside a session started by [`Promptuity::begin`], Raw Mode is left enabled when the guard is dropped.
+    pub fn raw_mode_guard(&mut self) -> Result<RawModeGuard<'_, W>, Error> {
+        let in_session = self.started && !self.finished;
+        RawModeGuard::new(self.term, !in_session)
+    }
+
     /// Sets the intro message for the prompt session.  
     /// May be required by the Theme.
     pub fn with_intro(&mut self, intro: impl std::fmt::Display) -> &mut Self {
-        self.intro = Some(intro.to_string());
+        self.intro = Some(IntroMetadata::new(intro));
+        self
+    }
+
+    /// Sets the intro metadata for the prompt session.  
+    /// Themes may render fields other than the title, such as the version and author.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::themes::FancyTheme;
+    /// use promptuity::{IntroMetadata, Promptuity, Term};
+    ///
+    /// # fn main() -> Result<(), promptuity::Error> {
+    /// let mut term = Term::default();
+    /// let mut theme = FancyTheme::default();
+    /// let mut p = Promptuity::new(&mut term, &mut theme);
+    ///
+    /// p.with_intro_metadata(IntroMetadata::new("Survey").with_version("1.0.0"))
+    ///     .begin()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_intro_metadata(&mut self, meta: IntroMetadata) -> &mut Self {
+        self.intro = Some(meta);
         self
     }
 
@@ -520,19 +1569,245 @@ impl<'a, W: std::io::Write> Promptuity<'a, W> {
         self
     }
 
+    /// Sets whether [`Promptuity::prompt`] calls [`Promptuity::finish`] when a prompt is cancelled.  
+    /// Defaults to `true`. When `false`, [`Error::Cancel`] is returned with the session left open. See the [Cancellation](Promptuity#cancellation) section.
+    pub fn with_finish_on_cancel(&mut self, finish_on_cancel: bool) -> &mut Self {
+        self.finish_on_cancel = finish_on_cancel;
+        self
+    }
+
+    /// Sets the exit code reported by [`Promptuity::exit_code`] when a prompt is cancelled.  
+    /// Defaults to `130`.
+    pub fn with_cancel_exit_code(&mut self, code: i32) -> &mut Self {
+        self.cancel_exit_code = code;
+        self
+    }
+
+    /// Sets the exit code reported by [`Promptuity::exit_code`] when a prompt fails with an unrecoverable error.  
+    /// Defaults to `1`.
+    pub fn with_error_exit_code(&mut self, code: i32) -> &mut Self {
+        self.error_exit_code = code;
+        self
+    }
+
+    /// Sets whether to run prompts without reading keys, for CI and other environments without a TTY.  
+    /// When enabled, [`Promptuity::begin`] does not activate Raw Mode, and [`Promptuity::prompt`] submits the default value of each prompt right away, rendering it once as a record of the answer. If the default value fails [`Prompt::validate`], [`Error::Prompt`] is returned. Use [`Promptuity::prompt_with_default`] to fall back to a value instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Input;
+    /// use promptuity::themes::MinimalTheme;
+    /// use promptuity::{Promptuity, Term};
+    ///
+    /// # fn main() -> Result<(), promptuity::Error> {
+    /// let mut term = Term::default();
+    /// let mut theme = MinimalTheme::default();
+    /// let mut p = Promptuity::new(&mut term, &mut theme);
+    ///
+    /// p.with_non_interactive(std::env::var_os("CI").is_some()).begin()?;
+    /// let name = p.prompt(Input::new("Project name").with_default("my-app"))?;
+    /// p.finish()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_non_interactive(&mut self, non_interactive: bool) -> &mut Self {
+        self.non_interactive = non_interactive;
+        self
+    }
+
+    /// Sets how long a prompt waits for a key press before giving up.  
+    /// The timeout restarts on every key press. When it expires, the prompt is cancelled, or submitted with its current value when [`Promptuity::with_submit_on_timeout`] is enabled. Keys are read with [`Terminal::read_key_timeout`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use promptuity::prompts::Confirm;
+    /// use promptuity::themes::MinimalTheme;
+    /// use promptuity::{Promptuity, Term};
+    ///
+    /// # fn main() -> Result<(), promptuity::Error> {
+    /// let mut term = Term::default();
+    /// let mut theme = MinimalTheme::default();
+    /// let mut p = Promptuity::new(&mut term, &mut theme);
+    ///
+    /// p.with_timeout(Duration::from_secs(30))
+    ///     .with_submit_on_timeout(true)
+    ///     .begin()?;
+    /// let ok = p.prompt(Confirm::new("Continue?").with_default(true))?;
+    /// p.finish()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to submit the current value of the prompt when the timeout set by [`Promptuity::with_timeout`] expires.  
+    /// If the value fails [`Prompt::validate`], the prompt is cancelled instead. Defaults to `false`, which cancels the prompt.
+    pub fn with_submit_on_timeout(&mut self, submit_on_timeout: bool) -> &mut Self {
+        self.submit_on_timeout = submit_on_timeout;
+        self
+    }
+
+    /// Sets the keys that return to the previous prompt in [`Promptuity::prompt_flow`].  
+    /// The keys are only handled by [`Promptuity::prompt_flow`]; [`Promptuity::prompt`] passes them to the prompt as usual. Defaults to `Shift+Tab`.
+    pub fn with_back_keys(&mut self, keys: &[KeyBinding]) -> &mut Self {
+        self.back_keys = keys.to_vec();
+        self
+    }
+
+    /// Sets whether to capture mouse events, passing them to [`Prompt::handle_mouse`].  
+    /// Mouse capture prevents selecting text in the terminal while the session is running, so it is disabled by default. It must be set before [`Promptuity::begin`].
+    pub fn with_mouse(&mut self, mouse: bool) -> &mut Self {
+        self.mouse = mouse;
+        self
+    }
+
+    /// Sets the keys that cancel prompts, replacing the cancel keys of every prompt run in the session.  
+    /// Useful when a key such as `Esc` should be handled differently. Applied with [`Prompt::set_cancel_keys`]. By default, each prompt uses its own key bindings, which cancel on `Esc` and `Ctrl+C`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::themes::MinimalTheme;
+    /// use promptuity::{KeyBinding, Promptuity, Term};
+    ///
+    /// let mut term = Term::default();
+    /// let mut theme = MinimalTheme::default();
+    /// let mut p = Promptuity::new(&mut term, &mut theme);
+    ///
+    /// // Only `Ctrl+C` cancels prompts.
+    /// p.with_cancel_keys(&[KeyBinding::ctrl('c')]);
+    /// ```
+    pub fn with_cancel_keys(&mut self, keys: &[KeyBinding]) -> &mut Self {
+        self.cancel_keys = Some(keys.to_vec());
+        self
+    }
+
+    /// Sets whether to enable the accessibility mode for screen readers.  
+    /// When enabled, the terminal title is set to the message of the current prompt before each render, and a plain-text line is written when the session begins.  
+    /// The visual rendering of the theme is not changed.
+    pub fn with_accessibility_mode(&mut self, enabled: bool) -> &mut Self {
+        self.accessibility_mode = enabled;
+        self
+    }
+
+    /// Sets the writer that receives the message and the current input as plain text on each active render in accessibility mode.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::themes::MinimalTheme;
+    /// use promptuity::{Promptuity, Term};
+    ///
+    /// let mut term = Term::default();
+    /// let mut theme = MinimalTheme::default();
+    /// let mut log = std::fs::File::create("prompt.log").unwrap();
+    /// let mut p = Promptuity::new(&mut term, &mut theme);
+    ///
+    /// p.with_accessibility_mode(true).with_accessibility_log(&mut log);
+    /// ```
+    pub fn with_accessibility_log(&mut self, writer: &'a mut dyn std::io::Write) -> &mut Self {
+        self.accessibility_log = Some(writer);
+        self
+    }
+
+    /// Adds a middleware that transforms the snapshot before it is rendered by the theme.  
+    /// Middlewares are applied in the order they are added.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::themes::MinimalTheme;
+    /// use promptuity::{Promptuity, StepCounterMiddleware, Term, TruncateMiddleware};
+    ///
+    /// let mut term = Term::default();
+    /// let mut theme = MinimalTheme::default();
+    /// let mut p = Promptuity::new(&mut term, &mut theme);
+    ///
+    /// p.with_render_middleware(StepCounterMiddleware::new(3))
+    ///     .with_render_middleware(TruncateMiddleware(60));
+    /// ```
+    pub fn with_render_middleware(&mut self, m: impl RenderMiddleware + 'static) -> &mut Self {
+        self.render_middlewares.push(Box::new(m));
+        self
+    }
+
+    /// Sets the page size applied to every paginated prompt, such as [`crate::prompts::Select`] and [`crate::prompts::MultiSelect`].  
+    /// Prompts with a page size set explicitly keep their own value. See [`Prompt::set_page_size`].
+    pub fn with_default_page_size(&mut self, page_size: usize) -> &mut Self {
+        self.default_page_size = Some(page_size);
+        self
+    }
+
+    /// Returns the exit code corresponding to the state of the last prompt.  
+    /// Returns `0` unless the prompt was cancelled or failed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Input;
+    /// use promptuity::themes::MinimalTheme;
+    /// use promptuity::{Error, Promptuity, Term};
+    ///
+    /// # fn main() -> Result<(), promptuity::Error> {
+    /// let mut term = Term::default();
+    /// let mut theme = MinimalTheme::default();
+    /// let mut p = Promptuity::new(&mut term, &mut theme);
+    ///
+    /// p.with_cancel_exit_code(2).begin()?;
+    ///
+    /// match p.prompt(Input::new("Please enter your username").as_mut()) {
+    ///     Ok(_) => p.finish()?,
+    ///     Err(Error::Cancel) => std::process::exit(p.exit_code()),
+    ///     Err(e) => return Err(e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn exit_code(&self) -> i32 {
+        match self.state {
+            PromptState::Cancel => self.cancel_exit_code,
+            PromptState::Fatal(_) => self.error_exit_code,
+            _ => 0,
+        }
+    }
+
     /// Declares the start of a prompt session.  
     /// Executing `begin` activates [Raw Mode](https://docs.rs/crossterm/latest/crossterm/terminal/index.html#raw-mode). Since log outputs like `println!` will no longer render correctly, if you need to output logs, please use log methods such as [`Promptuity::log`] or [`Promptuity::warn`].
     pub fn begin(&mut self) -> Result<(), Error> {
-        self.term.enable_raw()?;
-        self.theme.begin(self.term, self.intro.clone())?;
+        if !self.non_interactive {
+            self.term.enable_raw()?;
+            self.term.enable_bracketed_paste()?;
+            if self.mouse {
+                self.term.enable_mouse_capture()?;
+            }
+        }
+        self.started = true;
+        self.finished = false;
+        if self.accessibility_mode {
+            self.term.writeln("Prompt session started")?;
+        }
+        self.theme.begin_with_intro(self.term, self.intro.clone())?;
         Ok(())
     }
 
     /// Declares the end of a prompt session.  
-    /// Executing `finish` deactivates [Raw Mode](https://docs.rs/crossterm/latest/crossterm/terminal/index.html#raw-mode).
+    /// Executing `finish` deactivates [Raw Mode](https://docs.rs/crossterm/latest/crossterm/terminal/index.html#raw-mode).  
+    /// If a started session is dropped without calling `finish` (e.g. due to a panic), Raw Mode is deactivated and a plain "Prompt session interrupted" message is written instead. The message is omitted when the last prompt was cancelled with [`Promptuity::with_finish_on_cancel`] set to `false`.
     pub fn finish(&mut self) -> Result<(), Error> {
         self.theme
             .finish(self.term, &self.state, self.outro.clone())?;
+        if !self.non_interactive {
+            self.term.disable_bracketed_paste()?;
+            if self.mouse {
+                self.term.disable_mouse_capture()?;
+            }
+        }
         self.term.disable_raw()?;
         self.finished = true;
         Ok(())
@@ -544,12 +1819,67 @@ impl<'a, W: std::io::Write> Promptuity<'a, W> {
         Ok(())
     }
 
+    /// Displays a message as a prompt step, along with a brief hint.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::themes::FancyTheme;
+    /// use promptuity::{Promptuity, Term};
+    ///
+    /// # fn main() -> Result<(), promptuity::Error> {
+    /// let mut term = Term::default();
+    /// let mut theme = FancyTheme::default();
+    /// let mut p = Promptuity::new(&mut term, &mut theme);
+    ///
+    /// p.step_with_hint("Installed packages", "3 new, 1 updated")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn step_with_hint(
+        &mut self,
+        message: impl std::fmt::Display,
+        hint: impl std::fmt::Display,
+    ) -> Result<(), Error> {
+        self.theme
+            .step_with_hint(self.term, message.to_string(), Some(hint.to_string()))?;
+        Ok(())
+    }
+
     /// Output of messages without decoration.
     pub fn log(&mut self, message: impl std::fmt::Display) -> Result<(), Error> {
         self.theme.log(self.term, message.to_string())?;
         Ok(())
     }
 
+    /// Output of a key-value pair.
+    pub fn log_kv(&mut self, key: &str, value: impl std::fmt::Display) -> Result<(), Error> {
+        self.theme.log_kv(self.term, key, &value.to_string())?;
+        Ok(())
+    }
+
+    /// Output of multiple key-value pairs, with the values aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::themes::MinimalTheme;
+    /// use promptuity::{Promptuity, Term};
+    ///
+    /// # fn main() -> Result<(), promptuity::Error> {
+    /// let mut term = Term::default();
+    /// let mut theme = MinimalTheme::default();
+    /// let mut p = Promptuity::new(&mut term, &mut theme);
+    ///
+    /// p.log_kvs(&[("File", "main.rs"), ("Size", "1.2 KB")])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn log_kvs(&mut self, pairs: &[(&str, &str)]) -> Result<(), Error> {
+        self.theme.log_kvs(self.term, pairs)?;
+        Ok(())
+    }
+
     /// Output of messages with info decoration.
     pub fn info(&mut self, message: impl std::fmt::Display) -> Result<(), Error> {
         self.theme.info(self.term, message.to_string())?;
@@ -576,22 +1906,225 @@ impl<'a, W: std::io::Write> Promptuity<'a, W> {
 
     /// Executes the specified prompt and returns the input result.
     pub fn prompt<O>(&mut self, prompt: &mut dyn Prompt<Output = O>) -> Result<O, Error> {
-        prompt.setup()?;
+        self.setup_prompt(prompt)?;
+
+        if self.non_interactive {
+            return self.submit_default(prompt);
+        }
+
+        self.event_loop(prompt)
+    }
+
+    /// Executes the specified prompt, allowing the user to return to the previous prompt.  
+    /// Pressing one of the keys set by [`Promptuity::with_back_keys`] ends the prompt with [`Flow::Back`], rendering it as [`PromptState::Cancel`] with [`RenderSnapshot::back`] set. Otherwise it behaves the same as [`Promptuity::prompt`], returning the value as [`Flow::Next`].
+    ///
+    /// # Examples
+    ///
+    /// Build a wizard by keeping the index of the current step, and decrement it on [`Flow::Back`]:
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::{Confirm, Input};
+    /// use promptuity::themes::MinimalTheme;
+    /// use promptuity::{Flow, Promptuity, Term};
+    ///
+    /// # fn main() -> Result<(), promptuity::Error> {
+    /// let mut term = Term::default();
+    /// let mut theme = MinimalTheme::default();
+    /// let mut p = Promptuity::new(&mut term, &mut theme);
+    ///
+    /// let mut name = String::new();
+    /// let mut publish = false;
+    /// let mut step = 0usize;
+    ///
+    /// p.begin()?;
+    /// while step < 2 {
+    ///     let back = match step {
+    ///         0 => match p.prompt_flow(Input::new("Name").with_default(name.clone()))? {
+    ///             Flow::Next(value) => {
+    ///                 name = value;
+    ///                 false
+    ///             }
+    ///             Flow::Back => true,
+    ///         },
+    ///         _ => match p.prompt_flow(Confirm::new("Publish?").with_default(publish))? {
+    ///             Flow::Next(value) => {
+    ///                 publish = value;
+    ///                 false
+    ///             }
+    ///             Flow::Back => true,
+    ///         },
+    ///     };
+    ///     step = if back { step.saturating_sub(1) } else { step + 1 };
+    /// }
+    /// p.finish()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prompt_flow<O>(
+        &mut self,
+        prompt: &mut dyn Prompt<Output = O>,
+    ) -> Result<Flow<O>, Error> {
+        self.setup_prompt(prompt)?;
+
+        if self.non_interactive {
+            return self.submit_default(prompt).map(Flow::Next);
+        }
+
+        self.run_event_loop(prompt, true)
+    }
+
+    fn setup_prompt<O>(&mut self, prompt: &mut dyn Prompt<Output = O>) -> Result<(), Error> {
+        if let Some(page_size) = self.default_page_size {
+            prompt.set_page_size(page_size);
+        }
+        if let Some(keys) = &self.cancel_keys {
+            prompt.set_cancel_keys(keys);
+        }
+
+        prompt.setup().map_err(|err| match err {
+            Error::Config {
+                message,
+                field,
+                prompt: None,
+                source,
+            } => Error::Config {
+                message,
+                field,
+                prompt: Some(prompt.name().into()),
+                source,
+            },
+            err => err,
+        })
+    }
+
+    /// Executes the specified prompt, returning `default` instead of an error when it is not answered.  
+    /// The default is returned when the prompt is cancelled, without finishing the session regardless of [`Promptuity::with_finish_on_cancel`]. In the non-interactive mode, it is also returned when the default value of the prompt fails validation. See [`Promptuity::with_non_interactive`].
+    pub fn prompt_with_default<O>(
+        &mut self,
+        prompt: &mut dyn Prompt<Output = O>,
+        default: O,
+    ) -> Result<O, Error> {
+        let finish_on_cancel = std::mem::replace(&mut self.finish_on_cancel, false);
+        let result = self.prompt(prompt);
+        self.finish_on_cancel = finish_on_cancel;
+
+        match result {
+            Err(Error::Cancel) => Ok(default),
+            Err(Error::Prompt { .. }) if self.non_interactive => Ok(default),
+            result => result,
+        }
+    }
+
+    fn submit_default<O>(&mut self, prompt: &mut dyn Prompt<Output = O>) -> Result<O, Error> {
+        prompt
+            .validate()
+            .map_err(|err| Error::prompt(err.to_string()))?;
+
+        self.state = PromptState::Submit;
+        self.scroll_offset = 0;
+        self.render(prompt)?;
+
+        Ok(prompt.submit())
+    }
+
+    /// Runs the event loop of the specified prompt and returns the input result.  
+    /// Renders the prompt, then reads events with [`Terminal::read_event`] and passes key presses to [`Prompt::handle`] and pasted text to [`Prompt::handle_paste`], re-rendering on terminal resizes, until the prompt is submitted or cancelled. Validation, warnings and termination are handled the same as in [`Promptuity::prompt`].  
+    /// Unlike [`Promptuity::prompt`], it does not call [`Prompt::setup`], so custom runners can prepare the prompt themselves.
+    pub fn event_loop<O>(&mut self, prompt: &mut dyn Prompt<Output = O>) -> Result<O, Error> {
+        match self.run_event_loop(prompt, false)? {
+            Flow::Next(value) => Ok(value),
+            Flow::Back => unreachable!("back navigation is disabled"),
+        }
+    }
 
+    fn run_event_loop<O>(
+        &mut self,
+        prompt: &mut dyn Prompt<Output = O>,
+        allow_back: bool,
+    ) -> Result<Flow<O>, Error> {
         self.state = PromptState::Active;
+        self.scroll_offset = 0;
+        self.last_input = None;
+        self.cancelled = false;
 
         self.render(prompt)?;
 
         loop {
-            let (code, modifiers) = self.term.read_key()?;
-            let state = prompt.handle(code, modifiers);
+            let debounce = self.debounce_remaining(prompt);
+            let wait = match (self.timeout, debounce) {
+                (Some(timeout), Some(debounce)) => Some(timeout.min(debounce)),
+                (timeout, debounce) => timeout.or(debounce),
+            };
+
+            let event = match wait {
+                Some(wait) => match self.term.read_event_timeout(wait)? {
+                    Some(event) => event,
+                    // The input settled before the prompt timeout, so run the live validation.
+                    None if debounce
+                        .is_some_and(|d| !matches!(self.timeout, Some(t) if d >= t)) =>
+                    {
+                        self.last_input = None;
+                        self.render(prompt)?;
+                        continue;
+                    }
+                    None => return self.handle_timeout(prompt).map(Flow::Next),
+                },
+                None => self.term.read_event()?,
+            };
+
+            if matches!(event, Event::Key(KeyEvent { kind, .. }) if kind != KeyEventKind::Release)
+                || matches!(event, Event::Paste(_))
+            {
+                self.last_input = Some(Instant::now());
+            }
+
+            let state = match event {
+                Event::Key(KeyEvent {
+                    kind: KeyEventKind::Release,
+                    ..
+                }) => continue,
+                Event::Key(KeyEvent {
+                    code, modifiers, ..
+                }) => {
+                    if self.scrollable && self.handle_scroll(code, modifiers) {
+                        self.render(prompt)?;
+                        continue;
+                    }
+
+                    if allow_back && self.back_keys.contains_key(code, modifiers) {
+                        self.state = PromptState::Cancel;
+                        self.back = true;
+                        let result = self.render(prompt);
+                        self.back = false;
+                        return result.map(|_| Flow::Back);
+                    }
+
+                    prompt.handle(code, modifiers)
+                }
+                Event::Paste(text) => prompt.handle_paste(text),
+                Event::Resize(width, height) => {
+                    self.theme.resize(self.term, width, height)?;
+                    self.render(prompt)?;
+                    continue;
+                }
+                Event::Mouse(event) if self.mouse => {
+                    let row = self.body_row(event.row)?;
+                    prompt.handle_mouse(event, row)
+                }
+                _ => continue,
+            };
 
             self.state = match state {
                 PromptState::Submit => {
-                    if let Err(msg) = prompt.validate() {
-                        PromptState::Error(msg)
+                    if let Err(err) = prompt.validate() {
+                        err.into()
                     } else {
-                        PromptState::Submit
+                        match (prompt.warn(), &self.state) {
+                            (Some(msg), state) if !matches!(state, PromptState::Warning(_)) => {
+                                PromptState::Warning(msg)
+                            }
+                            _ => PromptState::Submit,
+                        }
                     }
                 }
                 state => state,
@@ -601,36 +2134,337 @@ impl<'a, W: std::io::Write> Promptuity<'a, W> {
 
             match self.state.clone() {
                 PromptState::Cancel => {
-                    self.finish()?;
+                    self.cancel()?;
                     return Err(Error::Cancel);
                 }
                 PromptState::Fatal(msg) => {
                     self.finish()?;
-                    return Err(Error::Prompt(msg));
+                    return Err(Error::prompt(msg));
                 }
                 PromptState::Submit => {
-                    return Ok(prompt.submit());
+                    return Ok(Flow::Next(prompt.submit()));
                 }
                 _ => {}
             }
         }
     }
 
+    fn handle_timeout<O>(&mut self, prompt: &mut dyn Prompt<Output = O>) -> Result<O, Error> {
+        if self.submit_on_timeout && prompt.validate().is_ok() {
+            self.state = PromptState::Submit;
+            self.render(prompt)?;
+            return Ok(prompt.submit());
+        }
+
+        self.state = PromptState::Cancel;
+        self.render(prompt)?;
+        self.cancel()?;
+        Err(Error::Cancel)
+    }
+
+    fn cancel(&mut self) -> Result<(), Error> {
+        if self.finish_on_cancel {
+            self.finish()
+        } else {
+            self.cancelled = true;
+            Ok(())
+        }
+    }
+
+    fn body_row(&self, row: u16) -> Result<Option<usize>, Error> {
+        let Some(offset) = self.theme.body_offset() else {
+            return Ok(None);
+        };
+        let top = self.term.cursor_pos()?.row.checked_sub(offset);
+        let scroll_offset = if self.scrollable {
+            self.scroll_offset
+        } else {
+            0
+        };
+        Ok(top
+            .and_then(|top| row.checked_sub(top))
+            .map(|row| row as usize + scroll_offset))
+    }
+
+    fn handle_scroll(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        if !modifiers.intersects(KeyModifiers::SHIFT | KeyModifiers::ALT) {
+            return false;
+        }
+        match code {
+            KeyCode::Up => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                // Clamped to the content length on the next render.
+                self.scroll_offset += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn debounce_remaining<O>(&self, prompt: &dyn Prompt<Output = O>) -> Option<Duration> {
+        let debounce = prompt.validation_debounce()?;
+        let last_input = self.last_input?;
+        Some(debounce.saturating_sub(last_input.elapsed()))
+    }
+
     fn render<O>(&mut self, prompt: &mut dyn Prompt<Output = O>) -> Result<(), Error> {
-        let res = prompt.render(&self.state).map_err(Error::Prompt)?;
+        prompt.set_validation_paused(
+            self.debounce_remaining(prompt)
+                .is_some_and(|d| !d.is_zero()),
+        );
+        // The size is unavailable without a TTY, e.g. in CI or when piped.
+        if let Ok(size) = self.term.size() {
+            prompt.set_width(size.width);
+        }
+        let mut res = prompt.render(&self.state).map_err(Error::prompt)?;
+
+        self.scrollable = false;
+        if let PromptBody::Scrollable {
+            content,
+            scroll_offset,
+            visible_lines,
+        } = &mut res.body
+        {
+            let max = content.lines().count().saturating_sub(*visible_lines);
+            self.scroll_offset = self.scroll_offset.min(max);
+            self.scrollable = true;
+            *scroll_offset = self.scroll_offset;
+        }
 
-        self.theme.render(
-            self.term,
-            RenderSnapshot {
-                state: &self.state,
-                input: res.input,
-                body: res.body,
-                message: res.message,
-                hint: res.hint,
-                placeholder: res.placeholder,
-            },
-        )?;
+        if self.accessibility_mode {
+            self.announce(&res)?;
+        }
+
+        let snapshot = RenderSnapshot {
+            state: &self.state,
+            input: res.input,
+            body: res.body,
+            message: res.message,
+            hint: res.hint,
+            placeholder: res.placeholder,
+            icon: res.icon.or_else(|| prompt.icon()),
+            description: res.description,
+            validation_hint: res.validation_hint,
+            page_indicator_before: res.page_indicator_before,
+            page_indicator_after: res.page_indicator_after,
+            back: self.back,
+        };
+
+        let snapshot = self
+            .render_middlewares
+            .iter()
+            .fold(snapshot, |snapshot, m| m.transform(snapshot));
+
+        self.theme.render(self.term, snapshot)?;
 
         Ok(())
     }
+
+    fn announce(&mut self, res: &RenderPayload) -> Result<(), Error> {
+        self.term.set_title(&res.message)?;
+
+        if let (PromptState::Active, Some(log)) = (&self.state, self.accessibility_log.as_mut()) {
+            match &res.input {
+                PromptInput::Cursor(c) if !c.is_empty() => {
+                    writeln!(log, "{}: {}", res.message, c.value())?
+                }
+                PromptInput::Raw(s) if !s.is_empty() => writeln!(log, "{}: {}", res.message, s)?,
+                _ => writeln!(log, "{}", res.message)?,
+            }
+            log.flush()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Promptuity<'a, std::io::Stderr> {
+    /// Creates a new [`PromptSession`] that writes to stderr.
+    pub fn with_stderr<T: Theme<std::io::Stderr>>(theme: T) -> PromptSession<std::io::Stderr, T> {
+        PromptSession::new(Term::stderr(), theme)
+    }
+}
+
+impl<'a> Promptuity<'a, std::io::Stdout> {
+    /// Creates a new [`PromptSession`] that writes to stdout.
+    pub fn with_stdout<T: Theme<std::io::Stdout>>(theme: T) -> PromptSession<std::io::Stdout, T> {
+        PromptSession::new(Term::stdout(), theme)
+    }
+}
+
+/// A struct that owns both the [`Term`] and the [`Theme`] of a prompt session.
+///
+/// Since [`Promptuity`] borrows the terminal and theme, `PromptSession` keeps them alive so that a session can be created without declaring each of them separately.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::Input;
+/// use promptuity::themes::FancyTheme;
+/// use promptuity::Promptuity;
+///
+/// # fn main() -> Result<(), promptuity::Error> {
+/// let mut session = Promptuity::with_stderr(FancyTheme::default());
+/// let mut p = session.promptuity();
+///
+/// p.begin()?;
+/// let name = p.prompt(Input::new("Please enter your username").as_mut())?;
+/// p.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PromptSession<W: std::io::Write, T: Theme<W>> {
+    term: Term<W>,
+    theme: T,
+}
+
+impl<W: std::io::Write, T: Theme<W>> PromptSession<W, T> {
+    /// Creates a new [`PromptSession`] with the given terminal and theme.
+    pub fn new(term: Term<W>, theme: T) -> Self {
+        Self { term, theme }
+    }
+
+    /// Returns a [`Promptuity`] instance that borrows the owned terminal and theme.
+    pub fn promptuity(&mut self) -> Promptuity<'_, W> {
+        Promptuity::new(&mut self.term, &mut self.theme)
+    }
+
+    /// Returns the owned terminal.
+    pub fn term(&mut self) -> &mut Term<W> {
+        &mut self.term
+    }
+
+    /// Returns the owned theme.
+    pub fn theme(&mut self) -> &mut T {
+        &mut self.theme
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_input_cursor_send_sync() {
+        assert_send_sync::<InputCursor>();
+    }
+
+    #[test]
+    fn test_default_char_width_unknown() {
+        // Control characters have no defined width and take one column.
+        assert_eq!(InputCursor::from("\u{7}a".into()).col(), 2);
+    }
+
+    #[test]
+    fn test_word_start_before_cursor() {
+        let tests = vec![
+            ("", 0, 0),
+            ("foo", 0, 0),
+            ("foo", 3, 0),
+            ("foo bar", 7, 4),
+            ("foo bar", 5, 4),
+            ("foo bar", 4, 0),
+            ("foo   bar   ", 12, 6),
+            ("   foo", 6, 3),
+            ("日本語 テスト", 7, 4),
+            ("abc日本 語def", 8, 6),
+        ];
+
+        for (value, cursor, expected) in tests {
+            let input = InputCursor::new(value.into(), cursor);
+            assert_eq!(
+                input.word_start_before_cursor(),
+                expected,
+                "value={:?}, cursor={}",
+                value,
+                cursor
+            );
+        }
+    }
+
+    #[test]
+    fn test_word_end_after_cursor() {
+        let tests = vec![
+            ("", 0, 0),
+            ("foo", 0, 3),
+            ("foo", 3, 3),
+            ("foo bar", 0, 3),
+            ("foo bar", 3, 7),
+            ("foo bar", 5, 7),
+            ("   foo   bar", 0, 6),
+            ("foo   ", 3, 6),
+            ("日本語 テスト", 0, 3),
+            ("abc日本 語def", 4, 5),
+        ];
+
+        for (value, cursor, expected) in tests {
+            let input = InputCursor::new(value.into(), cursor);
+            assert_eq!(
+                input.word_end_after_cursor(),
+                expected,
+                "value={:?}, cursor={}",
+                value,
+                cursor
+            );
+        }
+    }
+
+    #[test]
+    fn test_delete_word() {
+        let mut input = InputCursor::new("foo bar  baz".into(), 4);
+        input.delete_right_word();
+        assert_eq!(input.value(), "foo   baz");
+        assert_eq!(input.cursor(), 4);
+
+        input.delete_left_word();
+        assert_eq!(input.value(), "  baz");
+        assert_eq!(input.cursor(), 0);
+    }
+
+    #[test]
+    fn test_grapheme_clusters() {
+        let family = "👩\u{200d}👩\u{200d}👧";
+        let accent = "e\u{301}";
+
+        let mut input =
+            InputCursor::from(format!("a{family}{accent}b")).with_grapheme_clusters(true);
+        assert_eq!(input.cursor(), 4);
+        assert_eq!(input.col(), 5);
+
+        input.move_left();
+        input.move_left();
+        assert_eq!(input.col(), 3);
+        assert_eq!(
+            input.split(),
+            (format!("a{family}"), accent.into(), "b".into())
+        );
+
+        input.delete_left_char();
+        assert_eq!(input.value(), format!("a{accent}b"));
+        assert_eq!(input.cursor(), 1);
+
+        input.delete_right_char();
+        assert_eq!(input.value(), "ab");
+
+        input.insert('e');
+        input.insert('\u{301}');
+        assert_eq!(input.value(), format!("a{accent}b"));
+        assert_eq!(input.cursor(), 2);
+    }
+
+    #[test]
+    fn test_char_mode_splits_clusters() {
+        let mut input = InputCursor::from("a👩\u{200d}👧".into());
+        assert_eq!(input.cursor(), 4);
+
+        input.delete_left_char();
+        assert_eq!(input.value(), "a👩\u{200d}");
+    }
 }
@@ -7,10 +7,21 @@
 //!
 //! - [`Input`]: A prompt for general text input.
 //! - [`Password`]: A text input prompt where the input is not displayed.
+//! - [`Path`]: A prompt for inputting a filesystem path with tab-completion.
 //! - [`Number`]: A prompt for inputting only integer values.
+//! - [`Float`]: A prompt for inputting decimal values.
+//! - [`Slider`]: A prompt for choosing a number within a range with a horizontal bar.
+//! - [`DatePicker`]: A prompt for choosing a date from a month calendar.
 //! - [`Select`]: A prompt for selecting a single element from a list of options.
+//! - [`SelectIndex`]: A wrapper of [`Select`] that returns the index of the selected option.
 //! - [`MultiSelect`]: A prompt for selecting multiple elements from a list of options.
+//! - [`Sort`]: A prompt for reordering a list of options.
 //! - [`Confirm`]: A prompt for inputting a Yes/No choice.
+//! - [`Toggle`]: A prompt for switching between two inline labels.
+//! - [`Textarea`]: A prompt for multi-line text input.
+//! - [`Autocomplete`]: A prompt for selecting a single element from a list of options filtered by the input.
+//!
+//! In addition, [`Spinner`] is a standalone utility, not a prompt, for indicating progress between prompts. [`SpinnerHandle`] is its counterpart drawn by the theme, created with [`crate::Promptuity::spinner`].
 //!
 //! # Examples
 //!
@@ -36,18 +47,36 @@
 //! # }
 //! ```
 
+mod autocomplete;
 mod confirm;
+mod date_picker;
+mod float;
 mod input;
 mod multi_select;
 mod number;
 mod password;
+mod path;
 mod select;
+mod slider;
+mod sort;
+mod spinner;
 #[cfg(test)]
 pub(crate) mod test;
+mod textarea;
+mod toggle;
 
+pub use autocomplete::*;
 pub use confirm::*;
+pub use date_picker::*;
+pub use float::*;
 pub use input::*;
 pub use multi_select::*;
 pub use number::*;
 pub use password::*;
+pub use path::*;
 pub use select::*;
+pub use slider::*;
+pub use sort::*;
+pub use spinner::*;
+pub use textarea::*;
+pub use toggle::*;
@@ -0,0 +1,560 @@
+use crate::event::*;
+use crate::pagination::paginate;
+use crate::prompts::SelectOption;
+use crate::style::*;
+use crate::{
+    Error, InputCursor, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptBody, PromptInput,
+    PromptState, RenderPayload,
+};
+
+const S_UNSELECTED: Symbol = Symbol("◯", "[ ]");
+const S_SELECTED: Symbol = Symbol("◉", "[x]");
+const S_ICON: Symbol = Symbol("▶", ">");
+
+/// A trait for matching the options of [`Autocomplete`] against the input.
+///
+/// It is implemented for closures, so any matching algorithm, such as fuzzy matching provided by another crate, can be plugged in.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{Autocomplete, SelectOption};
+///
+/// let _ = Autocomplete::new("...", vec![SelectOption::new("...", "...")])
+///     .with_matcher(|label: &str, pattern: &str| label.ends_with(pattern).then_some(0));
+/// ```
+pub trait AutocompleteMatcher {
+    /// Returns the score of `label` for the `pattern`, or `None` if it does not match.  
+    /// Options with higher scores are listed first. Options with the same score keep their original order.
+    fn score(&self, label: &str, pattern: &str) -> Option<i64>;
+}
+
+impl<F> AutocompleteMatcher for F
+where
+    F: Fn(&str, &str) -> Option<i64>,
+{
+    fn score(&self, label: &str, pattern: &str) -> Option<i64> {
+        self(label, pattern)
+    }
+}
+
+/// A case-insensitive matcher for options containing the input. This is the default matcher.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SubstringMatcher;
+
+impl AutocompleteMatcher for SubstringMatcher {
+    fn score(&self, label: &str, pattern: &str) -> Option<i64> {
+        label
+            .to_lowercase()
+            .contains(&pattern.to_lowercase())
+            .then_some(0)
+    }
+}
+
+/// A case-insensitive matcher for options starting with the input.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PrefixMatcher;
+
+impl AutocompleteMatcher for PrefixMatcher {
+    fn score(&self, label: &str, pattern: &str) -> Option<i64> {
+        label
+            .to_lowercase()
+            .starts_with(&pattern.to_lowercase())
+            .then_some(0)
+    }
+}
+
+/// A trait for customizing the display of [`Autocomplete`].
+///
+/// All methods have default implementations, allowing you to override only the specific formatting process you need.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{Autocomplete, AutocompleteFormatter, SelectOption};
+///
+/// struct CustomFormatter;
+///
+/// impl AutocompleteFormatter for CustomFormatter {
+///     fn no_matches(&self) -> String {
+///         "Nothing found.".into()
+///     }
+/// }
+///
+/// let _ = Autocomplete::new("...", vec![SelectOption::new("...", "...")]).with_formatter(CustomFormatter);
+/// ```
+pub trait AutocompleteFormatter {
+    /// Icons displayed for each option.
+    fn option_icon(&self, active: bool) -> String {
+        if active {
+            Styled::new(S_SELECTED).fg(Color::Green).to_string()
+        } else {
+            Styled::new(S_UNSELECTED).fg(Color::DarkGrey).to_string()
+        }
+    }
+
+    /// Formats the label of the option.
+    fn option_label(&self, label: String, active: bool) -> String {
+        if active {
+            Styled::new(label).underline().to_string()
+        } else {
+            Styled::new(label).fg(Color::DarkGrey).to_string()
+        }
+    }
+
+    /// Formats the hint message of the option.
+    fn option_hint(&self, hint: Option<String>, active: bool) -> String {
+        let _ = active;
+        hint.as_ref().map_or_else(String::new, |hint| {
+            format!(
+                " {}",
+                Styled::new(format!("({})", hint)).fg(Color::DarkGrey)
+            )
+        })
+    }
+
+    /// Formats the option.
+    fn option(&self, icon: String, label: String, hint: String, active: bool) -> String {
+        let _ = active;
+        format!("{} {}{}", icon, label, hint)
+    }
+
+    /// Formats the message displayed in place of the options when nothing matches the input.
+    fn no_matches(&self) -> String {
+        Styled::new("<No matches found>")
+            .fg(Color::DarkGrey)
+            .to_string()
+    }
+
+    /// Formats the error message when submitting while nothing matches the input.
+    fn err_no_matches(&self) -> String {
+        "No matches found.".into()
+    }
+}
+
+/// The default formatter for [`Autocomplete`].
+#[derive(Default)]
+pub struct DefaultAutocompleteFormatter;
+
+impl DefaultAutocompleteFormatter {
+    /// Creates a new [`DefaultAutocompleteFormatter`].
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl AutocompleteFormatter for DefaultAutocompleteFormatter {}
+
+/// A prompt for selecting a single element from a list of options filtered by the input.
+///
+/// Typed characters are inserted into the input and filter the options. Options are moved with <kbd>↑</kbd> / <kbd>↓</kbd> or <kbd>Ctrl</kbd> + <kbd>P</kbd> / <kbd>N</kbd>.
+///
+/// # Options
+///
+/// - **Formatter**: Customizes the prompt display. See [`AutocompleteFormatter`].
+/// - **Matcher**: Filters and orders the options by the input. See [`AutocompleteMatcher`]. Defaults to [`SubstringMatcher`].
+/// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the input. Defaults to `None`.
+/// - **Placeholder**: An auxiliary message displayed when no input is given.
+/// - **Page Size**: The total number of options to displayed per page, used for pagination. Defaults to `8`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`]. Bindings without modifiers for character keys are ignored, since those keys are used for input.
+///
+/// # Notes
+///
+/// Passing an empty `options` will result in an error. Please ensure to provide `options` with at least one item.  
+/// Disabled options (see [`SelectOption::disabled`]) are never offered as completions.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{Autocomplete, PrefixMatcher, SelectOption};
+///
+/// let _ = Autocomplete::new("Which language do you use?", vec![
+///     SelectOption::new("Rust", "rust"),
+///     SelectOption::new("Ruby", "ruby"),
+///     SelectOption::new("Go", "go"),
+/// ])
+/// .with_matcher(PrefixMatcher)
+/// .with_placeholder("Type to filter");
+/// ```
+pub struct Autocomplete<T: Default + Clone> {
+    formatter: Box<dyn AutocompleteFormatter>,
+    matcher: Box<dyn AutocompleteMatcher>,
+    message: String,
+    hint: Option<String>,
+    description: Option<String>,
+    placeholder: Option<String>,
+    page_size: usize,
+    page_size_explicit: bool,
+    options: Vec<SelectOption<T>>,
+    filtered_options: Vec<usize>,
+    keybindings: KeyBindings,
+    index: usize,
+    input: InputCursor,
+}
+
+impl<T: Default + Clone> Autocomplete<T> {
+    /// Creates a new [`Autocomplete`] prompt with the given message and options.
+    pub fn new(message: impl std::fmt::Display, options: Vec<SelectOption<T>>) -> Self {
+        let filtered_options = options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| !option.disabled)
+            .map(|(i, _)| i)
+            .collect();
+        Self {
+            formatter: Box::<DefaultAutocompleteFormatter>::default(),
+            matcher: Box::<SubstringMatcher>::default(),
+            message: message.to_string(),
+            hint: None,
+            description: None,
+            placeholder: None,
+            page_size: 8,
+            page_size_explicit: false,
+            options,
+            filtered_options,
+            keybindings: KeyBindings::default(),
+            index: 0,
+            input: InputCursor::default(),
+        }
+    }
+
+    /// Sets the formatter for the prompt.
+    pub fn with_formatter(&mut self, formatter: impl AutocompleteFormatter + 'static) -> &mut Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Sets the matcher for the prompt.
+    pub fn with_matcher(&mut self, matcher: impl AutocompleteMatcher + 'static) -> &mut Self {
+        self.matcher = Box::new(matcher);
+        self.run_filter();
+        self
+    }
+
+    /// Sets the hint message for the prompt.
+    pub fn with_hint(&mut self, hint: impl std::fmt::Display) -> &mut Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the input.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Sets the placeholder message for the prompt.
+    pub fn with_placeholder(&mut self, placeholder: impl std::fmt::Display) -> &mut Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Sets the page size for the prompt.
+    pub fn with_page_size(&mut self, page_size: usize) -> &mut Self {
+        self.page_size = page_size;
+        self.page_size_explicit = true;
+        self
+    }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+}
+
+impl<T: Default + Clone> Autocomplete<T> {
+    fn run_filter(&mut self) {
+        let pattern = self.input.value();
+
+        if pattern.is_empty() {
+            self.filtered_options = self
+                .options
+                .iter()
+                .enumerate()
+                .filter(|(_, option)| !option.disabled)
+                .map(|(i, _)| i)
+                .collect();
+        } else {
+            let mut scored = self
+                .options
+                .iter()
+                .enumerate()
+                .filter(|(_, option)| !option.disabled)
+                .filter_map(|(i, option)| {
+                    self.matcher
+                        .score(&option.label, &pattern)
+                        .map(|score| (i, score))
+                })
+                .collect::<Vec<_>>();
+            scored.sort_by(|(_, a), (_, b)| b.cmp(a));
+            self.filtered_options = scored.into_iter().map(|(i, _)| i).collect();
+        }
+
+        self.index = std::cmp::min(self.filtered_options.len().saturating_sub(1), self.index);
+    }
+
+    fn current_option(&self) -> Option<&SelectOption<T>> {
+        self.filtered_options
+            .get(self.index)
+            .and_then(|idx| self.options.get(*idx))
+    }
+}
+
+impl<T: Default + Clone> AsMut<Autocomplete<T>> for Autocomplete<T> {
+    fn as_mut(&mut self) -> &mut Autocomplete<T> {
+        self
+    }
+}
+
+impl<T: Default + Clone> Prompt for Autocomplete<T> {
+    type Output = T;
+
+    fn name(&self) -> &'static str {
+        "Autocomplete"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
+    fn set_page_size(&mut self, page_size: usize) {
+        if !self.page_size_explicit {
+            self.page_size = page_size;
+        }
+    }
+
+    fn setup(&mut self) -> Result<(), Error> {
+        if self.options.is_empty() {
+            return Err(Error::Config {
+                message: "options cannot be empty.".into(),
+                field: Some("options".into()),
+                prompt: Some("Autocomplete".into()),
+                source: None,
+            });
+        }
+
+        if self.options.iter().all(|option| option.disabled) {
+            return Err(Error::Config {
+                message: "options cannot be all disabled.".into(),
+                field: Some("options".into()),
+                prompt: Some("Autocomplete".into()),
+                source: None,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
+        let kb = &self.keybindings;
+        let typing = modifiers == KeyModifiers::NONE || modifiers == KeyModifiers::SHIFT;
+        match code {
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.submit.contains_key(code, modifiers) => match self.current_option() {
+                Some(_) => PromptState::Submit,
+                None => PromptState::Error(self.formatter.err_no_matches()),
+            },
+            KeyCode::Char(c) if typing => {
+                self.input.insert(c);
+                self.run_filter();
+                PromptState::Active
+            }
+            _ if kb.move_up.contains_key(code, modifiers) => {
+                self.index = self.index.saturating_sub(1);
+                PromptState::Active
+            }
+            _ if kb.move_down.contains_key(code, modifiers) => {
+                self.index = std::cmp::min(
+                    self.filtered_options.len().saturating_sub(1),
+                    self.index.saturating_add(1),
+                );
+                PromptState::Active
+            }
+            _ if kb.move_prev_word.contains_key(code, modifiers) => {
+                self.input.move_prev_word();
+                PromptState::Active
+            }
+            _ if kb.move_next_word.contains_key(code, modifiers) => {
+                self.input.move_next_word();
+                PromptState::Active
+            }
+            _ if kb.move_left.contains_key(code, modifiers) => {
+                self.input.move_left();
+                PromptState::Active
+            }
+            _ if kb.move_right.contains_key(code, modifiers) => {
+                self.input.move_right();
+                PromptState::Active
+            }
+            _ if kb.move_home.contains_key(code, modifiers) => {
+                self.input.move_home();
+                PromptState::Active
+            }
+            _ if kb.move_end.contains_key(code, modifiers) => {
+                self.input.move_end();
+                PromptState::Active
+            }
+            _ if kb.delete_left_char.contains_key(code, modifiers) => {
+                self.input.delete_left_char();
+                self.run_filter();
+                PromptState::Active
+            }
+            _ if kb.delete_left_word.contains_key(code, modifiers) => {
+                self.input.delete_left_word();
+                self.run_filter();
+                PromptState::Active
+            }
+            _ if kb.delete_right_char.contains_key(code, modifiers) => {
+                self.input.delete_right_char();
+                self.run_filter();
+                PromptState::Active
+            }
+            _ if kb.delete_rest_line.contains_key(code, modifiers) => {
+                self.input.delete_rest_line();
+                self.run_filter();
+                PromptState::Active
+            }
+            _ if kb.delete_line.contains_key(code, modifiers) => {
+                self.input.delete_line();
+                self.run_filter();
+                PromptState::Active
+            }
+            _ => PromptState::Active,
+        }
+    }
+
+    fn handle_paste(&mut self, text: String) -> PromptState {
+        let text = text.chars().filter(|c| !c.is_control()).collect::<String>();
+        self.input.insert_str(&text);
+        self.run_filter();
+        PromptState::Active
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        self.current_option().unwrap().value.clone()
+    }
+
+    fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
+        let payload = RenderPayload::new(
+            self.message.clone(),
+            self.hint.clone(),
+            self.placeholder.clone(),
+        )
+        .description(self.description.clone());
+
+        match state {
+            PromptState::Submit => {
+                let option = self.current_option().unwrap();
+                Ok(payload.input(PromptInput::Raw(option.label.clone())))
+            }
+
+            _ => {
+                let page = paginate(self.page_size, &self.filtered_options, self.index);
+                let options = page
+                    .items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, idx)| {
+                        let option = self.options.get(*idx).unwrap();
+                        let active = i == page.cursor;
+                        self.formatter.option(
+                            self.formatter.option_icon(active),
+                            self.formatter.option_label(option.label.clone(), active),
+                            self.formatter.option_hint(option.hint.clone(), active),
+                            active,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                let raw = if options.is_empty() {
+                    self.formatter.no_matches()
+                } else {
+                    options.join("\n")
+                };
+
+                Ok(payload
+                    .input(PromptInput::Cursor(self.input.clone()))
+                    .body(PromptBody::Raw(raw)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prompt;
+
+    macro_rules! options {
+        ($($label: expr),*) => {{
+            vec![$(SelectOption::new($label, $label.to_lowercase())),*]
+        }};
+    }
+
+    test_prompt!(
+        test_filter,
+        Autocomplete::new("test message", options!("Rust", "Ruby", "Go", "TypeScript")).as_mut(),
+        vec![
+            (KeyCode::Char('r'), KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Char('U'), KeyModifiers::SHIFT),
+            (KeyCode::Char('s'), KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_no_matches,
+        Autocomplete::new("test message", options!("Rust", "Go")).as_mut(),
+        vec![
+            (KeyCode::Char('x'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char('u'), KeyModifiers::CONTROL),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_prefix_matcher,
+        Autocomplete::new("test message", options!("Rust", "TypeScript", "Ruby"))
+            .with_matcher(PrefixMatcher),
+        vec![
+            (KeyCode::Char('r'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_page_size,
+        Autocomplete::new("test message", options!("a1", "a2", "a3", "a4", "b1")).with_page_size(2),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_matcher_score_order() {
+        let mut prompt = Autocomplete::new("test message", options!("ab", "abc", "abcd"));
+        prompt.with_matcher(|label: &str, pattern: &str| {
+            label.starts_with(pattern).then_some(label.len() as i64)
+        });
+        prompt.handle(KeyCode::Char('a'), KeyModifiers::NONE);
+        prompt.handle(KeyCode::Char('b'), KeyModifiers::NONE);
+        assert_eq!(
+            prompt.handle(KeyCode::Enter, KeyModifiers::NONE),
+            PromptState::Submit
+        );
+        assert_eq!(prompt.submit(), "abcd");
+    }
+}
@@ -1,9 +1,12 @@
 use crate::event::*;
 use crate::style::{Color, Styled, Symbol};
-use crate::{Prompt, PromptInput, PromptState, RenderPayload};
+use crate::{
+    KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptInput, PromptState, RenderPayload,
+};
 
 const S_ACTIVE: Symbol = Symbol("●", ">");
 const S_INACTIVE: Symbol = Symbol("○", " ");
+const S_ICON: Symbol = Symbol("●", "o");
 
 /// A trait for formatting the [`Confirm`] prompt.
 ///
@@ -50,6 +53,11 @@ pub trait ConfirmFormatter {
         format!("{}  /  {}", yes, no)
     }
 
+    /// Formats the error message when submitting without a choice in required mode.
+    fn err_required(&self) -> String {
+        "Please choose Yes or No.".into()
+    }
+
     /// Formats the submitted value.
     fn submit(&self, value: bool) -> String {
         if value {
@@ -61,10 +69,11 @@ pub trait ConfirmFormatter {
 }
 
 /// The default formatter for [`Confirm`].
+#[derive(Default)]
 pub struct DefaultConfirmFormatter;
 
 impl DefaultConfirmFormatter {
-    #[allow(clippy::new_without_default)]
+    /// Creates a new [`DefaultConfirmFormatter`].
     pub fn new() -> Self {
         Self
     }
@@ -78,7 +87,11 @@ impl ConfirmFormatter for DefaultConfirmFormatter {}
 ///
 /// - **Formatter**: Customizes the prompt display. See [`ConfirmFormatter`].
 /// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the input. Defaults to `None`.
 /// - **Default Value**: The default value of `bool`. Defaults to `false`.
+/// - **Required**: A flag indicating whether to start with neither option chosen, requiring an explicit choice. Defaults to `false`.
+/// - **Keys**: The keys that submit "Yes" and "No". Defaults to `y` and `n`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
 ///
 /// # Examples
 ///
@@ -91,7 +104,9 @@ pub struct Confirm {
     formatter: Box<dyn ConfirmFormatter>,
     message: String,
     hint: Option<String>,
-    value: bool,
+    description: Option<String>,
+    keybindings: KeyBindings,
+    value: Option<bool>,
 }
 
 impl Confirm {
@@ -101,7 +116,9 @@ impl Confirm {
             formatter: Box::new(DefaultConfirmFormatter),
             message: message.to_string(),
             hint: None,
-            value: false,
+            description: None,
+            keybindings: KeyBindings::default(),
+            value: Some(false),
         }
     }
 
@@ -117,11 +134,68 @@ impl Confirm {
         self
     }
 
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the input.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
     /// Sets the default value for the prompt.
     pub fn with_default(&mut self, value: bool) -> &mut Self {
-        self.value = value;
+        self.value = Some(value);
+        self
+    }
+
+    /// Sets whether to require an explicit choice.  
+    /// When enabled, neither option is chosen initially, and submitting before choosing with the keys displays an error. This clears a default value set before it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Confirm;
+    ///
+    /// let _ = Confirm::new("Delete all files?").with_required(true);
+    /// ```
+    pub fn with_required(&mut self, required: bool) -> &mut Self {
+        self.value = match (required, self.value) {
+            (true, _) => None,
+            (false, value) => Some(value.unwrap_or(false)),
+        };
         self
     }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+
+    /// Sets the keys that submit "Yes" and "No", replacing <kbd>y</kbd> and <kbd>n</kbd>.  
+    /// Both the lowercase and uppercase forms of each key are bound. Since this updates the key bindings, call it after [`Confirm::with_keybindings`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Confirm;
+    ///
+    /// let _ = Confirm::new("Êtes-vous sûr ?").with_keys('o', 'n');
+    /// ```
+    pub fn with_keys(&mut self, yes: char, no: char) -> &mut Self {
+        self.keybindings.submit_yes = Self::char_bindings(yes);
+        self.keybindings.submit_no = Self::char_bindings(no);
+        self
+    }
+
+    fn char_bindings(c: char) -> Vec<KeyBinding> {
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        let upper = c.to_uppercase().next().unwrap_or(c);
+        let mut bindings = vec![KeyBinding::new(KeyCode::Char(lower), KeyModifiers::NONE)];
+        if upper != lower {
+            bindings.push(KeyBinding::new(KeyCode::Char(upper), KeyModifiers::NONE));
+        }
+        bindings
+    }
 }
 
 impl AsMut<Confirm> for Confirm {
@@ -133,28 +207,40 @@ impl AsMut<Confirm> for Confirm {
 impl Prompt for Confirm {
     type Output = bool;
 
+    fn name(&self) -> &'static str {
+        "Confirm"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
     fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
-        match (code, modifiers) {
-            (KeyCode::Enter, _) => PromptState::Submit,
-            (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => PromptState::Cancel,
-            (KeyCode::Char('y'), KeyModifiers::NONE) | (KeyCode::Char('Y'), KeyModifiers::NONE) => {
-                self.value = true;
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.submit.contains_key(code, modifiers) => match self.value {
+                Some(_) => PromptState::Submit,
+                None => PromptState::Error(self.formatter.err_required()),
+            },
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.submit_yes.contains_key(code, modifiers) => {
+                self.value = Some(true);
                 PromptState::Submit
             }
-            (KeyCode::Char('n'), KeyModifiers::NONE) | (KeyCode::Char('N'), KeyModifiers::NONE) => {
-                self.value = false;
+            _ if kb.submit_no.contains_key(code, modifiers) => {
+                self.value = Some(false);
                 PromptState::Submit
             }
-            (KeyCode::Left, _)
-            | (KeyCode::Char('h'), _)
-            | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
-                self.value = true;
+            _ if kb.choose_yes.contains_key(code, modifiers) => {
+                self.value = Some(true);
                 PromptState::Active
             }
-            (KeyCode::Right, _)
-            | (KeyCode::Char('l'), _)
-            | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
-                self.value = false;
+            _ if kb.choose_no.contains_key(code, modifiers) => {
+                self.value = Some(false);
                 PromptState::Active
             }
             _ => PromptState::Active,
@@ -162,31 +248,30 @@ impl Prompt for Confirm {
     }
 
     fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
-        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), None);
+        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), None)
+            .description(self.description.clone());
 
         match state {
-            PromptState::Submit => {
-                Ok(payload.input(PromptInput::Raw(self.formatter.submit(self.value))))
-            }
-
-            PromptState::Cancel => Ok(payload),
+            PromptState::Submit => Ok(payload.input(PromptInput::Raw(
+                self.formatter.submit(self.value.unwrap_or_default()),
+            ))),
 
             _ => Ok(payload.input(PromptInput::Raw(self.formatter.layout(
-                self.formatter.yes(self.value),
-                self.formatter.no(!self.value),
+                self.formatter.yes(self.value == Some(true)),
+                self.formatter.no(self.value == Some(false)),
             )))),
         }
     }
 
     fn submit(&mut self) -> Self::Output {
-        self.value
+        self.value.unwrap_or_default()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_prompt;
+    use crate::{assert_prompt_output, test_prompt};
 
     test_prompt!(
         test_hint,
@@ -245,4 +330,97 @@ mod tests {
         Confirm::new("test message").with_default(true),
         vec![(KeyCode::Char('N'), KeyModifiers::NONE)]
     );
+
+    test_prompt!(
+        test_cancel,
+        Confirm::new("test message").with_default(true),
+        vec![
+            (KeyCode::Right, KeyModifiers::NONE),
+            (KeyCode::Esc, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_direct_submit_states() {
+        assert_prompt_output!(
+            Confirm::new("test message").as_mut(),
+            vec![(KeyCode::Char('y'), KeyModifiers::NONE)],
+            vec![PromptState::Submit]
+        );
+
+        assert_prompt_output!(
+            Confirm::new("test message").with_default(true),
+            vec![
+                (KeyCode::Left, KeyModifiers::NONE),
+                (KeyCode::Char('x'), KeyModifiers::NONE),
+                (KeyCode::Char('y'), KeyModifiers::CONTROL),
+                (KeyCode::Char('N'), KeyModifiers::NONE),
+            ],
+            vec![
+                PromptState::Active,
+                PromptState::Active,
+                PromptState::Active,
+                PromptState::Submit,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_keys() {
+        assert_prompt_output!(
+            Confirm::new("test message").with_keys('o', 'n'),
+            vec![
+                (KeyCode::Char('y'), KeyModifiers::NONE),
+                (KeyCode::Left, KeyModifiers::NONE),
+                (KeyCode::Char('O'), KeyModifiers::NONE),
+            ],
+            vec![
+                PromptState::Active,
+                PromptState::Active,
+                PromptState::Submit,
+            ]
+        );
+
+        let mut prompt = Confirm::new("test message");
+        prompt.with_default(true).with_keys('o', 'n');
+        assert_eq!(
+            prompt.handle(KeyCode::Char('N'), KeyModifiers::NONE),
+            PromptState::Submit
+        );
+        assert!(!prompt.submit());
+    }
+
+    test_prompt!(
+        test_required,
+        Confirm::new("test message").with_required(true),
+        vec![
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_required_default() {
+        let mut prompt = Confirm::new("test message");
+        prompt.with_default(true).with_required(true);
+        assert_eq!(
+            prompt.handle(KeyCode::Enter, KeyModifiers::NONE),
+            PromptState::Error("Please choose Yes or No.".into())
+        );
+
+        let mut prompt = Confirm::new("test message");
+        prompt.with_required(true).with_default(true);
+        assert_eq!(
+            prompt.handle(KeyCode::Enter, KeyModifiers::NONE),
+            PromptState::Submit
+        );
+        assert!(prompt.submit());
+    }
+
+    test_prompt!(
+        test_description,
+        Confirm::new("test message").with_description("line1\nline2"),
+        vec![(KeyCode::Enter, KeyModifiers::NONE)]
+    );
 }
@@ -0,0 +1,548 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::event::*;
+use crate::style::{Color, Styled, Symbol};
+use crate::{
+    Error, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptBody, PromptInput, PromptState,
+    RenderPayload,
+};
+
+const S_ICON: Symbol = Symbol("?", "?");
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A date represented as `(year, month, day)`, where `month` and `day` start from `1`.
+pub type Date = (i32, u32, u32);
+
+/// A trait for formatting the [`DatePicker`] prompt.
+///
+/// All methods have default implementations, allowing you to override only the specific formatting process you need.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{DatePicker, DatePickerFormatter};
+///
+/// struct CustomFormatter;
+///
+/// impl DatePickerFormatter for CustomFormatter {
+///     fn value(&self, (year, month, day): (i32, u32, u32)) -> String {
+///         format!("{}/{}/{}", month, day, year)
+///     }
+/// }
+///
+/// let _ = DatePicker::new("Pick a date").with_formatter(CustomFormatter);
+/// ```
+pub trait DatePickerFormatter {
+    /// Formats the title of the calendar, displayed above the grid.
+    fn title(&self, year: i32, month: u32) -> String {
+        format!("{} {}", MONTHS[month as usize - 1], year)
+    }
+
+    /// Formats the header row of the weekdays, starting from Sunday.
+    fn weekdays(&self) -> String {
+        Styled::new("Su Mo Tu We Th Fr Sa")
+            .fg(Color::DarkGrey)
+            .to_string()
+    }
+
+    /// Formats a day cell of the grid. `disabled` is `true` when the day is out of the bounds.
+    fn day(&self, day: u32, focused: bool, today: bool, disabled: bool) -> String {
+        let label = format!("{:>2}", day);
+        if focused {
+            Styled::new(label).fg(Color::Cyan).rev().to_string()
+        } else if disabled {
+            Styled::new(label).fg(Color::DarkGrey).to_string()
+        } else if today {
+            Styled::new(label).fg(Color::Cyan).underline().to_string()
+        } else {
+            label
+        }
+    }
+
+    /// Formats the selected date displayed on submission.
+    fn value(&self, (year, month, day): Date) -> String {
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+/// The default formatter for [`DatePicker`].
+#[derive(Default)]
+pub struct DefaultDatePickerFormatter;
+
+impl DefaultDatePickerFormatter {
+    /// Creates a new [`DefaultDatePickerFormatter`].
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl DatePickerFormatter for DefaultDatePickerFormatter {}
+
+/// A prompt for choosing a date from a month calendar.
+///
+/// <kbd>Left</kbd> and <kbd>Right</kbd> move the focus by a day, <kbd>Up</kbd> and <kbd>Down</kbd> by a week, and <kbd>PageUp</kbd> and <kbd>PageDown</kbd> by a month. <kbd>Home</kbd> and <kbd>End</kbd> move it to the first and last day of the month. The focus never leaves the bounds.
+///
+/// The selected date is returned as a `(year, month, day)` tuple. See [`Date`].
+///
+/// # Options
+///
+/// - **Formatter**: Customizes the prompt display. See [`DatePickerFormatter`].
+/// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the calendar. Defaults to `None`.
+/// - **Default Value**: The initially focused date, clamped to the bounds. Defaults to today.
+/// - **Today**: The date highlighted as today. Defaults to the current date in UTC, since the local time zone is not available without additional dependencies. Set it with [`DatePicker::with_today`] to use the local date.
+/// - **Min**: The earliest date that can be chosen. Defaults to `None`.
+/// - **Max**: The latest date that can be chosen. Defaults to `None`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::DatePicker;
+///
+/// let _ = DatePicker::new("Pick a date")
+///     .with_default((2024, 2, 14))
+///     .with_min((2024, 1, 1))
+///     .with_max((2024, 12, 31));
+/// ```
+pub struct DatePicker {
+    formatter: Box<dyn DatePickerFormatter>,
+    message: String,
+    hint: Option<String>,
+    description: Option<String>,
+    min: Option<Date>,
+    max: Option<Date>,
+    keybindings: KeyBindings,
+    today: Date,
+    default: Option<Date>,
+    value: Date,
+}
+
+impl DatePicker {
+    /// Creates a new [`DatePicker`] prompt.
+    pub fn new(message: impl std::fmt::Display) -> Self {
+        let today = today();
+        Self {
+            formatter: Box::new(DefaultDatePickerFormatter),
+            message: message.to_string(),
+            hint: None,
+            description: None,
+            min: None,
+            max: None,
+            keybindings: KeyBindings::default(),
+            today,
+            default: None,
+            value: today,
+        }
+    }
+
+    /// Sets the formatter for the prompt.
+    pub fn with_formatter(&mut self, formatter: impl DatePickerFormatter + 'static) -> &mut Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Sets the hint message for the prompt.
+    pub fn with_hint(&mut self, hint: impl std::fmt::Display) -> &mut Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the calendar.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Sets the initially focused date. It is clamped to the bounds when the prompt starts.
+    pub fn with_default(&mut self, date: Date) -> &mut Self {
+        self.default = Some(date);
+        self.value = date;
+        self
+    }
+
+    /// Sets the date highlighted as today, which is also the default value unless [`DatePicker::with_default`] is set.  
+    /// By default, today is the current date in UTC, which can differ from the local date near midnight. Pass the local date, e.g. from the `chrono` or `time` crate, to match the user's time zone.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::DatePicker;
+    ///
+    /// let _ = DatePicker::new("Pick a date").with_today((2024, 2, 14));
+    /// ```
+    pub fn with_today(&mut self, date: Date) -> &mut Self {
+        self.today = date;
+        if self.default.is_none() {
+            self.value = date;
+        }
+        self
+    }
+
+    /// Sets the earliest date that can be chosen.
+    pub fn with_min(&mut self, date: Date) -> &mut Self {
+        self.min = Some(date);
+        self
+    }
+
+    /// Sets the latest date that can be chosen.
+    pub fn with_max(&mut self, date: Date) -> &mut Self {
+        self.max = Some(date);
+        self
+    }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+
+    fn clamp(&self, date: Date) -> Date {
+        let mut date = date;
+        if let Some(min) = self.min {
+            date = date.max(min);
+        }
+        if let Some(max) = self.max {
+            date = date.min(max);
+        }
+        date
+    }
+
+    fn in_bounds(&self, date: Date) -> bool {
+        self.clamp(date) == date
+    }
+
+    fn move_days(&mut self, delta: i64) {
+        self.value = self.clamp(from_days(to_days(self.value) + delta));
+    }
+
+    fn move_months(&mut self, delta: i32) {
+        let (year, month, day) = self.value;
+        let index = year * 12 + month as i32 - 1 + delta;
+        let (year, month) = (index.div_euclid(12), index.rem_euclid(12) as u32 + 1);
+        self.value = self.clamp((year, month, day.min(days_in_month(year, month))));
+    }
+
+    fn render_calendar(&self) -> String {
+        let (year, month, _) = self.value;
+        let offset = weekday(year, month, 1) as usize;
+        let days = days_in_month(year, month);
+
+        let mut cells = vec!["  ".to_string(); offset];
+        cells.extend((1..=days).map(|day| {
+            let date = (year, month, day);
+            self.formatter.day(
+                day,
+                date == self.value,
+                date == self.today,
+                !self.in_bounds(date),
+            )
+        }));
+
+        let mut lines = vec![self.formatter.title(year, month), self.formatter.weekdays()];
+        lines.extend(
+            cells
+                .chunks(7)
+                .map(|week| week.join(" ").trim_end().to_string()),
+        );
+        lines.join("\n")
+    }
+}
+
+impl AsMut<DatePicker> for DatePicker {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl Prompt for DatePicker {
+    type Output = Date;
+
+    fn name(&self) -> &'static str {
+        "DatePicker"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
+    fn setup(&mut self) -> Result<(), Error> {
+        for (field, date) in [
+            ("default", Some(self.value)),
+            ("min", self.min),
+            ("max", self.max),
+        ] {
+            if let Some((year, month, day)) = date {
+                if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+                    return Err(Error::Config {
+                        message: format!(
+                            "{} is not a valid date ({}-{}-{})",
+                            field, year, month, day
+                        ),
+                        field: Some(field.into()),
+                        prompt: Some("DatePicker".into()),
+                        source: None,
+                    });
+                }
+            }
+        }
+
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min > max {
+                return Err(Error::Config {
+                    message: format!(
+                        "min cannot be later than max (min={}, max={})",
+                        self.formatter.value(min),
+                        self.formatter.value(max)
+                    ),
+                    field: Some("min".into()),
+                    prompt: Some("DatePicker".into()),
+                    source: None,
+                });
+            }
+        }
+
+        self.value = self.clamp(self.value);
+
+        Ok(())
+    }
+
+    fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.submit.contains_key(code, modifiers) => PromptState::Submit,
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.move_left.contains_key(code, modifiers) => {
+                self.move_days(-1);
+                PromptState::Active
+            }
+            _ if kb.move_right.contains_key(code, modifiers) => {
+                self.move_days(1);
+                PromptState::Active
+            }
+            _ if kb.move_up.contains_key(code, modifiers) => {
+                self.move_days(-7);
+                PromptState::Active
+            }
+            _ if kb.move_down.contains_key(code, modifiers) => {
+                self.move_days(7);
+                PromptState::Active
+            }
+            _ if kb.page_up.contains_key(code, modifiers) => {
+                self.move_months(-1);
+                PromptState::Active
+            }
+            _ if kb.page_down.contains_key(code, modifiers) => {
+                self.move_months(1);
+                PromptState::Active
+            }
+            _ if kb.move_home.contains_key(code, modifiers) => {
+                let (year, month, _) = self.value;
+                self.value = self.clamp((year, month, 1));
+                PromptState::Active
+            }
+            _ if kb.move_end.contains_key(code, modifiers) => {
+                let (year, month, _) = self.value;
+                self.value = self.clamp((year, month, days_in_month(year, month)));
+                PromptState::Active
+            }
+            _ => PromptState::Active,
+        }
+    }
+
+    fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
+        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), None)
+            .description(self.description.clone());
+
+        match state {
+            PromptState::Submit => {
+                Ok(payload.input(PromptInput::Raw(self.formatter.value(self.value))))
+            }
+
+            _ => Ok(payload.body(PromptBody::Raw(self.render_calendar()))),
+        }
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        self.value
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01, based on Howard Hinnant's `days_from_civil`.
+fn to_days((year, month, day): Date) -> i64 {
+    let year = year as i64 - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+// The inverse of `to_days`, based on Howard Hinnant's `civil_from_days`.
+fn from_days(days: i64) -> Date {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+    (year, month, day)
+}
+
+// 0 is Sunday.
+fn weekday(year: i32, month: u32, day: u32) -> u32 {
+    // 1970-01-01 was a Thursday.
+    (to_days((year, month, day)) + 4).rem_euclid(7) as u32
+}
+
+// The current date in UTC.
+fn today() -> Date {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    from_days((secs / 86400) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prompt;
+
+    test_prompt!(
+        test_hint,
+        DatePicker::new("test message")
+            .with_hint("hint message")
+            .with_default((2024, 2, 14)),
+        vec![]
+    );
+
+    test_prompt!(
+        test_move,
+        DatePicker::new("test message").with_default((2024, 1, 31)),
+        vec![
+            (KeyCode::Right, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::PageDown, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::PageUp, KeyModifiers::NONE),
+            (KeyCode::End, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_bounds,
+        DatePicker::new("test message")
+            .with_default((2024, 3, 10))
+            .with_min((2024, 3, 5))
+            .with_max((2024, 3, 20)),
+        vec![
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Home, KeyModifiers::NONE),
+            (KeyCode::PageDown, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_days_roundtrip() {
+        assert_eq!(to_days((1970, 1, 1)), 0);
+        assert_eq!(from_days(0), (1970, 1, 1));
+        assert_eq!(from_days(to_days((2000, 2, 29))), (2000, 2, 29));
+        assert_eq!(from_days(to_days((1969, 12, 31))), (1969, 12, 31));
+        assert_eq!(weekday(2024, 2, 1), 4);
+        assert_eq!(weekday(2000, 1, 1), 6);
+    }
+
+    test_prompt!(
+        test_today,
+        DatePicker::new("test message").with_today((2024, 2, 14)),
+        vec![
+            (KeyCode::Right, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_today_keeps_default() {
+        let mut prompt = DatePicker::new("test message");
+        prompt.with_default((2024, 1, 1)).with_today((2024, 2, 14));
+        assert_eq!(prompt.submit(), (2024, 1, 1));
+    }
+
+    #[test]
+    fn test_move_months_clamps_day() {
+        let mut prompt = DatePicker::new("test message");
+        prompt.with_default((2024, 1, 31));
+        prompt.setup().unwrap();
+
+        prompt.handle(KeyCode::PageDown, KeyModifiers::NONE);
+        assert_eq!(prompt.submit(), (2024, 2, 29));
+        prompt.handle(KeyCode::PageUp, KeyModifiers::NONE);
+        prompt.handle(KeyCode::PageUp, KeyModifiers::NONE);
+        assert_eq!(prompt.submit(), (2023, 12, 29));
+    }
+
+    #[test]
+    fn test_clamp_default() {
+        let mut prompt = DatePicker::new("test message");
+        prompt.with_default((2020, 1, 1)).with_min((2024, 1, 1));
+        prompt.setup().unwrap();
+        assert_eq!(prompt.submit(), (2024, 1, 1));
+    }
+
+    #[test]
+    fn test_setup_error() {
+        assert!(DatePicker::new("test message")
+            .with_default((2023, 2, 29))
+            .setup()
+            .is_err());
+        assert!(DatePicker::new("test message")
+            .with_min((2024, 13, 1))
+            .setup()
+            .is_err());
+        assert!(DatePicker::new("test message")
+            .with_min((2024, 2, 1))
+            .with_max((2024, 1, 1))
+            .setup()
+            .is_err());
+    }
+}
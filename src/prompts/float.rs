@@ -0,0 +1,501 @@
+use crate::event::*;
+use crate::style::Symbol;
+use crate::{
+    DynValidator, Error, InputCursor, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptInput,
+    PromptState, RenderPayload, ValidationError, Validator,
+};
+
+const S_ICON: Symbol = Symbol("?", "?");
+
+/// A trait for formatting the [`Float`] prompt.
+///
+/// All methods have default implementations, allowing you to override only the specific formatting process you need.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{Float, FloatFormatter};
+///
+/// struct CustomFormatter;
+///
+/// impl FloatFormatter for CustomFormatter {
+///     fn err_invalid_range(&self, min: f64, max: f64) -> String {
+///         format!("Invalid Range: expect min={}, max={}.", min, max)
+///     }
+/// }
+///
+/// let _ = Float::new("...").with_formatter(CustomFormatter);
+/// ```
+pub trait FloatFormatter {
+    /// Formats the error message when the input is empty and required.
+    fn err_required(&self) -> String {
+        "This field is required.".into()
+    }
+
+    /// Formats the error message when the input is not a number.
+    fn err_invalid_format(&self) -> String {
+        "Invalid number.".into()
+    }
+
+    /// Formats the error message when the input is not within the range.
+    fn err_invalid_range(&self, min: f64, max: f64) -> String {
+        format!("Must be a number between {} and {}.", min, max)
+    }
+}
+
+/// The default formatter for [`Float`].
+#[derive(Default)]
+pub struct DefaultFloatFormatter;
+
+impl DefaultFloatFormatter {
+    /// Creates a new [`DefaultFloatFormatter`].
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl FloatFormatter for DefaultFloatFormatter {}
+
+/// A prompt for inputting decimal values.
+///
+/// Only digits, a single decimal point, and a leading `+` or `-` sign can be typed.
+///
+/// # Options
+///
+/// - **Formatter**: Customizes the prompt display. See [`FloatFormatter`].
+/// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the input. Defaults to `None`.
+/// - **Placeholder**: An auxiliary message displayed when no input is given.
+/// - **Required**: A flag indicating whether to allow no input.
+/// - **Min Value**: The minimum value of `f64`. Defaults to `f64::MIN`.
+/// - **Max Value**: The maximum value of `f64`. Defaults to `f64::MAX`.
+/// - **Default Value**: The default value of `f64`.
+/// - **Precision**: The number of decimal places to display and round to. Defaults to `None`, which keeps the value as is.
+/// - **Step**: The amount added on increment and subtracted on decrement. Defaults to `1.0`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
+/// - **Validator**: A function to validate the parsed `f64` value at the time of submission.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::Float;
+///
+/// let _ = Float::new("How much does it cost?")
+///     .with_min(0.0)
+///     .with_precision(2)
+///     .with_step(0.5);
+/// ```
+pub struct Float {
+    formatter: Box<dyn FloatFormatter>,
+    message: String,
+    hint: Option<String>,
+    description: Option<String>,
+    placeholder: Option<String>,
+    required: bool,
+    min: f64,
+    max: f64,
+    precision: Option<usize>,
+    step: f64,
+    validator: Option<DynValidator<f64>>,
+    keybindings: KeyBindings,
+    input: InputCursor,
+}
+
+impl Float {
+    /// Creates a new [`Float`] prompt.
+    pub fn new(message: impl std::fmt::Display) -> Self {
+        Self {
+            formatter: Box::<DefaultFloatFormatter>::default(),
+            message: message.to_string(),
+            hint: None,
+            description: None,
+            placeholder: None,
+            required: true,
+            min: f64::MIN,
+            max: f64::MAX,
+            precision: None,
+            step: 1.0,
+            validator: None,
+            keybindings: KeyBindings::default(),
+            input: InputCursor::new(String::new(), 0),
+        }
+    }
+
+    /// Sets the formatter for the prompt.
+    pub fn with_formatter(&mut self, formatter: impl FloatFormatter + 'static) -> &mut Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Sets the hint message for the prompt.
+    pub fn with_hint(&mut self, hint: impl std::fmt::Display) -> &mut Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the input.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Sets the placeholder message for the prompt.
+    pub fn with_placeholder(&mut self, placeholder: impl std::fmt::Display) -> &mut Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Sets the required flag for the prompt.
+    pub fn with_required(&mut self, required: bool) -> &mut Self {
+        self.required = required;
+        self
+    }
+
+    /// Sets the minimum value for the prompt.
+    pub fn with_min(&mut self, value: f64) -> &mut Self {
+        self.min = value;
+        self
+    }
+
+    /// Sets the maximum value for the prompt.
+    pub fn with_max(&mut self, value: f64) -> &mut Self {
+        self.max = value;
+        self
+    }
+
+    /// Sets the default value for the prompt.
+    pub fn with_default(&mut self, value: f64) -> &mut Self {
+        self.input = InputCursor::from(self.format(value));
+        self
+    }
+
+    /// Sets the number of decimal places for the prompt.  
+    /// Incremented, decremented and submitted values are rounded to it, and displayed with exactly that many decimal places.
+    pub fn with_precision(&mut self, precision: usize) -> &mut Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets the amount added on increment and subtracted on decrement.
+    pub fn with_step(&mut self, step: f64) -> &mut Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the validator for the parsed value of the prompt.  
+    /// It is called only when the input is a valid number.
+    pub fn with_validator(&mut self, f: impl Validator<f64> + 'static) -> &mut Self {
+        self.validator = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+
+    fn parse(&self) -> Option<f64> {
+        self.input.value().parse::<f64>().ok()
+    }
+
+    fn value(&self) -> f64 {
+        self.round(self.parse().unwrap_or_default())
+    }
+
+    fn round(&self, value: f64) -> f64 {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, value).parse().unwrap_or(value),
+            None => value,
+        }
+    }
+
+    fn format(&self, value: f64) -> String {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        }
+    }
+
+    fn starts_with_op(&self) -> bool {
+        let value = self.input.value();
+        value.starts_with('-') || value.starts_with('+')
+    }
+
+    fn is_within_range(&self, value: f64) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    fn normalize_value(&self, value: f64) -> f64 {
+        value.clamp(self.min, self.max)
+    }
+
+    fn insert(&mut self, chr: char) {
+        let before_sign = self.input.cursor() == 0 && self.starts_with_op();
+        match chr {
+            '0'..='9' => {
+                if before_sign {
+                    self.input.move_right();
+                }
+                self.input.insert(chr);
+            }
+            '.' if !before_sign && !self.input.value().contains('.') => {
+                self.input.insert(chr);
+            }
+            '-' | '+' if self.input.cursor() == 0 && !self.starts_with_op() => {
+                self.input.insert(chr);
+            }
+            _ => {}
+        }
+    }
+
+    fn increment(&mut self) {
+        let value = self.normalize_value(self.value() + self.step);
+        self.input = InputCursor::from(self.format(self.round(value)));
+    }
+
+    fn decrement(&mut self) {
+        let value = self.normalize_value(self.value() - self.step);
+        self.input = InputCursor::from(self.format(self.round(value)));
+    }
+}
+
+impl AsMut<Float> for Float {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl Prompt for Float {
+    type Output = f64;
+
+    fn name(&self) -> &'static str {
+        "Float"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
+    fn setup(&mut self) -> Result<(), crate::Error> {
+        if self.min > self.max {
+            return Err(Error::Config {
+                message: format!(
+                    "min cannot be greater than max (min={}, max={})",
+                    self.min, self.max
+                ),
+                field: Some("min".into()),
+                prompt: Some("Float".into()),
+                source: None,
+            });
+        }
+
+        self.validator
+            .as_mut()
+            .map_or(Ok(()), |validator| validator.prepare())
+    }
+
+    fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.submit.contains_key(code, modifiers) => {
+                if self.input.is_empty() && self.required {
+                    PromptState::Error(self.formatter.err_required())
+                } else if self.parse().is_none() {
+                    PromptState::Error(self.formatter.err_invalid_format())
+                } else if !self.is_within_range(self.value()) {
+                    PromptState::Error(self.formatter.err_invalid_range(self.min, self.max))
+                } else {
+                    PromptState::Submit
+                }
+            }
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.move_prev_word.contains_key(code, modifiers) => {
+                self.input.move_prev_word();
+                PromptState::Active
+            }
+            _ if kb.move_next_word.contains_key(code, modifiers) => {
+                self.input.move_next_word();
+                PromptState::Active
+            }
+            _ if kb.move_left.contains_key(code, modifiers) => {
+                self.input.move_left();
+                PromptState::Active
+            }
+            _ if kb.move_right.contains_key(code, modifiers) => {
+                self.input.move_right();
+                PromptState::Active
+            }
+            _ if kb.move_home.contains_key(code, modifiers) => {
+                self.input.move_home();
+                PromptState::Active
+            }
+            _ if kb.move_end.contains_key(code, modifiers) => {
+                self.input.move_end();
+                PromptState::Active
+            }
+            _ if kb.delete_left_char.contains_key(code, modifiers) => {
+                self.input.delete_left_char();
+                PromptState::Active
+            }
+            _ if kb.delete_left_word.contains_key(code, modifiers) => {
+                self.input.delete_left_word();
+                PromptState::Active
+            }
+            _ if kb.delete_right_char.contains_key(code, modifiers) => {
+                self.input.delete_right_char();
+                PromptState::Active
+            }
+            _ if kb.delete_rest_line.contains_key(code, modifiers) => {
+                self.input.delete_rest_line();
+                PromptState::Active
+            }
+            _ if kb.delete_line.contains_key(code, modifiers) => {
+                self.input.delete_line();
+                PromptState::Active
+            }
+            _ if kb.increment.contains_key(code, modifiers) => {
+                self.increment();
+                PromptState::Active
+            }
+            _ if kb.decrement.contains_key(code, modifiers) => {
+                self.decrement();
+                PromptState::Active
+            }
+            KeyCode::Char(c) if modifiers == KeyModifiers::NONE => {
+                self.insert(c);
+                PromptState::Active
+            }
+            _ => PromptState::Active,
+        }
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        self.value()
+    }
+
+    fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
+        let payload = RenderPayload::new(
+            self.message.clone(),
+            self.hint.clone(),
+            self.placeholder.clone(),
+        )
+        .description(self.description.clone());
+
+        match state {
+            PromptState::Submit => {
+                let raw = self.format(self.value());
+                Ok(payload.input(PromptInput::Raw(raw)))
+            }
+            _ => Ok(payload.input(PromptInput::Cursor(self.input.clone()))),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        match (&self.validator, self.parse()) {
+            (Some(validator), Some(_)) => validator.validate(&self.value()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prompt;
+
+    test_prompt!(
+        test_default,
+        Float::new("test message").with_default(1.5),
+        vec![]
+    );
+
+    test_prompt!(
+        test_required_error,
+        Float::new("test message").with_required(true),
+        vec![(KeyCode::Enter, KeyModifiers::NONE)]
+    );
+
+    test_prompt!(
+        test_float_input,
+        Float::new("test message").as_mut(),
+        vec![
+            (KeyCode::Char('1'), KeyModifiers::NONE),
+            (KeyCode::Char('.'), KeyModifiers::NONE),
+            (KeyCode::Char('2'), KeyModifiers::NONE),
+            (KeyCode::Char('.'), KeyModifiers::NONE),
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('5'), KeyModifiers::NONE),
+            (KeyCode::Home, KeyModifiers::NONE),
+            (KeyCode::Char('-'), KeyModifiers::NONE),
+            (KeyCode::Char('+'), KeyModifiers::NONE),
+            (KeyCode::Char('.'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_invalid_format,
+        Float::new("test message").as_mut(),
+        vec![
+            (KeyCode::Char('-'), KeyModifiers::NONE),
+            (KeyCode::Char('.'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_range,
+        Float::new("test message").with_min(0.5).with_max(1.5),
+        vec![
+            (KeyCode::Char('2'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Char('1'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_step_precision,
+        Float::new("test message")
+            .with_default(0.0)
+            .with_max(1.0)
+            .with_precision(2)
+            .with_step(0.25),
+        vec![
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_precision_submit_value() {
+        let mut prompt = Float::new("test message");
+        prompt.with_precision(2);
+        for c in "2.71828".chars() {
+            Prompt::handle(&mut prompt, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        assert_eq!(Prompt::submit(&mut prompt), 2.72);
+    }
+
+    #[test]
+    fn test_min_greater_than_max() {
+        let mut prompt = Float::new("test message");
+        prompt.with_min(2.0).with_max(1.0);
+        assert!(matches!(
+            prompt.setup(),
+            Err(Error::Config { field: Some(field), .. }) if field == "min"
+        ));
+    }
+}
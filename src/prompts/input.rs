@@ -1,5 +1,19 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use unicode_width::UnicodeWidthStr;
+
 use crate::event::*;
-use crate::{InputCursor, Prompt, PromptInput, PromptState, RenderPayload, Validator};
+use crate::style::Symbol;
+use crate::{
+    DynValidator, InputCursor, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptInput,
+    PromptState, RenderPayload, ValidationError, Validator,
+};
+
+const S_ICON: Symbol = Symbol("?", "?");
+
+/// The columns reserved for the prefix and spacing that themes add to the input line.
+const LINE_MARGIN: u16 = 4;
 
 /// A trait for formatting the [`Input`] prompt.
 ///
@@ -25,13 +39,28 @@ pub trait InputFormatter {
     fn err_required(&self) -> String {
         "This field is required.".into()
     }
+
+    /// Formats the character count displayed in the hint.
+    fn char_count(&self, count: usize) -> String {
+        if count == 1 {
+            "1 char".into()
+        } else {
+            format!("{} chars", count)
+        }
+    }
+
+    /// Formats the character count displayed in the hint when a maximum length is set.
+    fn char_count_with_max(&self, count: usize, max: usize) -> String {
+        format!("{}/{} chars", count, max)
+    }
 }
 
 /// The default formatter for [`Input`].
+#[derive(Default)]
 pub struct DefaultInputFormatter;
 
 impl DefaultInputFormatter {
-    #[allow(clippy::new_without_default)]
+    /// Creates a new [`DefaultInputFormatter`].
     pub fn new() -> Self {
         Self {}
     }
@@ -45,10 +74,24 @@ impl InputFormatter for DefaultInputFormatter {}
 ///
 /// - **Formatter**: Customizes the prompt display. See [`InputFormatter`].
 /// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the input. Defaults to `None`.
 /// - **Placeholder**: An auxiliary message displayed when no input is given.
 /// - **Required**: A flag indicating whether to allow no input.
 /// - **Default Value**: The default value of `String`.
-/// - **Validator**: A function to validate the value at the time of submission.
+/// - **Character Count**: A flag indicating whether to display the current character count in the hint. Defaults to `false`.
+/// - **Max Length**: The maximum number of characters. Further input is ignored once the limit is reached. Defaults to `None`.
+/// - **Max Width**: The maximum display width of the input. Longer input is truncated around the cursor. The input is always fitted to the terminal width, so this only sets a narrower cap. Defaults to `None`.
+/// - **Tab Width**: The number of spaces a pasted tab character expands to. `0` drops tabs. Defaults to `4`.
+/// - **Character Width**: The function that returns the display width of a character. Defaults to the width given by `unicode-width`.
+/// - **Grapheme Clusters**: A flag indicating whether to edit the input by grapheme clusters, so that emojis are handled as a whole. See [`InputCursor::with_grapheme_clusters`]. Defaults to `false`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
+/// - **Validator**: Functions to validate the value at the time of submission. Multiple validators run in order, and the first error is displayed.
+/// - **Live Validation**: A flag indicating whether to also run the validator while typing, displaying its message without blocking. Defaults to `false`.
+/// - **Validation Debounce**: The time to wait after the last key press before running the live validation. Defaults to `None`.
+///
+/// # Lifecycle
+///
+/// All options, including the validators, are stored at construction. [`Prompt::setup`] calls [`Validator::prepare`] on each validator, so state such as compiled patterns is built once and reused by every [`Prompt::validate`].
 ///
 /// # Examples
 ///
@@ -61,9 +104,20 @@ pub struct Input {
     formatter: Box<dyn InputFormatter>,
     message: String,
     hint: Option<String>,
+    description: Option<String>,
     placeholder: Option<String>,
     required: bool,
-    validator: Option<Box<dyn Validator<String>>>,
+    validators: Vec<DynValidator<String>>,
+    live_validation: bool,
+    validation_debounce: Option<Duration>,
+    validation_paused: bool,
+    live_hint: Option<String>,
+    char_count: bool,
+    max_length: Option<usize>,
+    max_width: Option<u16>,
+    width: Option<u16>,
+    tab_width: usize,
+    keybindings: KeyBindings,
     input: InputCursor,
 }
 
@@ -71,12 +125,23 @@ impl Input {
     /// Creates a new [`Input`] prompt.
     pub fn new(message: impl std::fmt::Display) -> Self {
         Self {
-            formatter: Box::new(DefaultInputFormatter::new()),
+            formatter: Box::<DefaultInputFormatter>::default(),
             message: message.to_string(),
             hint: None,
+            description: None,
             placeholder: None,
             required: true,
-            validator: None,
+            validators: Vec::new(),
+            live_validation: false,
+            validation_debounce: None,
+            validation_paused: false,
+            live_hint: None,
+            char_count: false,
+            max_length: None,
+            max_width: None,
+            width: None,
+            tab_width: 4,
+            keybindings: KeyBindings::default(),
             input: InputCursor::default(),
         }
     }
@@ -93,6 +158,13 @@ impl Input {
         self
     }
 
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the input.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
     /// Sets the placeholder message for the prompt.
     pub fn with_placeholder(&mut self, placeholder: impl std::fmt::Display) -> &mut Self {
         self.placeholder = Some(placeholder.to_string());
@@ -107,19 +179,155 @@ impl Input {
 
     /// Sets the default value for the prompt.
     pub fn with_default(&mut self, value: impl std::fmt::Display) -> &mut Self {
-        self.input = InputCursor::from(value.to_string());
+        self.input.set_value(value.to_string());
+        self.input.move_end();
         self
     }
 
-    /// Sets the validator for the prompt.
+    /// Sets whether to display the current character count in the hint.  
+    /// If a hint is set, the count is appended to it. Characters are counted the same way as the cursor, and the maximum set by [`Input::with_max_length`] is shown as `N/max`.
+    pub fn with_char_count(&mut self, char_count: bool) -> &mut Self {
+        self.char_count = char_count;
+        self
+    }
+
+    /// Sets the maximum number of characters.  
+    /// Once the value reaches the limit, further keystrokes are ignored and the prompt stays active. Characters are counted the same way as the cursor, see [`InputCursor::with_max_length`].
+    pub fn with_max_length(&mut self, max_length: usize) -> &mut Self {
+        self.max_length = Some(max_length);
+        self.input = std::mem::take(&mut self.input).with_max_length(max_length);
+        self
+    }
+
+    /// Sets the maximum display width of the input.  
+    /// When the input is wider than this, it is truncated with `…` while keeping the cursor visible. The value itself is not modified.  
+    /// The input is also fitted to the terminal width, so this only needs to be set to make it narrower.
+    pub fn with_max_width(&mut self, width: u16) -> &mut Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Sets the number of spaces a tab character expands to.  
+    /// Only tab characters inserted as text (e.g. pasted) are expanded; the `Tab` key itself is not affected.  
+    /// If `0` is specified, tab characters are dropped.
+    pub fn with_tab_width(&mut self, width: usize) -> &mut Self {
+        self.tab_width = width;
+        self
+    }
+
+    /// Sets the function that returns the display width of a character.  
+    /// Useful for fonts with non-standard widths, such as Powerline or Nerd Fonts. See [`InputCursor::with_char_width_fn`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use promptuity::prompts::Input;
+    ///
+    /// // Treat Powerline symbols as double width.
+    /// let _ = Input::new("Prompt").with_char_width_fn(Arc::new(|c| {
+    ///     if ('\u{e0a0}'..='\u{e0d4}').contains(&c) {
+    ///         2
+    ///     } else {
+    ///         1
+    ///     }
+    /// }));
+    /// ```
+    pub fn with_char_width_fn(
+        &mut self,
+        f: Arc<dyn Fn(char) -> u8 + Send + Sync + 'static>,
+    ) -> &mut Self {
+        self.input = std::mem::take(&mut self.input).with_char_width_fn(f);
+        self
+    }
+
+    /// Sets whether to edit the input by grapheme clusters instead of characters.
+    pub fn with_grapheme_clusters(&mut self, enabled: bool) -> &mut Self {
+        self.input = std::mem::take(&mut self.input).with_grapheme_clusters(enabled);
+        self
+    }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+
+    /// Sets the validator for the prompt, replacing any validators already set.
     pub fn with_validator(&mut self, f: impl Validator<String> + 'static) -> &mut Self {
-        self.validator = Some(Box::new(move |value: &String| -> Result<(), String> {
-            f.validate(value).map_err(|err| err.to_string())
-        }));
+        self.validators = vec![Box::new(f)];
+        self
+    }
+
+    /// Adds a validator for the prompt, keeping the ones already set.  
+    /// Validators run in the order they were added, and the first error is displayed.
+    pub fn add_validator(&mut self, f: impl Validator<String> + 'static) -> &mut Self {
+        self.validators.push(Box::new(f));
+        self
+    }
+
+    /// Sets whether to run the validator while typing.  
+    /// When enabled, the validation message is displayed below the input as soon as the value becomes invalid, while the prompt stays active. The validation at the time of submission still applies.
+    pub fn with_live_validation(&mut self, live_validation: bool) -> &mut Self {
+        self.live_validation = live_validation;
+        self
+    }
+
+    /// Sets how long to wait after the last key press before running the live validation.  
+    /// While typing within the interval, the validator is not called and the previous validation message is kept. Only affects [`Input::with_live_validation`]; the validation at the time of submission always runs immediately.
+    pub fn with_validation_debounce(&mut self, duration: Duration) -> &mut Self {
+        self.validation_debounce = Some(duration);
         self
     }
 }
 
+impl Input {
+    fn hint(&self, state: &PromptState) -> Option<String> {
+        match state {
+            PromptState::Active | PromptState::Error(_) if self.char_count => {
+                let count = match self.max_length {
+                    Some(max) => self.formatter.char_count_with_max(self.input.len(), max),
+                    None => self.formatter.char_count(self.input.len()),
+                };
+                match &self.hint {
+                    Some(hint) => Some(format!("{} ({})", hint, count)),
+                    None => Some(count),
+                }
+            }
+            _ => self.hint.clone(),
+        }
+    }
+
+    fn visible_width(&self) -> Option<u16> {
+        // Some themes render the message on the same line as the input, so its width is reserved too.
+        let message = u16::try_from(self.message.width()).unwrap_or(u16::MAX);
+        let width = self
+            .width
+            .map(|width| width.saturating_sub(LINE_MARGIN.saturating_add(message)));
+        match (width, self.max_width) {
+            (Some(width), Some(max_width)) => Some(width.min(max_width)),
+            (width, max_width) => width.or(max_width),
+        }
+    }
+
+    fn window(&self, width: u16) -> InputCursor {
+        let (left, cursor, right) = self.input.visible_window(width);
+        let at_end = self.input.split().0 == self.input.value();
+
+        // Clone the cursor to keep its settings, such as the width function.
+        let mut window = self.input.clone();
+        window.set_value(left);
+        window.move_end();
+        if !at_end {
+            let position = window.cursor();
+            window.set_value(format!("{}{}{}", window.value(), cursor, right));
+            window.set_cursor(position);
+        }
+        window
+    }
+}
+
 impl AsMut<Input> for Input {
     fn as_mut(&mut self) -> &mut Self {
         self
@@ -129,53 +337,90 @@ impl AsMut<Input> for Input {
 impl Prompt for Input {
     type Output = String;
 
+    fn name(&self) -> &'static str {
+        "Input"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_width(&mut self, width: u16) {
+        self.width = Some(width);
+    }
+
+    fn setup(&mut self) -> Result<(), crate::Error> {
+        self.validators
+            .iter_mut()
+            .try_for_each(|validator| validator.prepare())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
     fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
-        match (code, modifiers) {
-            (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => PromptState::Cancel,
-            (KeyCode::Enter, _) => {
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.submit.contains_key(code, modifiers) => {
                 if self.input.is_empty() && self.required {
                     PromptState::Error(self.formatter.err_required())
                 } else {
                     PromptState::Submit
                 }
             }
-            (KeyCode::Left, _) | (KeyCode::Char('b'), KeyModifiers::CONTROL) => {
+            _ if kb.move_prev_word.contains_key(code, modifiers) => {
+                self.input.move_prev_word();
+                PromptState::Active
+            }
+            _ if kb.move_next_word.contains_key(code, modifiers) => {
+                self.input.move_next_word();
+                PromptState::Active
+            }
+            _ if kb.move_left.contains_key(code, modifiers) => {
                 self.input.move_left();
                 PromptState::Active
             }
-            (KeyCode::Right, _) | (KeyCode::Char('f'), KeyModifiers::CONTROL) => {
+            _ if kb.move_right.contains_key(code, modifiers) => {
                 self.input.move_right();
                 PromptState::Active
             }
-            (KeyCode::Home, _) | (KeyCode::Char('a'), KeyModifiers::CONTROL) => {
+            _ if kb.move_home.contains_key(code, modifiers) => {
                 self.input.move_home();
                 PromptState::Active
             }
-            (KeyCode::End, _) | (KeyCode::Char('e'), KeyModifiers::CONTROL) => {
+            _ if kb.move_end.contains_key(code, modifiers) => {
                 self.input.move_end();
                 PromptState::Active
             }
-            (KeyCode::Backspace, _) | (KeyCode::Char('h'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_left_char.contains_key(code, modifiers) => {
                 self.input.delete_left_char();
                 PromptState::Active
             }
-            (KeyCode::Char('w'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_left_word.contains_key(code, modifiers) => {
                 self.input.delete_left_word();
                 PromptState::Active
             }
-            (KeyCode::Delete, _) | (KeyCode::Char('d'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_right_char.contains_key(code, modifiers) => {
                 self.input.delete_right_char();
                 PromptState::Active
             }
-            (KeyCode::Char('k'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_rest_line.contains_key(code, modifiers) => {
                 self.input.delete_rest_line();
                 PromptState::Active
             }
-            (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_line.contains_key(code, modifiers) => {
                 self.input.delete_line();
                 PromptState::Active
             }
-            (KeyCode::Char(c), _) => {
+            KeyCode::Char('\t') => {
+                for _ in 0..self.tab_width {
+                    self.input.insert(' ');
+                }
+                PromptState::Active
+            }
+            KeyCode::Char(c) => {
                 self.input.insert(c);
                 PromptState::Active
             }
@@ -183,23 +428,60 @@ impl Prompt for Input {
         }
     }
 
+    fn handle_paste(&mut self, text: String) -> PromptState {
+        let tab = " ".repeat(self.tab_width);
+        let text = text
+            .chars()
+            .filter(|c| *c == '\t' || !c.is_control())
+            .collect::<String>()
+            .replace('\t', &tab);
+        self.input.insert_str(&text);
+        PromptState::Active
+    }
+
+    fn validation_debounce(&self) -> Option<Duration> {
+        self.validation_debounce.filter(|_| self.live_validation)
+    }
+
+    fn set_validation_paused(&mut self, paused: bool) {
+        self.validation_paused = paused;
+    }
+
     fn submit(&mut self) -> Self::Output {
         self.input.value()
     }
 
-    fn render(&mut self, _: &PromptState) -> Result<RenderPayload, String> {
-        Ok(RenderPayload::new(
+    fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
+        let payload = RenderPayload::new(
             self.message.clone(),
-            self.hint.clone(),
+            self.hint(state),
             self.placeholder.clone(),
         )
-        .input(PromptInput::Cursor(self.input.clone())))
+        .description(self.description.clone());
+
+        let payload = match state {
+            PromptState::Active if self.live_validation && !self.input.is_empty() => {
+                if !self.validation_paused {
+                    self.live_hint = self.validate().err().map(|err| err.to_string());
+                }
+                payload.validation_hint(self.live_hint.clone())
+            }
+            _ => payload,
+        };
+
+        match (state, self.visible_width()) {
+            (PromptState::Submit, _) | (_, None) => {
+                Ok(payload.input(PromptInput::Cursor(self.input.clone())))
+            }
+            (_, Some(width)) => Ok(payload.input(PromptInput::Cursor(self.window(width)))),
+        }
     }
 
-    fn validate(&self) -> Result<(), String> {
-        self.validator
-            .as_ref()
-            .map_or(Ok(()), |validator| validator.validate(&self.input.value()))
+    fn validate(&self) -> Result<(), ValidationError> {
+        let value = self.input.value();
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator.validate(&value))
     }
 }
 
@@ -289,11 +571,175 @@ mod tests {
         ]
     );
 
+    test_prompt!(
+        test_tab_expansion,
+        Input::new("test message").as_mut(),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('\t'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_tab_expansion_custom_width,
+        Input::new("test message").with_tab_width(2),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('\t'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_tab_expansion_zero_width,
+        Input::new("test message").with_tab_width(0),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('\t'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    struct CharsetValidator {
+        charset: &'static str,
+        compiled: Option<std::collections::HashSet<char>>,
+        prepared: std::rc::Rc<std::cell::Cell<usize>>,
+    }
+
+    impl Validator<String> for CharsetValidator {
+        fn prepare(&mut self) -> Result<(), crate::Error> {
+            if self.charset.is_empty() {
+                return Err(crate::Error::config("charset cannot be empty."));
+            }
+            self.prepared.set(self.prepared.get() + 1);
+            self.compiled = Some(self.charset.chars().collect());
+            Ok(())
+        }
+
+        fn validate(&self, value: &String) -> Result<(), ValidationError> {
+            let compiled = self.compiled.as_ref().ok_or("Not prepared")?;
+            if value.chars().all(|c| compiled.contains(&c)) {
+                Ok(())
+            } else {
+                Err("Invalid character".into())
+            }
+        }
+    }
+
+    #[test]
+    fn test_validator_prepare() {
+        let prepared = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut prompt = Input::new("test message");
+        prompt.with_validator(CharsetValidator {
+            charset: "abc",
+            compiled: None,
+            prepared: prepared.clone(),
+        });
+        assert_eq!(Prompt::validate(&prompt), Err("Not prepared".into()));
+
+        Prompt::setup(&mut prompt).unwrap();
+        prompt.handle_paste("cab".into());
+        assert_eq!(Prompt::validate(&prompt), Ok(()));
+        prompt.handle_paste("d".into());
+        assert_eq!(Prompt::validate(&prompt), Err("Invalid character".into()));
+        assert_eq!(prepared.get(), 1);
+
+        let mut prompt = Input::new("test message");
+        prompt.with_validator(CharsetValidator {
+            charset: "",
+            compiled: None,
+            prepared,
+        });
+        assert!(matches!(
+            Prompt::setup(&mut prompt),
+            Err(crate::Error::Config { .. })
+        ));
+    }
+
+    #[test]
+    fn test_char_width_fn() {
+        let mut prompt = Input::new("m");
+        prompt
+            .with_char_width_fn(Arc::new(|_| 2))
+            .with_default("abcdefgh");
+        let col = |prompt: &mut Input| match prompt.render(&PromptState::Active).unwrap().input {
+            PromptInput::Cursor(cursor) => cursor.col(),
+            _ => unreachable!(),
+        };
+        assert_eq!(col(&mut prompt), 16);
+
+        // The truncated window keeps the width function.
+        prompt.set_width(15);
+        assert_eq!(col(&mut prompt), 8);
+    }
+
+    #[test]
+    fn test_paste_tab_expansion() {
+        let mut prompt = Input::new("test message");
+        prompt.handle_paste("a\tb\n".into());
+        assert_eq!(prompt.submit(), "a    b");
+
+        let mut prompt = Input::new("test message");
+        prompt.with_tab_width(2);
+        prompt.handle_paste("\ta\tb".into());
+        assert_eq!(prompt.submit(), "  a  b");
+
+        let mut prompt = Input::new("test message");
+        prompt.with_tab_width(0);
+        prompt.handle_paste("a\tb".into());
+        assert_eq!(prompt.submit(), "ab");
+    }
+
+    test_prompt!(
+        test_char_count,
+        Input::new("test message").with_char_count(true),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Char('c'), KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ],
+        full
+    );
+
+    test_prompt!(
+        test_char_count_with_max_length,
+        Input::new("test message")
+            .with_grapheme_clusters(true)
+            .with_max_length(5)
+            .with_char_count(true),
+        vec![
+            (KeyCode::Char('e'), KeyModifiers::NONE),
+            (KeyCode::Char('\u{301}'), KeyModifiers::NONE),
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+        ],
+        full
+    );
+
+    test_prompt!(
+        test_char_count_with_hint,
+        Input::new("test message")
+            .with_hint("hint message")
+            .with_char_count(true),
+        vec![
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+        ],
+        full
+    );
+
     test_prompt!(
         test_validation,
         Input::new("test message").with_validator(|v: &String| {
             if v.as_str() == "abc" {
-                Err("Error Message".into())
+                Err("Error Message")
             } else {
                 Ok(())
             }
@@ -308,4 +754,115 @@ mod tests {
             (KeyCode::Enter, KeyModifiers::NONE),
         ]
     );
+
+    test_prompt!(
+        test_description,
+        Input::new("test message").with_description("line1\nline2"),
+        vec![
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_grapheme_clusters,
+        Input::new("test message")
+            .with_grapheme_clusters(true)
+            .with_default("a👩\u{200d}👩\u{200d}👧b"),
+        vec![
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_move_word,
+        Input::new("test message").with_default("foo bar baz"),
+        vec![
+            (KeyCode::Char('b'), KeyModifiers::ALT),
+            (KeyCode::Left, KeyModifiers::CONTROL),
+            (KeyCode::Left, KeyModifiers::ALT),
+            (KeyCode::Char('f'), KeyModifiers::ALT),
+            (KeyCode::Right, KeyModifiers::CONTROL),
+            (KeyCode::Right, KeyModifiers::ALT),
+        ]
+    );
+
+    test_prompt!(
+        test_max_length,
+        Input::new("test message").with_max_length(3),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Char('c'), KeyModifiers::NONE),
+            (KeyCode::Char('d'), KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Char('e'), KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Char('f'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_live_validation,
+        Input::new("test message")
+            .with_live_validation(true)
+            .with_validator(|value: &String| {
+                if value.chars().count() < 3 {
+                    Err("Too short")
+                } else {
+                    Ok(())
+                }
+            }),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char('c'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_chained_validators,
+        Input::new("test message")
+            .add_validator(|v: &String| {
+                if v.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    Ok(())
+                } else {
+                    Err("Must be alphanumeric")
+                }
+            })
+            .add_validator(|v: &String| {
+                if v.chars().count() <= 3 {
+                    Ok(())
+                } else {
+                    Err("Too long")
+                }
+            }),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Char('c'), KeyModifiers::NONE),
+            (KeyCode::Char('d'), KeyModifiers::NONE),
+            (KeyCode::Char('!'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_with_validator_replaces_validators() {
+        let mut prompt = Input::new("test message");
+        prompt
+            .add_validator(|_: &String| Err("First"))
+            .with_validator(|_: &String| Err("Second"));
+        assert_eq!(prompt.validate(), Err("Second".into()));
+    }
 }
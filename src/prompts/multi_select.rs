@@ -1,7 +1,16 @@
 use crate::event::*;
-use crate::pagination::paginate;
+use crate::pagination::{paginate, Page};
 use crate::prompts::{DefaultSelectFormatter, SelectFormatter};
-use crate::{Error, Prompt, PromptBody, PromptInput, PromptState, RenderPayload};
+use crate::style::{Color, Styled, Symbol};
+use crate::{
+    Error, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptBody, PromptInput, PromptState,
+    RenderPayload,
+};
+
+const S_ICON: Symbol = Symbol("☑", "x");
+const S_GROUP_NONE: Symbol = Symbol("◯", "[ ]");
+const S_GROUP_PARTIAL: Symbol = Symbol("◐", "[~]");
+const S_GROUP_FULL: Symbol = Symbol("◉", "[x]");
 
 /// A struct representing an option in the [`MultiSelect`] prompt.
 #[derive(Debug, Clone)]
@@ -14,6 +23,8 @@ pub struct MultiSelectOption<T: Default + Clone> {
     pub hint: Option<String>,
     /// The selected flag of the option.
     pub selected: bool,
+    /// The disabled flag of the option. Disabled options are displayed but cannot be toggled. Defaults to `false`.
+    pub disabled: bool,
 }
 
 impl<T: Default + Clone> MultiSelectOption<T> {
@@ -24,14 +35,108 @@ impl<T: Default + Clone> MultiSelectOption<T> {
             value,
             hint: None,
             selected: false,
+            disabled: false,
         }
     }
 
+    /// Creates a new disabled [`MultiSelectOption`] with the given label and value.
+    pub fn disabled(label: impl std::fmt::Display, value: T) -> Self {
+        Self::new(label, value).with_disabled(true)
+    }
+
     /// Sets the hint message for the option.
     pub fn with_hint(mut self, hint: impl std::fmt::Display) -> Self {
         self.hint = Some(hint.to_string());
         self
     }
+
+    /// Sets the selected flag for the option, selecting it in advance.
+    pub fn with_selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the disabled flag for the option.
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Two options are equal if their label and value are equal, regardless of the hint, selection state and disabled flag.
+impl<T: Default + Clone + PartialEq> PartialEq for MultiSelectOption<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.value == other.value
+    }
+}
+
+impl<T: Default + Clone + Eq> Eq for MultiSelectOption<T> {}
+
+impl<T: Default + Clone + std::hash::Hash> std::hash::Hash for MultiSelectOption<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.label.hash(state);
+        self.value.hash(state);
+    }
+}
+
+/// Formats the option as `[x] label (hint)`, where `[x]` is `[ ]` if the option is not selected and the hint is omitted if it is not set.
+impl<T: Default + Clone> std::fmt::Display for MultiSelectOption<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mark = if self.selected { "[x]" } else { "[ ]" };
+        write!(f, "{} {}", mark, self.label)?;
+        if let Some(hint) = &self.hint {
+            write!(f, " ({})", hint)?;
+        }
+        Ok(())
+    }
+}
+
+/// A struct representing a group header in the [`MultiSelect`] prompt.
+///
+/// A group contains the options that follow it, up to the next group.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MultiSelectGroup {
+    /// The label of the group.
+    pub label: String,
+}
+
+impl MultiSelectGroup {
+    /// Creates a new [`MultiSelectGroup`] with the given label.
+    pub fn new(label: impl std::fmt::Display) -> Self {
+        Self {
+            label: label.to_string(),
+        }
+    }
+}
+
+/// An item in the [`MultiSelect`] prompt, either a group header, a separator or an option.
+#[derive(Debug, Clone)]
+pub enum MultiSelectItem<T: Default + Clone> {
+    /// A group header. Toggling it toggles all options in the group.
+    Group(MultiSelectGroup),
+    /// A non-selectable header row. It also ends the preceding group.
+    Separator(String),
+    /// A selectable option.
+    Option(MultiSelectOption<T>),
+}
+
+impl<T: Default + Clone> MultiSelectItem<T> {
+    /// Creates a new [`MultiSelectItem::Separator`] with the given label.
+    pub fn separator(label: impl std::fmt::Display) -> Self {
+        Self::Separator(label.to_string())
+    }
+}
+
+impl<T: Default + Clone> From<MultiSelectGroup> for MultiSelectItem<T> {
+    fn from(group: MultiSelectGroup) -> Self {
+        Self::Group(group)
+    }
+}
+
+impl<T: Default + Clone> From<MultiSelectOption<T>> for MultiSelectItem<T> {
+    fn from(option: MultiSelectOption<T>) -> Self {
+        Self::Option(option)
+    }
 }
 
 /// A trait for formatting the [`MultiSelect`] prompt.
@@ -57,7 +162,7 @@ impl<T: Default + Clone> MultiSelectOption<T> {
 ///        }
 ///     }
 ///
-///     fn option_label(&self, label: String, _active: bool, _selected: bool) -> String {
+///     fn option_label(&self, label: String, _active: bool, _selected: bool, _disabled: bool) -> String {
 ///         label.clone()
 ///     }
 ///
@@ -82,7 +187,7 @@ pub trait MultiSelectFormatter {
     /// Icons displayed for each option.
     fn option_icon(&self, active: bool, selected: bool) -> String;
     /// Formats the label of the option.
-    fn option_label(&self, label: String, active: bool, selected: bool) -> String;
+    fn option_label(&self, label: String, active: bool, selected: bool, disabled: bool) -> String;
     /// Formats the hint message of the option.
     fn option_hint(&self, hint: Option<String>, active: bool, selected: bool) -> String;
     /// Formats the option.
@@ -95,6 +200,50 @@ pub trait MultiSelectFormatter {
         selected: bool,
     ) -> String;
 
+    /// Icons displayed for each group header, based on the number of selected options in the group.
+    fn group_icon(&self, active: bool, selected: usize, total: usize) -> String {
+        let color = if active {
+            Color::Green
+        } else {
+            Color::DarkGrey
+        };
+        let icon = if total > 0 && selected == total {
+            S_GROUP_FULL
+        } else if selected > 0 {
+            S_GROUP_PARTIAL
+        } else {
+            S_GROUP_NONE
+        };
+        Styled::new(icon).fg(color).to_string()
+    }
+
+    /// Formats the group header.
+    fn group(&self, icon: String, label: String, active: bool) -> String {
+        let mut styled = Styled::new(label);
+        styled.bold();
+        if active {
+            styled.underline();
+        }
+        format!("{} {}", icon, styled)
+    }
+
+    /// Formats the separator created by [`MultiSelectItem::separator`].
+    fn separator(&self, label: String) -> String {
+        Styled::new(label).fg(Color::DarkGrey).bold().to_string()
+    }
+
+    /// Formats the row displayed above the options when there are more options before the current page.  
+    /// Themes decide where the row is placed, and it counts towards the page size. Returns `None` to hide the row.
+    fn page_indicator_before(&self) -> Option<String> {
+        Some(Styled::new("…").fg(Color::DarkGrey).to_string())
+    }
+
+    /// Formats the row displayed below the options when there are more options after the current page.  
+    /// Themes decide where the row is placed, and it counts towards the page size. Returns `None` to hide the row.
+    fn page_indicator_after(&self) -> Option<String> {
+        Some(Styled::new("…").fg(Color::DarkGrey).to_string())
+    }
+
     /// Formats the submitted value.
     fn submit(&self, labels: Vec<String>) -> String {
         labels.join(", ")
@@ -117,15 +266,16 @@ pub trait MultiSelectFormatter {
 }
 
 /// The default formatter for [`MultiSelect`].
+#[derive(Default)]
 pub struct DefaultMultiSelectFormatter {
     inner: DefaultSelectFormatter,
 }
 
 impl DefaultMultiSelectFormatter {
-    #[allow(clippy::new_without_default)]
+    /// Creates a new [`DefaultMultiSelectFormatter`].
     pub fn new() -> Self {
         Self {
-            inner: DefaultSelectFormatter::new(),
+            inner: Default::default(),
         }
     }
 }
@@ -135,8 +285,8 @@ impl MultiSelectFormatter for DefaultMultiSelectFormatter {
         self.inner.option_icon(selected)
     }
 
-    fn option_label(&self, label: String, active: bool, _selected: bool) -> String {
-        self.inner.option_label(label, active)
+    fn option_label(&self, label: String, active: bool, _selected: bool, disabled: bool) -> String {
+        self.inner.option_label(label, active, disabled)
     }
 
     fn option_hint(&self, hint: Option<String>, active: bool, _selected: bool) -> String {
@@ -161,12 +311,21 @@ impl MultiSelectFormatter for DefaultMultiSelectFormatter {
 ///
 /// - **Formatter**: Customizes the prompt display. See [`MultiSelectFormatter`].
 /// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the options. Defaults to `None`.
 /// - **Required**: A flag indicating whether to allow no input.
 /// - **Minimum Selections**: The minimum number of selections required. Defaults to `0`.
 /// - **Maximum Selections**: The maximum number of selections allowed. Defaults to `usize::MAX`.
 /// - **Page Size**: The total number of options to displayed per page, used for pagination. Defaults to `8`.
+/// - **Selected**: The options selected in advance, by value. Defaults to none.
+/// - **Loop**: Wraps the cursor around when moving past the first or last option. Defaults to `false`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
 /// - **Validator**: A function to validate the value at the time of submission.
 ///
+/// # Groups
+///
+/// Options can be grouped with [`MultiSelect::from_items`]. Toggling a group header selects all of its options, or deselects them if more than half are already selected.  
+/// For headers that only label the options without toggling them, use [`MultiSelectItem::separator`] instead.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -178,30 +337,59 @@ impl MultiSelectFormatter for DefaultMultiSelectFormatter {
 ///     MultiSelectOption::new("Blue", "#0000ff"),
 /// ]).with_page_size(5);
 /// ```
+///
+/// ```no_run
+/// use promptuity::prompts::{MultiSelect, MultiSelectGroup, MultiSelectOption};
+///
+/// let _ = MultiSelect::from_items("Which tools do you use?", vec![
+///     MultiSelectGroup::new("Editors").into(),
+///     MultiSelectOption::new("Vim", "vim").into(),
+///     MultiSelectOption::new("Emacs", "emacs").into(),
+///     MultiSelectGroup::new("Shells").into(),
+///     MultiSelectOption::new("Bash", "bash").into(),
+///     MultiSelectOption::new("Zsh", "zsh").into(),
+/// ]);
+/// ```
 pub struct MultiSelect<T: Default + Clone> {
     formatter: Box<dyn MultiSelectFormatter>,
     message: String,
     hint: Option<String>,
+    description: Option<String>,
     required: bool,
     min: usize,
     max: usize,
     page_size: usize,
-    options: Vec<MultiSelectOption<T>>,
+    page_size_explicit: bool,
+    looping: bool,
+    items: Vec<MultiSelectItem<T>>,
+    keybindings: KeyBindings,
     index: usize,
 }
 
 impl<T: Default + Clone> MultiSelect<T> {
     /// Creates a new [`MultiSelect`] prompt with the given message and options.
     pub fn new(message: impl std::fmt::Display, options: Vec<MultiSelectOption<T>>) -> Self {
+        Self::from_items(
+            message,
+            options.into_iter().map(MultiSelectItem::Option).collect(),
+        )
+    }
+
+    /// Creates a new [`MultiSelect`] prompt with the given message and items, which may include group headers.
+    pub fn from_items(message: impl std::fmt::Display, items: Vec<MultiSelectItem<T>>) -> Self {
         Self {
-            formatter: Box::new(DefaultMultiSelectFormatter::new()),
+            formatter: Box::<DefaultMultiSelectFormatter>::default(),
             message: message.to_string(),
             hint: None,
+            description: None,
             required: true,
             min: 0,
             max: usize::MAX,
             page_size: 8,
-            options,
+            page_size_explicit: false,
+            looping: false,
+            items,
+            keybindings: KeyBindings::default(),
             index: 0,
         }
     }
@@ -218,6 +406,13 @@ impl<T: Default + Clone> MultiSelect<T> {
         self
     }
 
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the options.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
     /// Sets the required flag for the prompt.
     pub fn with_required(&mut self, required: bool) -> &mut Self {
         self.required = required;
@@ -239,27 +434,177 @@ impl<T: Default + Clone> MultiSelect<T> {
     /// Sets the page size for the prompt.
     pub fn with_page_size(&mut self, page_size: usize) -> &mut Self {
         self.page_size = page_size;
+        self.page_size_explicit = true;
         self
     }
 
+    fn options(&self) -> impl Iterator<Item = &MultiSelectOption<T>> {
+        self.items.iter().filter_map(|item| match item {
+            MultiSelectItem::Option(option) => Some(option),
+            _ => None,
+        })
+    }
+
+    fn options_mut(&mut self) -> impl Iterator<Item = &mut MultiSelectOption<T>> {
+        self.items.iter_mut().filter_map(|item| match item {
+            MultiSelectItem::Option(option) => Some(option),
+            _ => None,
+        })
+    }
+
     fn values(&mut self) -> Vec<T> {
-        self.options
+        self.options()
+            .filter(|option| option.selected)
+            .map(|option| option.value.clone())
+            .collect::<Vec<_>>()
+    }
+
+    fn group_options(&self, index: usize) -> std::ops::Range<usize> {
+        let start = index + 1;
+        let end = self.items[start..]
             .iter()
-            .filter_map(|option| {
-                if option.selected {
-                    Some(option.value.clone())
-                } else {
-                    None
-                }
+            .position(|item| {
+                matches!(
+                    item,
+                    MultiSelectItem::Group(_) | MultiSelectItem::Separator(_)
+                )
             })
-            .collect::<Vec<_>>()
+            .map_or(self.items.len(), |pos| start + pos);
+        start..end
+    }
+
+    /// Counts the selected and total options in the group, ignoring disabled options.
+    fn group_selection(&self, index: usize) -> (usize, usize) {
+        let range = self.group_options(index);
+        let options = self.items[range].iter().filter_map(|item| match item {
+            MultiSelectItem::Option(option) if !option.disabled => Some(option),
+            _ => None,
+        });
+        options.fold((0, 0), |(selected, total), option| {
+            (selected + usize::from(option.selected), total + 1)
+        })
+    }
+
+    fn toggle(&mut self) {
+        match &mut self.items[self.index] {
+            MultiSelectItem::Option(option) => {
+                if !option.disabled {
+                    option.selected = !option.selected;
+                }
+            }
+            MultiSelectItem::Group(_) => {
+                let (selected, total) = self.group_selection(self.index);
+                let select = selected * 2 <= total;
+                let range = self.group_options(self.index);
+                for item in &mut self.items[range] {
+                    if let MultiSelectItem::Option(option) = item {
+                        if !option.disabled {
+                            option.selected = select;
+                        }
+                    }
+                }
+            }
+            MultiSelectItem::Separator(_) => {}
+        }
+    }
+
+    /// Paginates the items along with the page indicators, which count towards the page size.
+    fn page(&self) -> (Page<'_, MultiSelectItem<T>>, Option<String>, Option<String>) {
+        let mut rows = 0;
+        loop {
+            let size = self.page_size.saturating_sub(rows).max(1);
+            let page = paginate(size, &self.items, self.index);
+            let before = (!page.first)
+                .then(|| self.formatter.page_indicator_before())
+                .flatten();
+            let after = (!page.last)
+                .then(|| self.formatter.page_indicator_after())
+                .flatten();
+            let count = before.is_some() as usize + after.is_some() as usize;
+            if count <= rows || rows == 2 {
+                return (page, before, after);
+            }
+            rows = count;
+        }
+    }
+
+    fn is_selectable(&self, index: usize) -> bool {
+        match self.items.get(index) {
+            Some(MultiSelectItem::Option(option)) => !option.disabled,
+            Some(MultiSelectItem::Group(_)) => true,
+            Some(MultiSelectItem::Separator(_)) | None => false,
+        }
+    }
+
+    fn move_up(&mut self) {
+        let len = self.items.len();
+        let index = (0..self.index).rev().find(|i| self.is_selectable(*i));
+        let index = match index {
+            None if self.looping => (self.index + 1..len).rev().find(|i| self.is_selectable(*i)),
+            index => index,
+        };
+        if let Some(index) = index {
+            self.index = index;
+        }
+    }
+
+    fn move_down(&mut self) {
+        let len = self.items.len();
+        let index = (self.index + 1..len).find(|i| self.is_selectable(*i));
+        let index = match index {
+            None if self.looping => (0..self.index).find(|i| self.is_selectable(*i)),
+            index => index,
+        };
+        if let Some(index) = index {
+            self.index = index;
+        }
+    }
+
+    /// Moves the cursor to the selectable option nearest to `target`, searching in the given direction first.
+    fn jump(&mut self, target: usize, forward: bool) {
+        let len = self.items.len();
+        let target = std::cmp::min(len.saturating_sub(1), target);
+        let index = if forward {
+            (target..len)
+                .find(|i| self.is_selectable(*i))
+                .or_else(|| (0..target).rev().find(|i| self.is_selectable(*i)))
+        } else {
+            (0..=target)
+                .rev()
+                .find(|i| self.is_selectable(*i))
+                .or_else(|| (target + 1..len).find(|i| self.is_selectable(*i)))
+        };
+        if let Some(index) = index {
+            self.index = index;
+        }
+    }
+
+    /// Sets whether the cursor wraps around, moving from the last option to the first and vice versa.
+    pub fn with_loop(&mut self, looping: bool) -> &mut Self {
+        self.looping = looping;
+        self
     }
 
-    fn map_options<F>(&mut self, f: F) -> Vec<MultiSelectOption<T>>
-    where
-        F: Fn(&MultiSelectOption<T>) -> MultiSelectOption<T>,
-    {
-        self.options.iter().map(f).collect::<Vec<_>>()
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+}
+
+impl<T: Default + Clone + PartialEq> MultiSelect<T> {
+    /// Pre-selects the options with the given values.  
+    /// Options that are already selected stay selected, and values without a matching option are ignored.
+    pub fn with_selected(&mut self, values: &[T]) -> &mut Self {
+        self.options_mut()
+            .filter(|option| values.contains(&option.value))
+            .for_each(|option| option.selected = true);
+        self
+    }
+
+    /// Pre-selects the options with the given values. This is an alias of [`MultiSelect::with_selected`].
+    pub fn with_defaults(&mut self, values: &[T]) -> &mut Self {
+        self.with_selected(values)
     }
 }
 
@@ -272,25 +617,67 @@ impl<T: Default + Clone> AsMut<MultiSelect<T>> for MultiSelect<T> {
 impl<T: Default + Clone> Prompt for MultiSelect<T> {
     type Output = Vec<T>;
 
+    fn name(&self) -> &'static str {
+        "MultiSelect"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
+    fn set_page_size(&mut self, page_size: usize) {
+        if !self.page_size_explicit {
+            self.page_size = page_size;
+        }
+    }
+
     fn setup(&mut self) -> Result<(), Error> {
-        if self.options.is_empty() {
-            return Err(Error::Config("options cannot be empty.".into()));
+        if self.options().next().is_none() {
+            return Err(Error::Config {
+                message: "options cannot be empty.".into(),
+                field: Some("options".into()),
+                prompt: Some("MultiSelect".into()),
+                source: None,
+            });
+        }
+
+        if self.options().all(|option| option.disabled) {
+            return Err(Error::Config {
+                message: "options cannot be all disabled.".into(),
+                field: Some("options".into()),
+                prompt: Some("MultiSelect".into()),
+                source: None,
+            });
         }
 
         if self.min > self.max {
-            return Err(Error::Config(format!(
-                "min cannot be greater than max (min={}, max={})",
-                self.min, self.max
-            )));
+            return Err(Error::Config {
+                message: format!(
+                    "min cannot be greater than max (min={}, max={})",
+                    self.min, self.max
+                ),
+                field: Some("min".into()),
+                prompt: Some("MultiSelect".into()),
+                source: None,
+            });
+        }
+
+        if !self.is_selectable(self.index) {
+            self.move_down();
         }
 
         Ok(())
     }
 
     fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> crate::PromptState {
-        match (code, modifiers) {
-            (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => PromptState::Cancel,
-            (KeyCode::Enter, _) => {
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.submit.contains_key(code, modifiers) => {
                 let values = self.values();
                 if values.is_empty() && self.required {
                     PromptState::Error(self.formatter.err_required())
@@ -302,94 +689,139 @@ impl<T: Default + Clone> Prompt for MultiSelect<T> {
                     PromptState::Submit
                 }
             }
-            (KeyCode::Up, _)
-            | (KeyCode::Char('k'), _)
-            | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
-                self.index = self.index.saturating_sub(1);
+            _ if kb.move_up.contains_key(code, modifiers) => {
+                self.move_up();
                 PromptState::Active
             }
-            (KeyCode::Down, _)
-            | (KeyCode::Char('j'), _)
-            | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
-                self.index = std::cmp::min(
-                    self.options.len().saturating_sub(1),
-                    self.index.saturating_add(1),
-                );
+            _ if kb.move_down.contains_key(code, modifiers) => {
+                self.move_down();
                 PromptState::Active
             }
-            (KeyCode::Char(' '), KeyModifiers::NONE) => {
-                let mut option = self.options.get(self.index).unwrap().clone();
-                option.selected = !option.selected;
-                self.options[self.index] = option;
+            _ if kb.page_up.contains_key(code, modifiers) => {
+                self.jump(self.index.saturating_sub(self.page_size), false);
                 PromptState::Active
             }
-            (KeyCode::Char('a'), KeyModifiers::NONE) => {
-                self.options = self.map_options(|option| {
-                    let mut option = option.clone();
-                    option.selected = true;
-                    option
-                });
+            _ if kb.page_down.contains_key(code, modifiers) => {
+                self.jump(self.index.saturating_add(self.page_size), true);
                 PromptState::Active
             }
-            (KeyCode::Char('i'), KeyModifiers::NONE) => {
-                self.options = self.map_options(|option| {
-                    let mut option = option.clone();
-                    option.selected = !option.selected;
-                    option
-                });
+            _ if kb.move_home.contains_key(code, modifiers) => {
+                self.jump(0, true);
+                PromptState::Active
+            }
+            _ if kb.move_end.contains_key(code, modifiers) => {
+                self.jump(usize::MAX, false);
+                PromptState::Active
+            }
+            _ if kb.toggle.contains_key(code, modifiers) => {
+                self.toggle();
+                PromptState::Active
+            }
+            _ if kb.toggle_all.contains_key(code, modifiers) => {
+                self.options_mut()
+                    .filter(|option| !option.disabled)
+                    .for_each(|option| option.selected = true);
+                PromptState::Active
+            }
+            _ if kb.invert.contains_key(code, modifiers) => {
+                self.options_mut()
+                    .filter(|option| !option.disabled)
+                    .for_each(|option| option.selected = !option.selected);
                 PromptState::Active
             }
             _ => PromptState::Active,
         }
     }
 
+    fn handle_mouse(&mut self, event: MouseEvent, row: Option<usize>) -> PromptState {
+        match event.kind {
+            MouseEventKind::ScrollUp => self.move_up(),
+            MouseEventKind::ScrollDown => self.move_down(),
+            MouseEventKind::Down(MouseButton::Left) => {
+                let (page, before, _) = self.page();
+                let index = row
+                    .and_then(|row| row.checked_sub(before.is_some() as usize))
+                    .filter(|row| *row < page.items.len())
+                    .map(|row| page.global_index_of(row));
+                if let Some(index) = index.filter(|index| self.is_selectable(*index)) {
+                    self.index = index;
+                    self.toggle();
+                }
+            }
+            _ => {}
+        }
+        PromptState::Active
+    }
+
     fn submit(&mut self) -> Self::Output {
         self.values()
     }
 
     fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
-        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), None);
+        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), None)
+            .description(self.description.clone());
 
         match state {
             PromptState::Submit => {
                 let raw = self.formatter.submit(
-                    self.options
-                        .iter()
-                        .filter_map(|option| {
-                            if option.selected {
-                                Some(option.label.clone())
-                            } else {
-                                None
-                            }
-                        })
+                    self.options()
+                        .filter(|option| option.selected)
+                        .map(|option| option.label.clone())
                         .collect::<Vec<_>>(),
                 );
                 Ok(payload.input(PromptInput::Raw(raw)))
             }
 
             _ => {
-                let page = paginate(self.page_size, &self.options, self.index);
-                let options = page
-                    .items
-                    .iter()
-                    .enumerate()
-                    .map(|(i, option)| {
-                        let active = i == page.cursor;
-                        let selected = option.selected;
-                        self.formatter.option(
-                            self.formatter.option_icon(active, selected),
-                            self.formatter
-                                .option_label(option.label.clone(), active, selected),
-                            self.formatter
-                                .option_hint(option.hint.clone(), active, selected),
-                            active,
-                            selected,
-                        )
-                    })
-                    .collect::<Vec<_>>()
-                    .join("\n");
-
-                Ok(payload.body(PromptBody::Raw(options)))
+                let (page, before, after) = self.page();
+                let grouped = self.items.iter().any(|item| {
+                    matches!(
+                        item,
+                        MultiSelectItem::Group(_) | MultiSelectItem::Separator(_)
+                    )
+                });
+                let options = page.items_with_global_index().map(|(i, item)| {
+                    let active = i == self.index;
+                    match item {
+                        MultiSelectItem::Group(group) => {
+                            let (selected, total) = self.group_selection(i);
+                            self.formatter.group(
+                                self.formatter.group_icon(active, selected, total),
+                                group.label.clone(),
+                                active,
+                            )
+                        }
+                        MultiSelectItem::Separator(label) => {
+                            self.formatter.separator(label.clone())
+                        }
+                        MultiSelectItem::Option(option) => {
+                            let selected = option.selected;
+                            let line = self.formatter.option(
+                                self.formatter.option_icon(active, selected),
+                                self.formatter.option_label(
+                                    option.label.clone(),
+                                    active,
+                                    selected,
+                                    option.disabled,
+                                ),
+                                self.formatter
+                                    .option_hint(option.hint.clone(), active, selected),
+                                active,
+                                selected,
+                            );
+                            if grouped {
+                                format!("  {}", line)
+                            } else {
+                                line
+                            }
+                        }
+                    }
+                });
+                let options = options.collect::<Vec<_>>().join("\n");
+
+                Ok(payload
+                    .body(PromptBody::Raw(options))
+                    .page_indicators(before, after))
             }
         }
     }
@@ -439,6 +871,80 @@ mod tests {
         vec![]
     );
 
+    macro_rules! grouped_items {
+        () => {{
+            vec![
+                MultiSelectGroup::new("Group1").into(),
+                MultiSelectOption::new("Value1", "value1".to_string()).into(),
+                MultiSelectOption::new("Value2", "value2".to_string()).into(),
+                MultiSelectOption::new("Value3", "value3".to_string()).into(),
+                MultiSelectGroup::new("Group2").into(),
+                MultiSelectOption::new("Value4", "value4".to_string()).into(),
+                MultiSelectOption::new("Value5", "value5".to_string()).into(),
+            ]
+        }};
+    }
+
+    test_prompt!(
+        test_group,
+        MultiSelect::from_items("test message", grouped_items!()).as_mut(),
+        vec![]
+    );
+
+    test_prompt!(
+        test_group_toggle,
+        MultiSelect::from_items("test message", grouped_items!()).as_mut(),
+        vec![
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Char('j'), KeyModifiers::NONE),
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Char('k'), KeyModifiers::NONE),
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_group_toggle_majority,
+        MultiSelect::from_items("test message", grouped_items!()).as_mut(),
+        vec![
+            (KeyCode::Char('j'), KeyModifiers::NONE),
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Char('j'), KeyModifiers::NONE),
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Char('k'), KeyModifiers::NONE),
+            (KeyCode::Char('k'), KeyModifiers::NONE),
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_group_toggle_values() {
+        let mut prompt = MultiSelect::from_items("test message", grouped_items!());
+        prompt.setup().unwrap();
+
+        // Space on the second group selects all of its options.
+        prompt.index = 4;
+        prompt.handle(KeyCode::Char(' '), KeyModifiers::NONE);
+        assert_eq!(prompt.values(), vec!["value4", "value5"]);
+
+        // Only one of three is selected in the first group, so all get selected.
+        prompt.index = 1;
+        prompt.handle(KeyCode::Char(' '), KeyModifiers::NONE);
+        prompt.index = 0;
+        prompt.handle(KeyCode::Char(' '), KeyModifiers::NONE);
+        assert_eq!(
+            prompt.values(),
+            vec!["value1", "value2", "value3", "value4", "value5"]
+        );
+
+        // All are selected, so the group gets deselected.
+        prompt.handle(KeyCode::Char(' '), KeyModifiers::NONE);
+        assert_eq!(prompt.values(), vec!["value4", "value5"]);
+    }
+
     test_prompt!(
         test_move,
         MultiSelect::new("test message", options!(10)).with_page_size(5),
@@ -541,4 +1047,208 @@ mod tests {
             (KeyCode::Enter, KeyModifiers::NONE),
         ]
     );
+
+    #[test]
+    fn test_option_eq() {
+        let selected = MultiSelectOption::new("Value1", "value1".to_string())
+            .with_hint("hint")
+            .with_selected(true);
+
+        assert_eq!(
+            options!(2),
+            vec![
+                selected,
+                MultiSelectOption::new("Value2", "value2".to_string())
+            ]
+        );
+        assert_ne!(
+            MultiSelectOption::new("Value1", 1),
+            MultiSelectOption::new("Value1", 2)
+        );
+
+        let set: std::collections::HashSet<_> =
+            options!(3).into_iter().chain(options!(2)).collect();
+        assert_eq!(set.len(), 3);
+    }
+
+    test_prompt!(
+        test_description,
+        MultiSelect::new("test message", options!(3)).with_description("line1\nline2"),
+        vec![
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_separator,
+        MultiSelect::from_items(
+            "test message",
+            vec![
+                MultiSelectItem::separator("Group1"),
+                MultiSelectOption::new("Value1", "value1".to_string()).into(),
+                MultiSelectOption::new("Value2", "value2".to_string()).into(),
+                MultiSelectItem::separator("Group2"),
+                MultiSelectOption::new("Value3", "value3".to_string()).into(),
+            ]
+        )
+        .as_mut(),
+        vec![
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_disabled,
+        MultiSelect::from_items(
+            "test message",
+            vec![
+                MultiSelectGroup::new("Group1").into(),
+                MultiSelectOption::new("Value1", "value1".to_string()).into(),
+                MultiSelectOption::disabled("Value2", "value2".to_string()).into(),
+                MultiSelectOption::new("Value3", "value3".to_string()).into(),
+                MultiSelectOption::disabled("Value4", "value4".to_string()).into(),
+            ]
+        )
+        .as_mut(),
+        vec![
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Char('i'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_disabled_toggle() {
+        let mut prompt = MultiSelect::new(
+            "test message",
+            vec![
+                MultiSelectOption::disabled("Value1", "value1"),
+                MultiSelectOption::new("Value2", "value2"),
+            ],
+        );
+        prompt.setup().unwrap();
+        assert_eq!(prompt.index, 1);
+
+        prompt.index = 0;
+        prompt.handle(KeyCode::Char(' '), KeyModifiers::NONE);
+        assert!(prompt.values().is_empty());
+
+        prompt.handle(KeyCode::Char('a'), KeyModifiers::NONE);
+        assert_eq!(prompt.values(), vec!["value2"]);
+    }
+
+    #[test]
+    fn test_all_disabled() {
+        let mut prompt = MultiSelect::new(
+            "test message",
+            vec![
+                MultiSelectOption::disabled("Value1", "value1"),
+                MultiSelectOption::disabled("Value2", "value2"),
+            ],
+        );
+        assert!(matches!(
+            prompt.setup(),
+            Err(Error::Config { field: Some(field), .. }) if field == "options"
+        ));
+    }
+
+    #[test]
+    fn test_option_disabled() {
+        assert!(MultiSelectOption::disabled("Label", "value").disabled);
+        assert!(!MultiSelectOption::new("Label", "value").disabled);
+    }
+
+    test_prompt!(
+        test_selected,
+        MultiSelect::new("test message", options!(5))
+            .with_selected(&["value2".to_string(), "value4".to_string()]),
+        vec![(KeyCode::Enter, KeyModifiers::NONE)]
+    );
+
+    test_prompt!(
+        test_option_selected,
+        MultiSelect::new(
+            "test message",
+            vec![
+                MultiSelectOption::new("Value1", "value1".to_string()),
+                MultiSelectOption::new("Value2", "value2".to_string()).with_selected(true),
+                MultiSelectOption::new("Value3", "value3".to_string()),
+            ]
+        )
+        .as_mut(),
+        vec![(KeyCode::Enter, KeyModifiers::NONE)]
+    );
+
+    #[test]
+    fn test_defaults_submit() {
+        let mut prompt = MultiSelect::new("test message", options!(5));
+        prompt.with_defaults(&["value1".to_string(), "value5".to_string()]);
+        prompt.setup().unwrap();
+        assert_eq!(
+            prompt.handle(KeyCode::Enter, KeyModifiers::NONE),
+            PromptState::Submit
+        );
+        assert_eq!(prompt.submit(), vec!["value1", "value5"]);
+    }
+
+    test_prompt!(
+        test_loop,
+        MultiSelect::new("test message", options!(5))
+            .with_page_size(3)
+            .with_loop(true),
+        vec![
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_page_jump,
+        MultiSelect::new("test message", options!(20)).with_page_size(5),
+        vec![
+            (KeyCode::PageDown, KeyModifiers::NONE),
+            (KeyCode::PageDown, KeyModifiers::NONE),
+            (KeyCode::PageDown, KeyModifiers::NONE),
+            (KeyCode::PageUp, KeyModifiers::NONE),
+            (KeyCode::PageUp, KeyModifiers::NONE),
+            (KeyCode::PageUp, KeyModifiers::NONE),
+            (KeyCode::End, KeyModifiers::NONE),
+            (KeyCode::PageDown, KeyModifiers::NONE),
+            (KeyCode::Home, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_set_page_size() {
+        let mut prompt = MultiSelect::new("test message", options!(10));
+        prompt.set_page_size(3);
+        assert_eq!(prompt.page_size, 3);
+
+        let mut prompt = MultiSelect::new("test message", options!(10));
+        prompt.with_page_size(5).set_page_size(3);
+        assert_eq!(prompt.page_size, 5);
+    }
+
+    #[test]
+    fn test_option_display() {
+        let mut option = MultiSelectOption::new("Label", "value");
+        assert_eq!(option.to_string(), "[ ] Label");
+
+        option.selected = true;
+        assert_eq!(option.to_string(), "[x] Label");
+
+        let option = MultiSelectOption::new("Label", "value").with_hint("hint");
+        assert_eq!(option.to_string(), "[ ] Label (hint)");
+    }
 }
@@ -1,5 +1,11 @@
 use crate::event::*;
-use crate::{Error, InputCursor, Prompt, PromptInput, PromptState, RenderPayload, Validator};
+use crate::style::Symbol;
+use crate::{
+    DynValidator, Error, InputCursor, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptBody,
+    PromptInput, PromptState, RenderPayload, ValidationError, Validator,
+};
+
+const S_ICON: Symbol = Symbol("?", "?");
 
 /// A trait for formatting the [`Number`] prompt.
 ///
@@ -35,13 +41,20 @@ pub trait NumberFormatter {
     fn err_invalid_range(&self, min: isize, max: isize) -> String {
         format!("Must be a number between {} and {}.", min, max)
     }
+
+    /// Formats the raw input for display. Defaults to returning the input as is.  
+    /// The result is presentation-only: it may only insert characters around the raw ones, and the cursor, validation and the submitted value all keep using the raw input.
+    fn format_display(&self, value: &str) -> String {
+        value.to_string()
+    }
 }
 
 /// The default formatter for [`Number`].
+#[derive(Default)]
 pub struct DefaultNumberFormatter;
 
 impl DefaultNumberFormatter {
-    #[allow(clippy::new_without_default)]
+    /// Creates a new [`DefaultNumberFormatter`].
     pub fn new() -> Self {
         Self {}
     }
@@ -49,18 +62,83 @@ impl DefaultNumberFormatter {
 
 impl NumberFormatter for DefaultNumberFormatter {}
 
+/// A formatter for [`Number`] that displays the value with a separator between groups of thousands, such as `1,000,000`.
+///
+/// Only the display is affected. Validation still runs against the unformatted string, and the submitted value is the plain `isize`.
+///
+/// # Examples
+///
+/// ```
+/// use promptuity::prompts::{GroupedNumberFormatter, NumberFormatter};
+///
+/// assert_eq!(GroupedNumberFormatter::new().format_display("-1234567"), "-1,234,567");
+/// assert_eq!(GroupedNumberFormatter::new().with_separator("_").format_display("10000"), "10_000");
+/// ```
+pub struct GroupedNumberFormatter {
+    separator: String,
+}
+
+impl GroupedNumberFormatter {
+    /// Creates a new [`GroupedNumberFormatter`] with `,` as the separator.
+    pub fn new() -> Self {
+        Self {
+            separator: ",".into(),
+        }
+    }
+
+    /// Sets the separator inserted between groups of thousands.
+    pub fn with_separator(mut self, separator: impl std::fmt::Display) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+}
+
+impl Default for GroupedNumberFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumberFormatter for GroupedNumberFormatter {
+    fn format_display(&self, value: &str) -> String {
+        let digits = value.trim_start_matches(['-', '+']);
+        let sign = &value[..value.len() - digits.len()];
+        let digits = digits.chars().collect::<Vec<_>>();
+        let groups = digits
+            .rchunks(3)
+            .rev()
+            .map(|group| group.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        format!("{}{}", sign, groups.join(&self.separator))
+    }
+}
+
 /// A prompt for inputting only integer values.
 ///
 /// # Options
 ///
-/// - **Formatter**: Customizes the prompt display. See [`NumberFormatter`].
+/// - **Formatter**: Customizes the prompt display, such as grouping digits with [`GroupedNumberFormatter`]. See [`NumberFormatter`].
 /// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the input. Defaults to `None`.
 /// - **Placeholder**: An auxiliary message displayed when no input is given.
 /// - **Required**: A flag indicating whether to allow no input.
 /// - **Min Value**: The minimum value of `isize`. Defaults to `isize::MIN`.
 /// - **Max Value**: The maximum value of `isize`. Defaults to `isize::MAX`.
 /// - **Default Value**: The default value of `isize`.
-/// - **Validator**: A function to validate the value at the time of submission.
+/// - **Default Placeholder**: A default value displayed as the placeholder and submitted when the input is empty. Defaults to `None`.
+/// - **Allow Sign Prefix**: A flag indicating whether `+` and `-` can be typed as a prefix. Defaults to `false`, so negative numbers cannot be entered. Prior to this option, signs were always accepted; call `.with_allow_sign_prefix(true)` to keep that behavior.
+/// - **Step**: The amount added on increment and subtracted on decrement. Defaults to `1`. It is a shorthand for the step and decrement functions.
+/// - **Big Step**: The amount added and subtracted with <kbd>Shift</kbd> + <kbd>↑</kbd> / <kbd>↓</kbd>. Defaults to `10`.
+/// - **Step Function**: A function returning the next value on increment. Defaults to adding `1`.
+/// - **Decrement Function**: A function returning the next value on decrement. Defaults to subtracting `1`.
+/// - **Format Function**: A function formatting the value for display. Used for the submitted value and a preview while typing. Defaults to `None`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
+/// - **Validator**: Functions to validate the raw input string at the time of submission. Multiple validators run in order, and the first error is displayed.
+/// - **Value Validator**: A function to validate the parsed `isize` value at the time of submission. Runs only when the input is a valid integer.
+///
+/// # Lifecycle
+///
+/// All options, including the validators, are stored at construction. [`Prompt::setup`] checks that the range is consistent and calls [`Validator::prepare`] on each validator, so state such as compiled patterns is built once and reused by every [`Prompt::validate`].
 ///
 /// # Examples
 ///
@@ -73,11 +151,20 @@ pub struct Number {
     formatter: Box<dyn NumberFormatter>,
     message: String,
     hint: Option<String>,
+    description: Option<String>,
     placeholder: Option<String>,
+    default_placeholder: Option<isize>,
     required: bool,
     min: isize,
     max: isize,
-    validator: Option<Box<dyn Validator<String>>>,
+    allow_sign_prefix: bool,
+    validators: Vec<DynValidator<String>>,
+    value_validator: Option<DynValidator<isize>>,
+    step_fn: Box<dyn Fn(isize) -> isize>,
+    decrement_fn: Box<dyn Fn(isize) -> isize>,
+    big_step: isize,
+    format_fn: Option<Box<dyn Fn(isize) -> String>>,
+    keybindings: KeyBindings,
     input: InputCursor,
 }
 
@@ -85,14 +172,23 @@ impl Number {
     /// Creates a new [`Number`] prompt.
     pub fn new(message: impl std::fmt::Display) -> Self {
         Self {
-            formatter: Box::new(DefaultNumberFormatter::new()),
+            formatter: Box::<DefaultNumberFormatter>::default(),
             message: message.to_string(),
             hint: None,
+            description: None,
             placeholder: None,
+            default_placeholder: None,
             required: true,
-            validator: None,
+            validators: Vec::new(),
+            value_validator: None,
             min: isize::MIN,
             max: isize::MAX,
+            allow_sign_prefix: false,
+            step_fn: Box::new(|v| v.saturating_add(1)),
+            decrement_fn: Box::new(|v| v.saturating_sub(1)),
+            big_step: 10,
+            format_fn: None,
+            keybindings: KeyBindings::default(),
             input: InputCursor::new(String::new(), 0),
         }
     }
@@ -109,6 +205,13 @@ impl Number {
         self
     }
 
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the input.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
     /// Sets the placeholder message for the prompt.
     pub fn with_placeholder(&mut self, placeholder: impl std::fmt::Display) -> &mut Self {
         self.placeholder = Some(placeholder.to_string());
@@ -141,16 +244,145 @@ impl Number {
         self
     }
 
-    /// Sets the validator for the prompt.
+    /// Sets the default value for the prompt, displayed as the placeholder instead of pre-filling the input.  
+    /// Submitting with an empty input submits this value, after the same range check and validation as a typed value. A placeholder message set by [`Number::with_placeholder`] takes precedence in the display.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Number;
+    ///
+    /// let _ = Number::new("How many workers?").with_min(1).with_default_placeholder(4);
+    /// ```
+    pub fn with_default_placeholder(&mut self, value: isize) -> &mut Self {
+        self.default_placeholder = Some(value);
+        self
+    }
+
+    /// Sets whether `+` and `-` can be typed as a sign prefix.  
+    /// When disabled, both characters are ignored on input and negative numbers cannot be entered.  
+    /// Defaults to `false`. Signs used to be accepted unconditionally, so call `.with_allow_sign_prefix(true)` to keep the previous behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Number;
+    ///
+    /// let _ = Number::new("Temperature").with_allow_sign_prefix(true);
+    /// ```
+    pub fn with_allow_sign_prefix(&mut self, allow: bool) -> &mut Self {
+        self.allow_sign_prefix = allow;
+        self
+    }
+
+    /// Sets the amount added on increment and subtracted on decrement.  
+    /// This replaces the functions set by [`Number::with_step_fn`] and [`Number::with_decrement_fn`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Number;
+    ///
+    /// let _ = Number::new("Port").with_step(100).with_big_step(1000);
+    /// ```
+    pub fn with_step(&mut self, step: isize) -> &mut Self {
+        self.step_fn = Box::new(move |v| v.saturating_add(step));
+        self.decrement_fn = Box::new(move |v| v.saturating_sub(step));
+        self
+    }
+
+    /// Sets the amount added and subtracted by the `increment_big` and `decrement_big` key bindings.  
+    /// The resulting value is clamped to the range.
+    pub fn with_big_step(&mut self, step: isize) -> &mut Self {
+        self.big_step = step;
+        self
+    }
+
+    /// Sets the function that returns the next value on increment.  
+    /// It receives the current value, and the returned value is clamped to the range.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Number;
+    ///
+    /// let _ = Number::new("Buffer size")
+    ///     .with_step_fn(|v| v.saturating_mul(2))
+    ///     .with_decrement_fn(|v| v / 2);
+    /// ```
+    pub fn with_step_fn(&mut self, f: impl Fn(isize) -> isize + 'static) -> &mut Self {
+        self.step_fn = Box::new(f);
+        self
+    }
+
+    /// Sets the function that returns the next value on decrement.  
+    /// It receives the current value, and the returned value is clamped to the range.
+    pub fn with_decrement_fn(&mut self, f: impl Fn(isize) -> isize + 'static) -> &mut Self {
+        self.decrement_fn = Box::new(f);
+        self
+    }
+
+    /// Sets the function that formats the value for display.  
+    /// The formatted value is displayed on submission, and as a preview in the body while typing. The submitted value remains the `isize`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Number;
+    ///
+    /// let _ = Number::new("Timeout (seconds)").with_format_fn(|v| {
+    ///     format!("{:02}:{:02}:{:02}", v / 3600, v % 3600 / 60, v % 60)
+    /// });
+    /// ```
+    pub fn with_format_fn(&mut self, f: impl Fn(isize) -> String + 'static) -> &mut Self {
+        self.format_fn = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the validator for the raw input string of the prompt, replacing any validators already set.
     pub fn with_validator(&mut self, f: impl Validator<String> + 'static) -> &mut Self {
-        self.validator = Some(Box::new(move |value: &String| -> Result<(), String> {
-            f.validate(value).map_err(|err| err.to_string())
-        }));
+        self.validators = vec![Box::new(f)];
         self
     }
 
+    /// Adds a validator for the raw input string of the prompt, keeping the ones already set.  
+    /// Validators run in the order they were added, and the first error is displayed.
+    pub fn add_validator(&mut self, f: impl Validator<String> + 'static) -> &mut Self {
+        self.validators.push(Box::new(f));
+        self
+    }
+
+    /// Sets the validator for the parsed value of the prompt.  
+    /// It is called after the validators set by [`Number::with_validator`] and [`Number::add_validator`], and only when the input is a valid integer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Number;
+    ///
+    /// let _ = Number::new("Enter an even number").with_value_validator(|v: &isize| {
+    ///     if v % 2 == 0 {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("Must be an even number")
+    ///     }
+    /// });
+    /// ```
+    pub fn with_value_validator(&mut self, f: impl Validator<isize> + 'static) -> &mut Self {
+        self.value_validator = Some(Box::new(f));
+        self
+    }
+
+    /// Returns the raw input, or the default placeholder value when the input is empty.
+    fn raw_value(&self) -> String {
+        match self.default_placeholder {
+            Some(value) if self.input.is_empty() => value.to_string(),
+            _ => self.input.value(),
+        }
+    }
+
     fn value(&self) -> isize {
-        self.input.value().parse::<isize>().unwrap_or_default()
+        self.raw_value().parse::<isize>().unwrap_or_default()
     }
 
     fn starts_with_op(&self) -> bool {
@@ -158,6 +390,10 @@ impl Number {
         value.starts_with('-') || value.starts_with('+')
     }
 
+    fn can_insert_sign(&self) -> bool {
+        self.allow_sign_prefix && self.input.cursor() == 0 && !self.starts_with_op()
+    }
+
     fn is_within_range(&self, value: isize) -> bool {
         self.min <= value && value <= self.max
     }
@@ -174,24 +410,53 @@ impl Number {
                 }
                 self.input.insert(chr);
             }
-            '-' | '+' => {
-                if self.input.cursor() == 0 && !self.starts_with_op() {
-                    self.input.insert(chr);
-                }
+            '-' | '+' if self.can_insert_sign() => {
+                self.input.insert(chr);
             }
             _ => {}
         }
     }
 
     fn increment(&mut self) {
-        let value = self.value().saturating_add(1);
+        let value = (self.step_fn)(self.value());
         self.input = InputCursor::from(self.normalize_value(value).to_string());
     }
 
     fn decrement(&mut self) {
-        let value = self.value().saturating_sub(1);
+        let value = (self.decrement_fn)(self.value());
+        self.input = InputCursor::from(self.normalize_value(value).to_string());
+    }
+
+    /// Returns the input formatted by [`NumberFormatter::format_display`], keeping the cursor on the same raw character.
+    fn display_input(&self) -> InputCursor {
+        let raw = self.input.value().chars().collect::<Vec<_>>();
+        let formatted = self.formatter.format_display(&self.input.value());
+
+        // Match the raw characters in order to find where each one ended up.
+        let mut positions = Vec::with_capacity(raw.len());
+        for (i, c) in formatted.chars().enumerate() {
+            if raw.get(positions.len()) == Some(&c) {
+                positions.push(i);
+            }
+        }
+
+        let cursor = positions
+            .get(self.input.cursor())
+            .copied()
+            .unwrap_or_else(|| formatted.chars().count());
+        InputCursor::new(formatted, cursor)
+    }
+
+    fn add(&mut self, amount: isize) {
+        let value = self.value().saturating_add(amount);
         self.input = InputCursor::from(self.normalize_value(value).to_string());
     }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
 }
 
 impl AsMut<Number> for Number {
@@ -203,23 +468,47 @@ impl AsMut<Number> for Number {
 impl Prompt for Number {
     type Output = isize;
 
+    fn name(&self) -> &'static str {
+        "Number"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
     fn setup(&mut self) -> Result<(), crate::Error> {
         if self.min > self.max {
-            return Err(Error::Config(format!(
-                "min cannot be greater than max (min={}, max={})",
-                self.min, self.max
-            )));
+            return Err(Error::Config {
+                message: format!(
+                    "min cannot be greater than max (min={}, max={})",
+                    self.min, self.max
+                ),
+                field: Some("min".into()),
+                prompt: Some("Number".into()),
+                source: None,
+            });
         }
 
-        Ok(())
+        self.validators
+            .iter_mut()
+            .try_for_each(|validator| validator.prepare())?;
+        self.value_validator
+            .as_mut()
+            .map_or(Ok(()), |validator| validator.prepare())
     }
 
     fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
-        match (code, modifiers) {
-            (KeyCode::Enter, _) => {
-                if self.input.is_empty() && self.required {
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.submit.contains_key(code, modifiers) => {
+                let raw = self.raw_value();
+                if raw.is_empty() && self.required {
                     PromptState::Error(self.formatter.err_required())
-                } else if self.input.value().parse::<isize>().is_err() {
+                } else if raw.parse::<isize>().is_err() {
                     PromptState::Error(self.formatter.err_invalid_format())
                 } else if !self.is_within_range(self.value()) {
                     PromptState::Error(self.formatter.err_invalid_range(self.min, self.max))
@@ -227,52 +516,75 @@ impl Prompt for Number {
                     PromptState::Submit
                 }
             }
-            (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => PromptState::Cancel,
-            (KeyCode::Left, _) | (KeyCode::Char('b'), KeyModifiers::CONTROL) => {
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.move_prev_word.contains_key(code, modifiers) => {
+                self.input.move_prev_word();
+                PromptState::Active
+            }
+            _ if kb.move_next_word.contains_key(code, modifiers) => {
+                self.input.move_next_word();
+                PromptState::Active
+            }
+            _ if kb.move_left.contains_key(code, modifiers) => {
                 self.input.move_left();
                 PromptState::Active
             }
-            (KeyCode::Right, _) | (KeyCode::Char('f'), KeyModifiers::CONTROL) => {
+            _ if kb.move_right.contains_key(code, modifiers) => {
                 self.input.move_right();
                 PromptState::Active
             }
-            (KeyCode::Home, _) | (KeyCode::Char('a'), KeyModifiers::CONTROL) => {
+            _ if kb.move_home.contains_key(code, modifiers) => {
                 self.input.move_home();
                 PromptState::Active
             }
-            (KeyCode::End, _) | (KeyCode::Char('e'), KeyModifiers::CONTROL) => {
+            _ if kb.move_end.contains_key(code, modifiers) => {
                 self.input.move_end();
                 PromptState::Active
             }
-            (KeyCode::Backspace, _) | (KeyCode::Char('h'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_left_char.contains_key(code, modifiers) => {
                 self.input.delete_left_char();
                 PromptState::Active
             }
-            (KeyCode::Char('w'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_left_word.contains_key(code, modifiers) => {
                 self.input.delete_left_word();
                 PromptState::Active
             }
-            (KeyCode::Delete, _) | (KeyCode::Char('d'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_right_char.contains_key(code, modifiers) => {
                 self.input.delete_right_char();
                 PromptState::Active
             }
-            (KeyCode::Char('k'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_rest_line.contains_key(code, modifiers) => {
                 self.input.delete_rest_line();
                 PromptState::Active
             }
-            (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_line.contains_key(code, modifiers) => {
                 self.input.delete_line();
                 PromptState::Active
             }
-            (KeyCode::Up, _) => {
+            // Typing a sign takes precedence over key bindings on the same keys, such as in `KeyBindings::vi`.
+            KeyCode::Char(c @ ('-' | '+'))
+                if modifiers == KeyModifiers::NONE && self.can_insert_sign() =>
+            {
+                self.insert(c);
+                PromptState::Active
+            }
+            _ if kb.increment_big.contains_key(code, modifiers) => {
+                self.add(self.big_step);
+                PromptState::Active
+            }
+            _ if kb.decrement_big.contains_key(code, modifiers) => {
+                self.add(self.big_step.saturating_neg());
+                PromptState::Active
+            }
+            _ if kb.increment.contains_key(code, modifiers) => {
                 self.increment();
                 PromptState::Active
             }
-            (KeyCode::Down, _) => {
+            _ if kb.decrement.contains_key(code, modifiers) => {
                 self.decrement();
                 PromptState::Active
             }
-            (KeyCode::Char(c), KeyModifiers::NONE) => {
+            KeyCode::Char(c) if modifiers == KeyModifiers::NONE => {
                 self.insert(c);
                 PromptState::Active
             }
@@ -285,22 +597,48 @@ impl Prompt for Number {
     }
 
     fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
-        let payload = RenderPayload::new(
-            self.message.clone(),
-            self.hint.clone(),
-            self.placeholder.clone(),
-        );
+        let placeholder = match (&self.placeholder, self.default_placeholder) {
+            (None, Some(value)) => Some(match &self.format_fn {
+                Some(f) => f(value),
+                None => self.formatter.format_display(&value.to_string()),
+            }),
+            (placeholder, _) => placeholder.clone(),
+        };
+        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), placeholder)
+            .description(self.description.clone());
 
         match state {
-            PromptState::Submit => Ok(payload.input(PromptInput::Raw(self.value().to_string()))),
-            _ => Ok(payload.input(PromptInput::Cursor(self.input.clone()))),
+            PromptState::Submit => {
+                let value = self.value();
+                let raw = match &self.format_fn {
+                    Some(f) => f(value),
+                    None => self.formatter.format_display(&value.to_string()),
+                };
+                Ok(payload.input(PromptInput::Raw(raw)))
+            }
+            _ => {
+                let preview = match (&self.format_fn, self.input.value().parse::<isize>()) {
+                    (Some(f), Ok(value)) => PromptBody::Raw(f(value)),
+                    _ => PromptBody::None,
+                };
+                Ok(payload
+                    .input(PromptInput::Cursor(self.display_input()))
+                    .body(preview))
+            }
         }
     }
 
-    fn validate(&self) -> Result<(), String> {
-        self.validator
-            .as_ref()
-            .map_or(Ok(()), |validator| validator.validate(&self.input.value()))
+    fn validate(&self) -> Result<(), ValidationError> {
+        let value = self.raw_value();
+
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator.validate(&value))?;
+
+        match (&self.value_validator, value.parse::<isize>()) {
+            (Some(validator), Ok(value)) => validator.validate(&value),
+            _ => Ok(()),
+        }
     }
 }
 
@@ -341,7 +679,7 @@ mod tests {
 
     test_prompt!(
         test_number_input,
-        Number::new("test message").as_mut(),
+        Number::new("test message").with_allow_sign_prefix(true),
         vec![
             (KeyCode::Char('1'), KeyModifiers::NONE),
             (KeyCode::Char('2'), KeyModifiers::NONE),
@@ -364,8 +702,32 @@ mod tests {
 
     test_prompt!(
         test_invalid_format,
+        Number::new("test message").with_allow_sign_prefix(true),
+        vec![
+            (KeyCode::Char('-'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_sign_prefix_rejected,
         Number::new("test message").as_mut(),
         vec![
+            (KeyCode::Char('+'), KeyModifiers::NONE),
+            (KeyCode::Char('1'), KeyModifiers::NONE),
+            (KeyCode::Home, KeyModifiers::NONE),
+            (KeyCode::Char('-'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_sign_prefix_allowed,
+        Number::new("test message").with_allow_sign_prefix(true),
+        vec![
+            (KeyCode::Char('+'), KeyModifiers::NONE),
+            (KeyCode::Char('1'), KeyModifiers::NONE),
+            (KeyCode::Home, KeyModifiers::NONE),
             (KeyCode::Char('-'), KeyModifiers::NONE),
             (KeyCode::Enter, KeyModifiers::NONE),
         ]
@@ -409,4 +771,227 @@ mod tests {
             (KeyCode::Up, KeyModifiers::NONE),
         ]
     );
+
+    test_prompt!(
+        test_vi_keybindings,
+        Number::new("test message").with_keybindings(KeyBindings::vi()),
+        vec![
+            (KeyCode::Char('1'), KeyModifiers::NONE),
+            (KeyCode::Char('+'), KeyModifiers::NONE),
+            (KeyCode::Char('+'), KeyModifiers::NONE),
+            (KeyCode::Char('-'), KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_vi_keybindings_negative() {
+        let mut prompt = Number::new("test message");
+        prompt
+            .with_keybindings(KeyBindings::vi())
+            .with_allow_sign_prefix(true);
+
+        for c in ['-', '4', '2'] {
+            Prompt::handle(&mut prompt, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        assert_eq!(prompt.value(), -42);
+
+        // Away from the start of the input, the keys increment and decrement.
+        Prompt::handle(&mut prompt, KeyCode::Char('+'), KeyModifiers::NONE);
+        assert_eq!(prompt.value(), -41);
+    }
+
+    fn even(value: &isize) -> Result<(), String> {
+        if value % 2 == 0 {
+            Ok(())
+        } else {
+            Err("Must be an even number".into())
+        }
+    }
+
+    test_prompt!(
+        test_value_validator,
+        Number::new("test message").with_value_validator(even),
+        vec![
+            (KeyCode::Char('3'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Char('4'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_value_validator_skips_unparsable_input() {
+        let mut prompt = Number::new("test message");
+        prompt
+            .with_validator(|v: &String| {
+                if v.starts_with('+') {
+                    Err("Sign is not allowed")
+                } else {
+                    Ok(())
+                }
+            })
+            .with_value_validator(even)
+            .with_allow_sign_prefix(true);
+
+        Prompt::handle(&mut prompt, KeyCode::Char('-'), KeyModifiers::NONE);
+        assert_eq!(Prompt::validate(&prompt), Ok(()));
+
+        Prompt::handle(&mut prompt, KeyCode::Char('1'), KeyModifiers::NONE);
+        assert_eq!(
+            Prompt::validate(&prompt),
+            Err("Must be an even number".into())
+        );
+
+        Prompt::handle(&mut prompt, KeyCode::Char('u'), KeyModifiers::CONTROL);
+        Prompt::handle(&mut prompt, KeyCode::Char('+'), KeyModifiers::NONE);
+        Prompt::handle(&mut prompt, KeyCode::Char('1'), KeyModifiers::NONE);
+        assert_eq!(Prompt::validate(&prompt), Err("Sign is not allowed".into()));
+    }
+
+    fn hhmmss(value: isize) -> String {
+        format!(
+            "{:02}:{:02}:{:02}",
+            value / 3600,
+            value % 3600 / 60,
+            value % 60
+        )
+    }
+
+    test_prompt!(
+        test_format_fn,
+        Number::new("test message").with_format_fn(hhmmss),
+        vec![
+            (KeyCode::Char('3'), KeyModifiers::NONE),
+            (KeyCode::Char('6'), KeyModifiers::NONE),
+            (KeyCode::Char('6'), KeyModifiers::NONE),
+            (KeyCode::Char('1'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_format_fn_submit_value() {
+        let mut prompt = Number::new("test message");
+        prompt.with_format_fn(hhmmss).with_default(3661);
+        assert_eq!(Prompt::submit(&mut prompt), 3661);
+    }
+
+    test_prompt!(
+        test_step_fn,
+        Number::new("test message")
+            .with_default(1)
+            .with_max(10)
+            .with_step_fn(|v| v * 2)
+            .with_decrement_fn(|v| v / 2),
+        vec![
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_grouped_display,
+        Number::new("test message")
+            .with_allow_sign_prefix(true)
+            .with_formatter(GroupedNumberFormatter::new()),
+        vec![
+            (KeyCode::Char('-'), KeyModifiers::NONE),
+            (KeyCode::Char('1'), KeyModifiers::NONE),
+            (KeyCode::Char('2'), KeyModifiers::NONE),
+            (KeyCode::Char('3'), KeyModifiers::NONE),
+            (KeyCode::Char('4'), KeyModifiers::NONE),
+            (KeyCode::Char('5'), KeyModifiers::NONE),
+            (KeyCode::Char('6'), KeyModifiers::NONE),
+            (KeyCode::Char('7'), KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_grouped_submit_value() {
+        let mut prompt = Number::new("test message");
+        prompt
+            .with_formatter(GroupedNumberFormatter::new())
+            .with_validator(|v: &String| {
+                if v.contains(',') {
+                    Err("Unexpected separator")
+                } else {
+                    Ok(())
+                }
+            })
+            .with_default(1000000);
+        assert_eq!(Prompt::validate(&prompt), Ok(()));
+        assert_eq!(Prompt::submit(&mut prompt), 1000000);
+    }
+
+    test_prompt!(
+        test_step,
+        Number::new("test message")
+            .with_default(0)
+            .with_min(-100)
+            .with_max(100)
+            .with_step(5)
+            .with_big_step(50),
+        vec![
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::SHIFT),
+            (KeyCode::Up, KeyModifiers::SHIFT),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::SHIFT),
+            (KeyCode::Down, KeyModifiers::SHIFT),
+            (KeyCode::Down, KeyModifiers::SHIFT),
+            (KeyCode::Down, KeyModifiers::SHIFT),
+        ]
+    );
+
+    test_prompt!(
+        test_description,
+        Number::new("test message").with_description("line1\nline2"),
+        vec![
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char('1'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_default_placeholder,
+        Number::new("test message").with_default_placeholder(5),
+        vec![(KeyCode::Enter, KeyModifiers::NONE)]
+    );
+
+    test_prompt!(
+        test_default_placeholder_range,
+        Number::new("test message")
+            .with_min(10)
+            .with_default_placeholder(5),
+        vec![
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char('1'), KeyModifiers::NONE),
+            (KeyCode::Char('2'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_default_placeholder_value() {
+        let mut prompt = Number::new("test message");
+        prompt.with_default_placeholder(5);
+        assert_eq!(
+            prompt.handle(KeyCode::Enter, KeyModifiers::NONE),
+            PromptState::Submit
+        );
+        assert_eq!(prompt.submit(), 5);
+
+        prompt.handle(KeyCode::Up, KeyModifiers::NONE);
+        assert_eq!(prompt.submit(), 6);
+    }
 }
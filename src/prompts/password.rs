@@ -1,5 +1,71 @@
 use crate::event::*;
-use crate::{InputCursor, Prompt, PromptInput, PromptState, RenderPayload, Validator};
+use crate::style::Symbol;
+use crate::{
+    DynValidator, InputCursor, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptBody,
+    PromptInput, PromptState, RenderPayload, ValidationError, Validator,
+};
+
+const S_ICON: Symbol = Symbol("?", "?");
+
+type StrengthFn = Box<dyn Fn(&str) -> PasswordStrength>;
+
+/// The strength of a password, measured by [`PasswordStrength::measure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordStrength {
+    /// A password that is easy to guess.
+    Weak,
+    /// A password that is somewhat guessable.
+    Fair,
+    /// A password that is hard to guess.
+    Good,
+    /// A password that is very hard to guess.
+    Strong,
+}
+
+impl PasswordStrength {
+    /// Measures the strength of the given password.  
+    /// Length of 8 or more, uppercase, lowercase, digits, and symbols each contribute to the score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::prompts::PasswordStrength;
+    ///
+    /// assert_eq!(PasswordStrength::measure("abc"), PasswordStrength::Weak);
+    /// assert_eq!(PasswordStrength::measure("Abc123!"), PasswordStrength::Strong);
+    /// ```
+    pub fn measure(value: &str) -> Self {
+        let score = [
+            value.chars().count() >= 8,
+            value.chars().any(|c| c.is_uppercase()),
+            value.chars().any(|c| c.is_lowercase()),
+            value.chars().any(|c| c.is_ascii_digit()),
+            value
+                .chars()
+                .any(|c| !c.is_alphanumeric() && !c.is_whitespace()),
+        ]
+        .iter()
+        .filter(|&&v| v)
+        .count();
+
+        match score {
+            0 | 1 => Self::Weak,
+            2 => Self::Fair,
+            3 => Self::Good,
+            _ => Self::Strong,
+        }
+    }
+
+    /// Returns the level of the strength, from `1` (weak) to `4` (strong).
+    pub fn level(&self) -> usize {
+        match self {
+            Self::Weak => 1,
+            Self::Fair => 2,
+            Self::Good => 3,
+            Self::Strong => 4,
+        }
+    }
+}
 
 /// A trait for formatting the [`Password`] prompt.
 ///
@@ -25,13 +91,39 @@ pub trait PasswordFormatter {
     fn err_required(&self) -> String {
         "This field is required.".into()
     }
+
+    /// Formats the error message when the confirmation does not match the first entry.
+    fn err_mismatch(&self) -> String {
+        "Passwords do not match.".into()
+    }
+
+    /// Formats the message displayed while entering the confirmation.
+    fn confirm_message(&self) -> String {
+        "Confirm password".into()
+    }
+
+    /// Formats the strength meter displayed in the hint, or below the input when [`Password::with_strength`] is set.
+    fn format_strength(&self, strength: PasswordStrength) -> String {
+        let level = strength.level();
+        format!("Strength: {}{}", "⬛".repeat(level), "⬜".repeat(4 - level))
+    }
+
+    /// Formats the number of entered characters displayed in the hint when [`Password::with_show_length`] is enabled.
+    fn format_length(&self, length: usize) -> String {
+        if length == 1 {
+            "1 char".into()
+        } else {
+            format!("{} chars", length)
+        }
+    }
 }
 
 /// The default formatter for [`Password`].
+#[derive(Default)]
 pub struct DefaultPasswordFormatter;
 
 impl DefaultPasswordFormatter {
-    #[allow(clippy::new_without_default)]
+    /// Creates a new [`DefaultPasswordFormatter`].
     pub fn new() -> Self {
         Self {}
     }
@@ -47,7 +139,12 @@ impl PasswordFormatter for DefaultPasswordFormatter {}
 /// - **Hint**: A message to assist with field input. Defaults to `None`.
 /// - **Required**: A flag indicating whether to allow no input.
 /// - **Mask**: A string used to mask the input value. Defaults to `*`.
-/// - **Validator**: A function to validate the value at the time of submission.
+/// - **Strength Meter**: A flag indicating whether to display the password strength in the hint. Defaults to `false`.
+/// - **Show Length**: A flag indicating whether to display the number of entered characters in the hint. Defaults to `false`.
+/// - **Strength**: A function that measures the password strength, displayed below the input. Replaces the Strength Meter in the hint when set. Defaults to `None`.
+/// - **Confirmation**: A flag indicating whether to require typing the password twice. Defaults to `false`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
+/// - **Validator**: Functions to validate the value at the time of submission. Multiple validators run in order, and the first error is displayed.
 ///
 /// # Examples
 ///
@@ -62,7 +159,13 @@ pub struct Password {
     hint: Option<String>,
     required: bool,
     mask: char,
-    validator: Option<Box<dyn Validator<String>>>,
+    strength_meter: bool,
+    show_length: bool,
+    strength_fn: Option<StrengthFn>,
+    confirmation: bool,
+    first_entry: Option<String>,
+    validators: Vec<DynValidator<String>>,
+    keybindings: KeyBindings,
     input: InputCursor,
 }
 
@@ -70,12 +173,18 @@ impl Password {
     /// Creates a new [`Password`] prompt.
     pub fn new(message: impl std::fmt::Display) -> Self {
         Self {
-            formatter: Box::new(DefaultPasswordFormatter::new()),
+            formatter: Box::<DefaultPasswordFormatter>::default(),
             message: message.to_string(),
             hint: None,
             required: true,
             mask: '*',
-            validator: None,
+            strength_meter: false,
+            show_length: false,
+            strength_fn: None,
+            confirmation: false,
+            first_entry: None,
+            validators: Vec::new(),
+            keybindings: KeyBindings::default(),
             input: InputCursor::new(String::new(), 0),
         }
     }
@@ -104,15 +213,103 @@ impl Password {
         self
     }
 
-    /// Sets the validator for the prompt.
+    /// Sets whether to display the password strength in the hint.  
+    /// If a hint is set, the strength is appended to it. When [`Password::with_strength`] is set, the strength is displayed below the input instead, so it is not repeated in the hint.
+    pub fn with_strength_meter(&mut self, strength_meter: bool) -> &mut Self {
+        self.strength_meter = strength_meter;
+        self
+    }
+
+    /// Sets whether to display the number of entered characters in the hint, such as `8 chars`.  
+    /// The characters themselves stay masked. If a hint is set, the count is appended to it.
+    pub fn with_show_length(&mut self, show_length: bool) -> &mut Self {
+        self.show_length = show_length;
+        self
+    }
+
+    /// Sets the function that measures the password strength.  
+    /// The function is called with the actual value on each render, and the result is displayed below the input while typing. It is advisory only and does not prevent submission.  
+    /// This replaces the strength in the hint set by [`Password::with_strength_meter`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::{Password, PasswordStrength};
+    ///
+    /// let _ = Password::new("Choose a new password").with_strength(|value| {
+    ///     if value.chars().count() >= 12 {
+    ///         PasswordStrength::Strong
+    ///     } else {
+    ///         PasswordStrength::measure(value)
+    ///     }
+    /// });
+    /// ```
+    pub fn with_strength(&mut self, f: impl Fn(&str) -> PasswordStrength + 'static) -> &mut Self {
+        self.strength_fn = Some(Box::new(f));
+        self
+    }
+
+    /// Sets whether to require typing the password twice.  
+    /// When enabled, the first <kbd>Enter</kbd> asks for the password again with [`PasswordFormatter::confirm_message`]. If the two entries do not match, an error is displayed and the input starts over from the first entry.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use promptuity::prompts::Password;
+    ///
+    /// let _ = Password::new("Choose a new password").with_confirmation(true);
+    /// ```
+    pub fn with_confirmation(&mut self, confirmation: bool) -> &mut Self {
+        self.confirmation = confirmation;
+        self
+    }
+
+    /// Sets the validator for the prompt, replacing any validators already set.
     pub fn with_validator(&mut self, f: impl Validator<String> + 'static) -> &mut Self {
-        self.validator = Some(Box::new(move |value: &String| -> Result<(), String> {
-            f.validate(value).map_err(|err| err.to_string())
-        }));
+        self.validators = vec![Box::new(f)];
+        self
+    }
+
+    /// Adds a validator for the prompt, keeping the ones already set.  
+    /// Validators run in the order they were added, and the first error is displayed.
+    pub fn add_validator(&mut self, f: impl Validator<String> + 'static) -> &mut Self {
+        self.validators.push(Box::new(f));
+        self
+    }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
         self
     }
 }
 
+impl Password {
+    fn hint(&self, state: &PromptState) -> Option<String> {
+        if !matches!(state, PromptState::Active | PromptState::Error(_)) {
+            return self.hint.clone();
+        }
+
+        let mut extras = Vec::new();
+        // A custom strength function is displayed below the input instead.
+        if self.strength_meter && self.strength_fn.is_none() && self.first_entry.is_none() {
+            extras.push(
+                self.formatter
+                    .format_strength(PasswordStrength::measure(&self.input.value())),
+            );
+        }
+        if self.show_length {
+            extras.push(self.formatter.format_length(self.input.len()));
+        }
+
+        match &self.hint {
+            _ if extras.is_empty() => self.hint.clone(),
+            Some(hint) => Some(format!("{} ({})", hint, extras.join(", "))),
+            None => Some(extras.join(", ")),
+        }
+    }
+}
+
 impl AsMut<Password> for Password {
     fn as_mut(&mut self) -> &mut Password {
         self
@@ -122,57 +319,95 @@ impl AsMut<Password> for Password {
 impl Prompt for Password {
     type Output = String;
 
+    fn name(&self) -> &'static str {
+        "Password"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
+    fn setup(&mut self) -> Result<(), crate::Error> {
+        self.validators
+            .iter_mut()
+            .try_for_each(|validator| validator.prepare())
+    }
+
     fn handle(
         &mut self,
         code: crossterm::event::KeyCode,
         modifiers: crossterm::event::KeyModifiers,
     ) -> crate::PromptState {
-        match (code, modifiers) {
-            (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => PromptState::Cancel,
-            (KeyCode::Enter, _) => {
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.submit.contains_key(code, modifiers) => {
                 if self.input.is_empty() && self.required {
-                    PromptState::Error(self.formatter.err_required())
-                } else {
-                    PromptState::Submit
+                    return PromptState::Error(self.formatter.err_required());
+                }
+
+                if !self.confirmation {
+                    return PromptState::Submit;
+                }
+
+                match self.first_entry.take() {
+                    None => {
+                        // Validate the first entry, so that the confirmation is not asked for an invalid password.
+                        if let Err(err) = self.validate() {
+                            return err.into();
+                        }
+                        self.first_entry = Some(self.input.value());
+                        self.input = InputCursor::default();
+                        PromptState::Active
+                    }
+                    Some(first) if first == self.input.value() => PromptState::Submit,
+                    Some(_) => {
+                        self.input = InputCursor::default();
+                        PromptState::Error(self.formatter.err_mismatch())
+                    }
                 }
             }
-            (KeyCode::Left, _) | (KeyCode::Char('b'), KeyModifiers::CONTROL) => {
+            _ if kb.move_left.contains_key(code, modifiers) => {
                 self.input.move_left();
                 PromptState::Active
             }
-            (KeyCode::Right, _) | (KeyCode::Char('f'), KeyModifiers::CONTROL) => {
+            _ if kb.move_right.contains_key(code, modifiers) => {
                 self.input.move_right();
                 PromptState::Active
             }
-            (KeyCode::Home, _) | (KeyCode::Char('a'), KeyModifiers::CONTROL) => {
+            _ if kb.move_home.contains_key(code, modifiers) => {
                 self.input.move_home();
                 PromptState::Active
             }
-            (KeyCode::End, _) | (KeyCode::Char('e'), KeyModifiers::CONTROL) => {
+            _ if kb.move_end.contains_key(code, modifiers) => {
                 self.input.move_end();
                 PromptState::Active
             }
-            (KeyCode::Backspace, _) | (KeyCode::Char('h'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_left_char.contains_key(code, modifiers) => {
                 self.input.delete_left_char();
                 PromptState::Active
             }
-            (KeyCode::Char('w'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_left_word.contains_key(code, modifiers) => {
                 self.input.delete_left_word();
                 PromptState::Active
             }
-            (KeyCode::Delete, _) | (KeyCode::Char('d'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_right_char.contains_key(code, modifiers) => {
                 self.input.delete_right_char();
                 PromptState::Active
             }
-            (KeyCode::Char('k'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_rest_line.contains_key(code, modifiers) => {
                 self.input.delete_rest_line();
                 PromptState::Active
             }
-            (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
+            _ if kb.delete_line.contains_key(code, modifiers) => {
                 self.input.delete_line();
                 PromptState::Active
             }
-            (KeyCode::Char(c), _) => {
+            KeyCode::Char(c) => {
                 self.input.insert(c);
                 PromptState::Active
             }
@@ -180,26 +415,46 @@ impl Prompt for Password {
         }
     }
 
+    fn handle_paste(&mut self, text: String) -> PromptState {
+        let text = text.chars().filter(|c| !c.is_control()).collect::<String>();
+        self.input.insert_str(&text);
+        PromptState::Active
+    }
+
     fn submit(&mut self) -> Self::Output {
         self.input.value()
     }
 
-    fn render(&mut self, _: &crate::PromptState) -> Result<crate::RenderPayload, String> {
+    fn render(&mut self, state: &crate::PromptState) -> Result<crate::RenderPayload, String> {
         let input = InputCursor::new(
             self.input.value().chars().map(|_| self.mask).collect(),
             self.input.cursor(),
         );
 
-        Ok(
-            RenderPayload::new(self.message.clone(), self.hint.clone(), None)
-                .input(PromptInput::Cursor(input)),
-        )
+        let message = match (&self.first_entry, state) {
+            (Some(_), PromptState::Active | PromptState::Error(_)) => {
+                self.formatter.confirm_message()
+            }
+            _ => self.message.clone(),
+        };
+
+        let body = match (&self.strength_fn, &self.first_entry, state) {
+            (Some(f), None, PromptState::Active | PromptState::Error(_)) => {
+                PromptBody::Raw(self.formatter.format_strength(f(&self.input.value())))
+            }
+            _ => PromptBody::None,
+        };
+
+        Ok(RenderPayload::new(message, self.hint(state), None)
+            .input(PromptInput::Cursor(input))
+            .body(body))
     }
 
-    fn validate(&self) -> Result<(), String> {
-        self.validator
-            .as_ref()
-            .map_or(Ok(()), |validator| validator.validate(&self.input.value()))
+    fn validate(&self) -> Result<(), ValidationError> {
+        let value = self.input.value();
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator.validate(&value))
     }
 }
 
@@ -208,6 +463,43 @@ mod tests {
     use super::*;
     use crate::test_prompt;
 
+    #[test]
+    fn test_strength_measure() {
+        assert_eq!(PasswordStrength::measure(""), PasswordStrength::Weak);
+        assert_eq!(PasswordStrength::measure("abc"), PasswordStrength::Weak);
+        assert_eq!(PasswordStrength::measure("abc123"), PasswordStrength::Fair);
+        assert_eq!(PasswordStrength::measure("Abc123"), PasswordStrength::Good);
+        assert_eq!(
+            PasswordStrength::measure("Abc123!"),
+            PasswordStrength::Strong
+        );
+        assert_eq!(
+            PasswordStrength::measure("Abcdef123!"),
+            PasswordStrength::Strong
+        );
+    }
+
+    #[test]
+    fn test_format_strength() {
+        let formatter: DefaultPasswordFormatter = Default::default();
+        assert_eq!(
+            formatter.format_strength(PasswordStrength::Weak),
+            "Strength: ⬛⬜⬜⬜"
+        );
+        assert_eq!(
+            formatter.format_strength(PasswordStrength::Fair),
+            "Strength: ⬛⬛⬜⬜"
+        );
+        assert_eq!(
+            formatter.format_strength(PasswordStrength::Good),
+            "Strength: ⬛⬛⬛⬜"
+        );
+        assert_eq!(
+            formatter.format_strength(PasswordStrength::Strong),
+            "Strength: ⬛⬛⬛⬛"
+        );
+    }
+
     test_prompt!(
         test_hint,
         Password::new("test message").with_hint("hint message"),
@@ -283,4 +575,103 @@ mod tests {
             (KeyCode::Enter, KeyModifiers::NONE),
         ]
     );
+
+    test_prompt!(
+        test_confirmation,
+        Password::new("test message").with_confirmation(true),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('c'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ],
+        full
+    );
+
+    #[test]
+    fn test_confirmation_submit_value() {
+        let mut prompt = Password::new("test message");
+        prompt.with_confirmation(true).with_validator(|v: &String| {
+            if v.len() < 3 {
+                Err("Too short")
+            } else {
+                Ok(())
+            }
+        });
+
+        let mut enter = |value: &str| {
+            for c in value.chars() {
+                prompt.handle(KeyCode::Char(c), KeyModifiers::NONE);
+            }
+            prompt.handle(KeyCode::Enter, KeyModifiers::NONE)
+        };
+        assert_eq!(enter("ab"), PromptState::Error("Too short".into()));
+        assert_eq!(enter("c"), PromptState::Active);
+        assert_eq!(enter("abc"), PromptState::Submit);
+        assert_eq!(prompt.submit(), "abc");
+    }
+
+    test_prompt!(
+        test_strength,
+        Password::new("test message").with_strength(|v| match v.len() {
+            0..=1 => PasswordStrength::Weak,
+            _ => PasswordStrength::Strong,
+        }),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_strength_meter,
+        Password::new("test message").with_strength_meter(true),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('B'), KeyModifiers::NONE),
+            (KeyCode::Char('1'), KeyModifiers::NONE),
+            (KeyCode::Char('!'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ],
+        full
+    );
+
+    test_prompt!(
+        test_strength_with_strength_meter,
+        Password::new("test message")
+            .with_strength_meter(true)
+            .with_strength(|v| match v.len() {
+                0..=1 => PasswordStrength::Weak,
+                _ => PasswordStrength::Strong,
+            }),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ],
+        full
+    );
+
+    test_prompt!(
+        test_show_length,
+        Password::new("test message")
+            .with_hint("hint message")
+            .with_show_length(true),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('日'), KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ],
+        full
+    );
 }
@@ -0,0 +1,538 @@
+use std::path::PathBuf;
+
+use crate::event::*;
+use crate::style::{Color, Styled, Symbol};
+use crate::{
+    InputCursor, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptBody, PromptInput,
+    PromptState, RenderPayload, ValidationError,
+};
+
+const S_ICON: Symbol = Symbol("?", "?");
+const S_SEPARATOR: char = std::path::MAIN_SEPARATOR;
+
+/// A trait for formatting the [`Path`] prompt.
+///
+/// All methods have default implementations, allowing you to override only the specific formatting process you need.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{Path, PathFormatter};
+///
+/// struct CustomFormatter;
+///
+/// impl PathFormatter for CustomFormatter {
+///     fn err_not_found(&self, path: &str) -> String {
+///         format!("{} does not exist.", path)
+///     }
+/// }
+///
+/// let _ = Path::new("...").with_formatter(CustomFormatter);
+/// ```
+pub trait PathFormatter {
+    /// Formats the error message when the input is empty and required.
+    fn err_required(&self) -> String {
+        "This field is required.".into()
+    }
+
+    /// Formats the error message when the path must exist but does not.
+    fn err_not_found(&self, path: &str) -> String {
+        format!("No such file or directory: {}", path)
+    }
+
+    /// Formats the error message when only directories are accepted but the path is not a directory.
+    fn err_not_dir(&self, path: &str) -> String {
+        format!("Not a directory: {}", path)
+    }
+
+    /// Formats a completion candidate. `focused` is `true` for the candidate currently inserted by cycling.
+    fn candidate(&self, name: &str, focused: bool) -> String {
+        if focused {
+            Styled::new(name).fg(Color::Cyan).to_string()
+        } else {
+            Styled::new(name).fg(Color::DarkGrey).to_string()
+        }
+    }
+}
+
+/// The default formatter for [`Path`].
+#[derive(Default)]
+pub struct DefaultPathFormatter;
+
+impl DefaultPathFormatter {
+    /// Creates a new [`DefaultPathFormatter`].
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl PathFormatter for DefaultPathFormatter {}
+
+/// A prompt for inputting a filesystem path with tab-completion.
+///
+/// The input is edited in the same way as [`crate::prompts::Input`]. <kbd>Tab</kbd> completes the last component of the path against the directory listing: the input is extended to the longest common prefix of the matching entries, and repeated presses cycle through them. Directories are completed with a trailing separator. The candidates are displayed below the input.
+///
+/// # Options
+///
+/// - **Formatter**: Customizes the prompt display. See [`PathFormatter`].
+/// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the input. Defaults to `None`.
+/// - **Placeholder**: An auxiliary message displayed when no input is given.
+/// - **Required**: A flag indicating whether to allow no input. Defaults to `true`.
+/// - **Default Value**: The default value of the input.
+/// - **Base Directory**: The directory that relative paths are completed and validated against. Defaults to the current directory.
+/// - **Must Exist**: A flag indicating whether the path must exist at the time of submission. Defaults to `false`.
+/// - **Only Directories**: A flag indicating whether to complete only directories, and reject existing paths that are not directories. Defaults to `false`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::Path;
+///
+/// let _ = Path::new("Where is the config file?").with_must_exist(true);
+/// let _ = Path::new("Output directory").with_only_dirs(true);
+/// ```
+pub struct Path {
+    formatter: Box<dyn PathFormatter>,
+    message: String,
+    hint: Option<String>,
+    description: Option<String>,
+    placeholder: Option<String>,
+    required: bool,
+    base_dir: Option<PathBuf>,
+    must_exist: bool,
+    only_dirs: bool,
+    keybindings: KeyBindings,
+    input: InputCursor,
+    completion_dir: String,
+    candidates: Vec<String>,
+    focused: Option<usize>,
+}
+
+impl Path {
+    /// Creates a new [`Path`] prompt.
+    pub fn new(message: impl std::fmt::Display) -> Self {
+        Self {
+            formatter: Box::<DefaultPathFormatter>::default(),
+            message: message.to_string(),
+            hint: None,
+            description: None,
+            placeholder: None,
+            required: true,
+            base_dir: None,
+            must_exist: false,
+            only_dirs: false,
+            keybindings: KeyBindings::default(),
+            input: InputCursor::default(),
+            completion_dir: String::new(),
+            candidates: Vec::new(),
+            focused: None,
+        }
+    }
+
+    /// Sets the formatter for the prompt.
+    pub fn with_formatter(&mut self, formatter: impl PathFormatter + 'static) -> &mut Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Sets the hint message for the prompt.
+    pub fn with_hint(&mut self, hint: impl std::fmt::Display) -> &mut Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the input.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Sets the placeholder message for the prompt.
+    pub fn with_placeholder(&mut self, placeholder: impl std::fmt::Display) -> &mut Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Sets the required flag for the prompt.
+    pub fn with_required(&mut self, required: bool) -> &mut Self {
+        self.required = required;
+        self
+    }
+
+    /// Sets the default value for the prompt.
+    pub fn with_default(&mut self, value: impl std::fmt::Display) -> &mut Self {
+        self.input = InputCursor::from(value.to_string());
+        self
+    }
+
+    /// Sets the directory that relative paths are completed and validated against.  
+    /// The submitted value is returned as typed, without being joined to this directory.
+    pub fn with_base_dir(&mut self, dir: impl Into<PathBuf>) -> &mut Self {
+        self.base_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets whether the path must exist at the time of submission.
+    pub fn with_must_exist(&mut self, must_exist: bool) -> &mut Self {
+        self.must_exist = must_exist;
+        self
+    }
+
+    /// Sets whether to complete only directories.  
+    /// When enabled, an existing path that is not a directory is also rejected at the time of submission.
+    pub fn with_only_dirs(&mut self, only_dirs: bool) -> &mut Self {
+        self.only_dirs = only_dirs;
+        self
+    }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+
+    fn resolve(&self, path: &str) -> PathBuf {
+        match &self.base_dir {
+            Some(base) => base.join(path),
+            None => PathBuf::from(path),
+        }
+    }
+
+    fn split_input(&self) -> (String, String) {
+        let value = self.input.value();
+        match value.rfind(['/', S_SEPARATOR]) {
+            Some(i) => (value[..=i].to_string(), value[i + 1..].to_string()),
+            None => (String::new(), value),
+        }
+    }
+
+    fn read_candidates(&self, dir: &str, prefix: &str) -> Vec<String> {
+        let dir = self.resolve(if dir.is_empty() { "." } else { dir });
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+
+        let mut candidates = entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let name = entry.file_name().into_string().ok()?;
+                if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.'))
+                {
+                    return None;
+                }
+                let is_dir = entry.path().is_dir();
+                match (is_dir, self.only_dirs) {
+                    (true, _) => Some(format!("{}{}", name, S_SEPARATOR)),
+                    (false, false) => Some(name),
+                    (false, true) => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        candidates.sort();
+        candidates
+    }
+
+    fn complete(&mut self) {
+        // Cycle through the candidates of the previous completion.
+        if !self.candidates.is_empty() {
+            let index = self.focused.map_or(0, |i| (i + 1) % self.candidates.len());
+            self.focused = Some(index);
+            self.input =
+                InputCursor::from(format!("{}{}", self.completion_dir, self.candidates[index]));
+            return;
+        }
+
+        let (dir, prefix) = self.split_input();
+        let candidates = self.read_candidates(&dir, &prefix);
+        match candidates.as_slice() {
+            [] => {}
+            [candidate] => {
+                self.input = InputCursor::from(format!("{}{}", dir, candidate));
+            }
+            _ => {
+                let common = common_prefix(&candidates);
+                if common.chars().count() > prefix.chars().count() {
+                    self.input = InputCursor::from(format!("{}{}", dir, common));
+                }
+                self.completion_dir = dir;
+                self.candidates = candidates;
+            }
+        }
+    }
+
+    fn reset_completion(&mut self) {
+        self.candidates.clear();
+        self.focused = None;
+    }
+}
+
+fn common_prefix(candidates: &[String]) -> String {
+    let mut iter = candidates.iter();
+    let first = iter.next().cloned().unwrap_or_default();
+    iter.fold(first, |acc, s| {
+        acc.chars()
+            .zip(s.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect()
+    })
+}
+
+impl AsMut<Path> for Path {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl Prompt for Path {
+    type Output = PathBuf;
+
+    fn name(&self) -> &'static str {
+        "Path"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
+    fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
+        let kb = &self.keybindings;
+        if kb.complete.contains_key(code, modifiers) {
+            self.complete();
+            return PromptState::Active;
+        }
+
+        self.reset_completion();
+
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.submit.contains_key(code, modifiers) => {
+                if self.input.is_empty() && self.required {
+                    PromptState::Error(self.formatter.err_required())
+                } else {
+                    PromptState::Submit
+                }
+            }
+            _ if kb.move_prev_word.contains_key(code, modifiers) => {
+                self.input.move_prev_word();
+                PromptState::Active
+            }
+            _ if kb.move_next_word.contains_key(code, modifiers) => {
+                self.input.move_next_word();
+                PromptState::Active
+            }
+            _ if kb.move_left.contains_key(code, modifiers) => {
+                self.input.move_left();
+                PromptState::Active
+            }
+            _ if kb.move_right.contains_key(code, modifiers) => {
+                self.input.move_right();
+                PromptState::Active
+            }
+            _ if kb.move_home.contains_key(code, modifiers) => {
+                self.input.move_home();
+                PromptState::Active
+            }
+            _ if kb.move_end.contains_key(code, modifiers) => {
+                self.input.move_end();
+                PromptState::Active
+            }
+            _ if kb.delete_left_char.contains_key(code, modifiers) => {
+                self.input.delete_left_char();
+                PromptState::Active
+            }
+            _ if kb.delete_left_word.contains_key(code, modifiers) => {
+                self.input.delete_left_word();
+                PromptState::Active
+            }
+            _ if kb.delete_right_char.contains_key(code, modifiers) => {
+                self.input.delete_right_char();
+                PromptState::Active
+            }
+            _ if kb.delete_rest_line.contains_key(code, modifiers) => {
+                self.input.delete_rest_line();
+                PromptState::Active
+            }
+            _ if kb.delete_line.contains_key(code, modifiers) => {
+                self.input.delete_line();
+                PromptState::Active
+            }
+            KeyCode::Char(c) if !c.is_control() => {
+                self.input.insert(c);
+                PromptState::Active
+            }
+            _ => PromptState::Active,
+        }
+    }
+
+    fn handle_paste(&mut self, text: String) -> PromptState {
+        self.reset_completion();
+        let text = text.chars().filter(|c| !c.is_control()).collect::<String>();
+        self.input.insert_str(&text);
+        PromptState::Active
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        PathBuf::from(self.input.value())
+    }
+
+    fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
+        let payload = RenderPayload::new(
+            self.message.clone(),
+            self.hint.clone(),
+            self.placeholder.clone(),
+        )
+        .description(self.description.clone())
+        .input(PromptInput::Cursor(self.input.clone()));
+
+        match state {
+            PromptState::Active | PromptState::Error(_) if !self.candidates.is_empty() => {
+                let body = self
+                    .candidates
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| self.formatter.candidate(name, self.focused == Some(i)))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(payload.body(PromptBody::Raw(body)))
+            }
+            _ => Ok(payload),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        let value = self.input.value();
+        if value.is_empty() {
+            return Ok(());
+        }
+
+        let path = self.resolve(&value);
+        if !path.exists() {
+            return match self.must_exist {
+                true => Err(self.formatter.err_not_found(&value).into()),
+                false => Ok(()),
+            };
+        }
+
+        if self.only_dirs && !path.is_dir() {
+            return Err(self.formatter.err_not_dir(&value).into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prompt;
+
+    fn fixture(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("promptuity-path-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src/prompts")).unwrap();
+        std::fs::create_dir_all(dir.join("scripts")).unwrap();
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+        std::fs::write(dir.join("Cargo.toml"), "").unwrap();
+        std::fs::write(dir.join("Cargo.lock"), "").unwrap();
+        std::fs::write(dir.join("src/lib.rs"), "").unwrap();
+        std::fs::write(dir.join("src/prompt.rs"), "").unwrap();
+        dir
+    }
+
+    test_prompt!(
+        test_hint,
+        Path::new("test message").with_hint("hint message"),
+        vec![]
+    );
+
+    test_prompt!(
+        test_complete_single,
+        Path::new("test message").with_base_dir(fixture("single")),
+        vec![
+            (KeyCode::Char('s'), KeyModifiers::NONE),
+            (KeyCode::Char('r'), KeyModifiers::NONE),
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::Char('l'), KeyModifiers::NONE),
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_complete_cycle,
+        Path::new("test message").with_base_dir(fixture("cycle")),
+        vec![
+            (KeyCode::Char('C'), KeyModifiers::NONE),
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_complete_only_dirs,
+        Path::new("test message")
+            .with_base_dir(fixture("only_dirs"))
+            .with_only_dirs(true),
+        vec![
+            (KeyCode::Char('s'), KeyModifiers::NONE),
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::Char('x'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_must_exist,
+        Path::new("test message")
+            .with_base_dir(fixture("must_exist"))
+            .with_must_exist(true),
+        vec![
+            (KeyCode::Char('x'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Char('s'), KeyModifiers::NONE),
+            (KeyCode::Char('r'), KeyModifiers::NONE),
+            (KeyCode::Char('c'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_only_dirs_rejects_files() {
+        let mut prompt = Path::new("test message");
+        prompt
+            .with_base_dir(fixture("rejects_files"))
+            .with_only_dirs(true)
+            .with_default("Cargo.toml");
+        assert!(prompt.validate().is_err());
+
+        prompt.with_default("src");
+        assert!(prompt.validate().is_ok());
+        assert_eq!(prompt.submit(), PathBuf::from("src"));
+    }
+
+    #[test]
+    fn test_common_prefix() {
+        assert_eq!(common_prefix(&[]), "");
+        assert_eq!(common_prefix(&["abc".into()]), "abc");
+        assert_eq!(
+            common_prefix(&["Cargo.lock".into(), "Cargo.toml".into()]),
+            "Cargo."
+        );
+        assert_eq!(common_prefix(&["ab".into(), "cd".into()]), "");
+    }
+}
@@ -1,10 +1,20 @@
 use crate::event::*;
 use crate::pagination::paginate;
 use crate::style::*;
-use crate::{Error, Prompt, PromptBody, PromptInput, PromptState, RenderPayload};
+use crate::{
+    Error, InputCursor, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptBody, PromptInput,
+    PromptState, RenderPayload,
+};
 
 const S_UNSELECTED: Symbol = Symbol("◯", "[ ]");
 const S_SELECTED: Symbol = Symbol("◉", "[x]");
+const S_ICON: Symbol = Symbol("▶", ">");
+
+/// The columns reserved for the prefix that themes add to each line of the body.
+const LINE_MARGIN: u16 = 4;
+
+/// The time window in which typed characters are accumulated into the typeahead buffer.
+const TYPEAHEAD_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(500);
 
 /// A struct representing an option in the [`Select`] prompt.
 #[derive(Debug, Clone)]
@@ -15,6 +25,10 @@ pub struct SelectOption<T: Default + Clone> {
     pub value: T,
     /// The hint message of the option. Defaults to `None`.
     pub hint: Option<String>,
+    /// The disabled flag of the option. Disabled options are displayed but cannot be selected. Defaults to `false`.
+    pub disabled: bool,
+    /// The separator flag of the option. Separators are displayed as group headers and cannot be selected. Defaults to `false`.
+    pub separator: bool,
 }
 
 impl<T: Default + Clone> SelectOption<T> {
@@ -24,6 +38,22 @@ impl<T: Default + Clone> SelectOption<T> {
             label: label.to_string(),
             value,
             hint: None,
+            disabled: false,
+            separator: false,
+        }
+    }
+
+    /// Creates a new disabled [`SelectOption`] with the given label and value.
+    pub fn disabled(label: impl std::fmt::Display, value: T) -> Self {
+        Self::new(label, value).with_disabled(true)
+    }
+
+    /// Creates a new separator with the given label.  
+    /// A separator is displayed as a header that groups the options following it, and is skipped when moving the cursor.
+    pub fn separator(label: impl std::fmt::Display) -> Self {
+        Self {
+            separator: true,
+            ..Self::new(label, T::default()).with_disabled(true)
         }
     }
 
@@ -32,6 +62,39 @@ impl<T: Default + Clone> SelectOption<T> {
         self.hint = Some(hint.to_string());
         self
     }
+
+    /// Sets the disabled flag for the option.
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Two options are equal if their label and value are equal, regardless of the hint and disabled flag.
+impl<T: Default + Clone + PartialEq> PartialEq for SelectOption<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.value == other.value
+    }
+}
+
+impl<T: Default + Clone + Eq> Eq for SelectOption<T> {}
+
+impl<T: Default + Clone + std::hash::Hash> std::hash::Hash for SelectOption<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.label.hash(state);
+        self.value.hash(state);
+    }
+}
+
+/// Formats the option as `label (hint)`, omitting the hint if it is not set.
+impl<T: Default + Clone> std::fmt::Display for SelectOption<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)?;
+        if let Some(hint) = &self.hint {
+            write!(f, " ({})", hint)?;
+        }
+        Ok(())
+    }
 }
 
 /// A trait for customizing the display of [`Select`].
@@ -68,8 +131,10 @@ pub trait SelectFormatter {
     }
 
     /// Formats the label of the option.
-    fn option_label(&self, label: String, active: bool) -> String {
-        if active {
+    fn option_label(&self, label: String, active: bool, disabled: bool) -> String {
+        if disabled {
+            Styled::new(label).fg(Color::DarkGrey).dim().to_string()
+        } else if active {
             Styled::new(label).underline().to_string()
         } else {
             Styled::new(label).fg(Color::DarkGrey).to_string()
@@ -92,13 +157,36 @@ pub trait SelectFormatter {
         let _ = active;
         format!("{} {}{}", icon, label, hint)
     }
+
+    /// Formats the separator created by [`SelectOption::separator`].
+    fn separator(&self, label: String) -> String {
+        Styled::new(label).fg(Color::DarkGrey).bold().to_string()
+    }
+
+    /// Formats the position indicator, such as `3–8 of 40`, displayed below the options.
+    fn position_indicator(&self, label: String) -> String {
+        Styled::new(label).fg(Color::DarkGrey).to_string()
+    }
+
+    /// Formats the message displayed in place of the options when nothing matches the filter.
+    fn no_matches(&self) -> String {
+        Styled::new("<No matches found>")
+            .fg(Color::DarkGrey)
+            .to_string()
+    }
+
+    /// Formats the error message when submitting while nothing matches the filter.
+    fn err_no_matches(&self) -> String {
+        "No matches found.".into()
+    }
 }
 
 /// The default formatter for [`Select`].
+#[derive(Default)]
 pub struct DefaultSelectFormatter;
 
 impl DefaultSelectFormatter {
-    #[allow(clippy::new_without_default)]
+    /// Creates a new [`DefaultSelectFormatter`].
     pub fn new() -> Self {
         Self {}
     }
@@ -112,11 +200,22 @@ impl SelectFormatter for DefaultSelectFormatter {}
 ///
 /// - **Formatter**: Customizes the prompt display. See [`SelectFormatter`].
 /// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the options. Defaults to `None`.
 /// - **Page Size**: The total number of options to displayed per page, used for pagination. Defaults to `8`.
+/// - **Filterable**: Narrows the options by the typed query. Defaults to `false`.
+/// - **Typeahead**: Jumps to the option starting with the typed characters. Defaults to `false`.
+/// - **Loop**: Wraps the cursor around when moving past the first or last option. Defaults to `false`.
+/// - **Position Indicator**: Displays the range of the options shown, when they exceed the page size. Defaults to `false`.
+/// - **Default**: The option where the cursor starts, by index or by value. Defaults to the first option.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
+///
+/// # Separators
+///
+/// Options can be grouped under headers by inserting [`SelectOption::separator`] between them. Separators are not selectable, and are hidden while filtering.
 ///
 /// # Notes
 ///
-/// Passing an empty `options` will result in an error. Please ensure to provide `options` with at least one item.
+/// Passing an empty `options`, or `options` that are all disabled, will result in an error. Please ensure to provide `options` with at least one selectable item.
 ///
 /// # Examples
 ///
@@ -127,27 +226,55 @@ impl SelectFormatter for DefaultSelectFormatter {}
 ///     SelectOption::new("Red", "#ff0000"),
 ///     SelectOption::new("Green", "#00ff00").with_hint("recommended"),
 ///     SelectOption::new("Blue", "#0000ff"),
+///     SelectOption::disabled("Black", "#000000").with_hint("sold out"),
 /// ]).with_page_size(5);
 /// ```
 pub struct Select<T: Default + Clone> {
     formatter: Box<dyn SelectFormatter>,
     message: String,
     hint: Option<String>,
+    description: Option<String>,
     page_size: usize,
+    page_size_explicit: bool,
+    looping: bool,
+    position_indicator: bool,
     options: Vec<SelectOption<T>>,
+    filterable: bool,
+    filter: InputCursor,
+    filtered_options: Vec<usize>,
+    typeahead: bool,
+    typeahead_buffer: String,
+    typeahead_at: Option<std::time::Instant>,
+    keybindings: KeyBindings,
+    default_index: Option<usize>,
     index: usize,
+    width: Option<u16>,
 }
 
 impl<T: Default + Clone> Select<T> {
     /// Creates a new [`Select`] prompt with the given message and options.
     pub fn new(message: impl std::fmt::Display, options: Vec<SelectOption<T>>) -> Self {
+        let filtered_options = (0..options.len()).collect();
         Self {
-            formatter: Box::new(DefaultSelectFormatter::new()),
+            formatter: Box::<DefaultSelectFormatter>::default(),
             message: message.to_string(),
             hint: None,
+            description: None,
             page_size: 8,
+            page_size_explicit: false,
+            looping: false,
+            position_indicator: false,
             options,
+            filterable: false,
+            filter: InputCursor::default(),
+            filtered_options,
+            typeahead: false,
+            typeahead_buffer: String::new(),
+            typeahead_at: None,
+            keybindings: KeyBindings::default(),
+            default_index: None,
             index: 0,
+            width: None,
         }
     }
 
@@ -163,11 +290,204 @@ impl<T: Default + Clone> Select<T> {
         self
     }
 
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the options.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
     /// Sets the page size for the prompt.
     pub fn with_page_size(&mut self, page_size: usize) -> &mut Self {
         self.page_size = page_size;
+        self.page_size_explicit = true;
+        self
+    }
+
+    /// Sets whether the options can be filtered by typing.  
+    /// When enabled, typed characters are collected into a query, and only options whose label contains it (ignoring case) are displayed.
+    /// Character keys without modifiers are used for the query, so bindings such as <kbd>j</kbd> / <kbd>k</kbd> and <kbd>Space</kbd> are not available.
+    pub fn with_filterable(&mut self, filterable: bool) -> &mut Self {
+        self.filterable = filterable;
         self
     }
+
+    /// Sets whether typing jumps to the matching option.  
+    /// When enabled, characters typed in quick succession are accumulated, and the cursor moves to the first option whose label starts with them (ignoring case). Pressing the same letter repeatedly cycles through the options starting with it.
+    /// Character keys without modifiers, except <kbd>Space</kbd>, are used for the typeahead, so bindings such as <kbd>j</kbd> / <kbd>k</kbd> are not available. [`Select::with_filterable`] takes precedence over this option.
+    pub fn with_typeahead(&mut self, typeahead: bool) -> &mut Self {
+        self.typeahead = typeahead;
+        self
+    }
+
+    /// Sets the index of the option where the cursor starts.  
+    /// Out-of-range indices are clamped to the last option rather than panicking. If the option is disabled, the cursor starts on the nearest selectable option after it.
+    pub fn with_default_index(&mut self, index: usize) -> &mut Self {
+        self.default_index = Some(index);
+        self
+    }
+
+    /// Sets whether to display the position of the current page, such as `3–8 of 40`, below the options.  
+    /// It is displayed only when the options do not fit in a single page.
+    pub fn with_position_indicator(&mut self, position_indicator: bool) -> &mut Self {
+        self.position_indicator = position_indicator;
+        self
+    }
+
+    /// Sets whether the cursor wraps around, moving from the last option to the first and vice versa.
+    pub fn with_loop(&mut self, looping: bool) -> &mut Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+}
+
+impl<T: Default + Clone + PartialEq> Select<T> {
+    /// Sets the value of the option where the cursor starts.  
+    /// If no option has the value, the default is left unchanged.
+    pub fn with_default_value(&mut self, value: &T) -> &mut Self {
+        if let Some(index) = self
+            .options
+            .iter()
+            .position(|option| option.value == *value)
+        {
+            self.default_index = Some(index);
+        }
+        self
+    }
+}
+
+impl<T: Default + Clone> Select<T> {
+    fn run_filter(&mut self) {
+        let query = self.filter.value().to_lowercase();
+        self.filtered_options = self
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| {
+                if option.separator {
+                    query.is_empty()
+                } else {
+                    option.label.to_lowercase().contains(&query)
+                }
+            })
+            .map(|(i, _)| i)
+            .collect();
+        self.clamp_index();
+    }
+
+    /// Returns the index of the option under the cursor in the original `options`.  
+    /// The index is independent of pagination and filtering. Returns `None` if no option matches the filter.
+    pub fn selected_index(&self) -> Option<usize> {
+        self.filtered_options.get(self.index).copied()
+    }
+
+    fn current_option(&self) -> Option<&SelectOption<T>> {
+        self.filtered_options
+            .get(self.index)
+            .and_then(|idx| self.options.get(*idx))
+    }
+
+    fn is_selectable(&self, index: usize) -> bool {
+        self.filtered_options
+            .get(index)
+            .and_then(|idx| self.options.get(*idx))
+            .is_some_and(|option| !option.disabled)
+    }
+
+    fn move_up(&mut self) {
+        let len = self.filtered_options.len();
+        let index = (0..self.index).rev().find(|i| self.is_selectable(*i));
+        let index = match index {
+            None if self.looping => (self.index + 1..len).rev().find(|i| self.is_selectable(*i)),
+            index => index,
+        };
+        if let Some(index) = index {
+            self.index = index;
+        }
+    }
+
+    fn move_down(&mut self) {
+        let len = self.filtered_options.len();
+        let index = (self.index + 1..len).find(|i| self.is_selectable(*i));
+        let index = match index {
+            None if self.looping => (0..self.index).find(|i| self.is_selectable(*i)),
+            index => index,
+        };
+        if let Some(index) = index {
+            self.index = index;
+        }
+    }
+
+    fn run_typeahead(&mut self, c: char) {
+        let now = std::time::Instant::now();
+        let expired = match self.typeahead_at {
+            Some(at) => now.duration_since(at) > TYPEAHEAD_TIMEOUT,
+            None => true,
+        };
+        if expired {
+            self.typeahead_buffer.clear();
+        }
+        self.typeahead_at = Some(now);
+        self.typeahead_buffer.extend(c.to_lowercase());
+
+        // Repeating the same letter cycles through the options starting with it, beginning after the current one.
+        let mut chars = self.typeahead_buffer.chars();
+        let first = chars.next().unwrap_or_default();
+        let (prefix, start) = if chars.all(|c| c == first) {
+            (first.to_string(), self.index + 1)
+        } else {
+            (self.typeahead_buffer.clone(), self.index)
+        };
+
+        let len = self.filtered_options.len();
+        let index = (start..len).chain(0..start).find(|i| {
+            self.is_selectable(*i)
+                && self.options[self.filtered_options[*i]]
+                    .label
+                    .to_lowercase()
+                    .starts_with(&prefix)
+        });
+        if let Some(index) = index {
+            self.index = index;
+        }
+    }
+
+    /// Moves the cursor to the selectable option nearest to `target`, searching in the given direction first.
+    fn jump(&mut self, target: usize, forward: bool) {
+        let len = self.filtered_options.len();
+        let target = std::cmp::min(len.saturating_sub(1), target);
+        let index = if forward {
+            (target..len)
+                .find(|i| self.is_selectable(*i))
+                .or_else(|| (0..target).rev().find(|i| self.is_selectable(*i)))
+        } else {
+            (0..=target)
+                .rev()
+                .find(|i| self.is_selectable(*i))
+                .or_else(|| (target + 1..len).find(|i| self.is_selectable(*i)))
+        };
+        if let Some(index) = index {
+            self.index = index;
+        }
+    }
+
+    /// Keeps the index within the filtered options, moving it off a disabled option if possible.
+    fn clamp_index(&mut self) {
+        self.index = std::cmp::min(self.filtered_options.len().saturating_sub(1), self.index);
+        if !self.is_selectable(self.index) {
+            let len = self.filtered_options.len();
+            self.index = (self.index..len)
+                .chain((0..self.index).rev())
+                .find(|i| self.is_selectable(*i))
+                .unwrap_or(self.index);
+        }
+    }
 }
 
 impl<T: Default + Clone> AsMut<Select<T>> for Select<T> {
@@ -176,72 +496,312 @@ impl<T: Default + Clone> AsMut<Select<T>> for Select<T> {
     }
 }
 
+/// A prompt that wraps [`Select`] and outputs the index of the selected option instead of its value.
+///
+/// The output is the position in the `options` passed to [`Select::new`], regardless of the page displayed or the filter applied.
+/// This is useful when the values are expensive to clone or cannot be compared.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{Select, SelectIndex, SelectOption};
+///
+/// let mut select = Select::new("What is your favorite color?", vec![
+///     SelectOption::new("Red", "#ff0000"),
+///     SelectOption::new("Green", "#00ff00"),
+///     SelectOption::new("Blue", "#0000ff"),
+/// ]);
+/// select.with_page_size(2);
+///
+/// let _ = SelectIndex::new(select);
+/// ```
+pub struct SelectIndex<T: Default + Clone> {
+    select: Select<T>,
+}
+
+impl<T: Default + Clone> SelectIndex<T> {
+    /// Creates a new [`SelectIndex`] prompt from the given [`Select`].
+    pub fn new(select: Select<T>) -> Self {
+        Self { select }
+    }
+}
+
+impl<T: Default + Clone> From<Select<T>> for SelectIndex<T> {
+    fn from(select: Select<T>) -> Self {
+        Self::new(select)
+    }
+}
+
+impl<T: Default + Clone> AsMut<SelectIndex<T>> for SelectIndex<T> {
+    fn as_mut(&mut self) -> &mut SelectIndex<T> {
+        self
+    }
+}
+
+impl<T: Default + Clone> Prompt for SelectIndex<T> {
+    type Output = usize;
+
+    fn name(&self) -> &'static str {
+        self.select.name()
+    }
+
+    fn icon(&self) -> Option<String> {
+        self.select.icon()
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.select.set_cancel_keys(keys);
+    }
+
+    fn set_page_size(&mut self, page_size: usize) {
+        self.select.set_page_size(page_size);
+    }
+
+    fn set_width(&mut self, width: u16) {
+        self.select.set_width(width);
+    }
+
+    fn setup(&mut self) -> Result<(), Error> {
+        self.select.setup()
+    }
+
+    fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
+        self.select.handle(code, modifiers)
+    }
+
+    fn handle_paste(&mut self, text: String) -> PromptState {
+        self.select.handle_paste(text)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent, row: Option<usize>) -> PromptState {
+        self.select.handle_mouse(event, row)
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        self.select.selected_index().unwrap()
+    }
+
+    fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
+        self.select.render(state)
+    }
+}
+
 impl<T: Default + Clone> Prompt for Select<T> {
     type Output = T;
 
+    fn name(&self) -> &'static str {
+        "Select"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
+    fn set_page_size(&mut self, page_size: usize) {
+        if !self.page_size_explicit {
+            self.page_size = page_size;
+        }
+    }
+
+    fn set_width(&mut self, width: u16) {
+        self.width = Some(width);
+    }
+
     fn setup(&mut self) -> Result<(), Error> {
         if self.options.is_empty() {
-            return Err(Error::Config("options cannot be empty.".into()));
+            return Err(Error::Config {
+                message: "options cannot be empty.".into(),
+                field: Some("options".into()),
+                prompt: Some("Select".into()),
+                source: None,
+            });
         }
+
+        if self.options.iter().all(|option| option.disabled) {
+            return Err(Error::Config {
+                message: "options cannot be all disabled.".into(),
+                field: Some("options".into()),
+                prompt: Some("Select".into()),
+                source: None,
+            });
+        }
+
+        if let Some(index) = self.default_index {
+            self.index = index;
+        }
+        self.clamp_index();
+
         Ok(())
     }
 
     fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> crate::PromptState {
-        match (code, modifiers) {
-            (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => PromptState::Cancel,
-            (KeyCode::Enter, _) | (KeyCode::Char(' '), _) => PromptState::Submit,
-            (KeyCode::Up, _)
-            | (KeyCode::Char('k'), _)
-            | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
-                self.index = self.index.saturating_sub(1);
+        let kb = &self.keybindings;
+        let typing = self.filterable
+            && (modifiers == KeyModifiers::NONE || modifiers == KeyModifiers::SHIFT);
+        match code {
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            KeyCode::Char(c) if typing => {
+                self.filter.insert(c);
+                self.run_filter();
+                PromptState::Active
+            }
+            KeyCode::Char(c)
+                if self.typeahead
+                    && c != ' '
+                    && (modifiers == KeyModifiers::NONE || modifiers == KeyModifiers::SHIFT) =>
+            {
+                self.run_typeahead(c);
+                PromptState::Active
+            }
+            _ if kb.submit.contains_key(code, modifiers)
+                || kb.toggle.contains_key(code, modifiers) =>
+            {
+                match self.current_option() {
+                    Some(option) if option.disabled => PromptState::Active,
+                    Some(_) => PromptState::Submit,
+                    None => PromptState::Error(self.formatter.err_no_matches()),
+                }
+            }
+            _ if kb.move_up.contains_key(code, modifiers) => {
+                self.move_up();
                 PromptState::Active
             }
-            (KeyCode::Down, _)
-            | (KeyCode::Char('j'), _)
-            | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
-                self.index = std::cmp::min(
-                    self.options.len().saturating_sub(1),
-                    self.index.saturating_add(1),
-                );
+            _ if kb.move_down.contains_key(code, modifiers) => {
+                self.move_down();
+                PromptState::Active
+            }
+            _ if kb.page_up.contains_key(code, modifiers) => {
+                self.jump(self.index.saturating_sub(self.page_size), false);
+                PromptState::Active
+            }
+            _ if kb.page_down.contains_key(code, modifiers) => {
+                self.jump(self.index.saturating_add(self.page_size), true);
+                PromptState::Active
+            }
+            _ if kb.move_home.contains_key(code, modifiers) => {
+                self.jump(0, true);
+                PromptState::Active
+            }
+            _ if kb.move_end.contains_key(code, modifiers) => {
+                self.jump(usize::MAX, false);
+                PromptState::Active
+            }
+            _ if self.filterable && kb.delete_left_char.contains_key(code, modifiers) => {
+                self.filter.delete_left_char();
+                self.run_filter();
+                PromptState::Active
+            }
+            _ if self.filterable && kb.delete_left_word.contains_key(code, modifiers) => {
+                self.filter.delete_left_word();
+                self.run_filter();
+                PromptState::Active
+            }
+            _ if self.filterable && kb.delete_line.contains_key(code, modifiers) => {
+                self.filter.delete_line();
+                self.run_filter();
                 PromptState::Active
             }
             _ => PromptState::Active,
         }
     }
 
+    fn handle_paste(&mut self, text: String) -> PromptState {
+        if !self.filterable {
+            return PromptState::Active;
+        }
+        let text = text.chars().filter(|c| !c.is_control()).collect::<String>();
+        self.filter.insert_str(&text);
+        self.run_filter();
+        PromptState::Active
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent, row: Option<usize>) -> PromptState {
+        match event.kind {
+            MouseEventKind::ScrollUp => self.move_up(),
+            MouseEventKind::ScrollDown => self.move_down(),
+            MouseEventKind::Down(MouseButton::Left) => {
+                let page = paginate(self.page_size, &self.filtered_options, self.index);
+                let index = row
+                    .filter(|row| *row < page.items.len())
+                    .map(|row| page.global_index_of(row));
+                if let Some(index) = index.filter(|index| self.is_selectable(*index)) {
+                    self.index = index;
+                    return PromptState::Submit;
+                }
+            }
+            _ => {}
+        }
+        PromptState::Active
+    }
+
     fn submit(&mut self) -> Self::Output {
-        let option = self.options.get(self.index).unwrap();
+        let option = self.current_option().unwrap();
         option.value.clone()
     }
 
     fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
-        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), None);
+        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), None)
+            .description(self.description.clone());
 
         match state {
             PromptState::Submit => {
-                let option = self.options.get(self.index).unwrap();
+                let option = self.current_option().unwrap();
                 Ok(payload.input(PromptInput::Raw(option.label.clone())))
             }
 
             _ => {
-                let page = paginate(self.page_size, &self.options, self.index);
+                let page = paginate(self.page_size, &self.filtered_options, self.index);
                 let options = page
                     .items
                     .iter()
                     .enumerate()
-                    .map(|(i, option)| {
-                        let active = i == page.cursor;
-                        self.formatter.option(
-                            self.formatter.option_icon(active),
-                            self.formatter.option_label(option.label.clone(), active),
-                            self.formatter.option_hint(option.hint.clone(), active),
-                            active,
-                        )
+                    .map(|(i, idx)| {
+                        let option = self.options.get(*idx).unwrap();
+                        let line = if option.separator {
+                            self.formatter.separator(option.label.clone())
+                        } else {
+                            let active = i == page.cursor;
+                            self.formatter.option(
+                                self.formatter.option_icon(active),
+                                self.formatter.option_label(
+                                    option.label.clone(),
+                                    active,
+                                    option.disabled,
+                                ),
+                                self.formatter.option_hint(option.hint.clone(), active),
+                                active,
+                            )
+                        };
+                        match self.width {
+                            Some(width) => {
+                                truncate_to_width(&line, width.saturating_sub(LINE_MARGIN))
+                            }
+                            None => line,
+                        }
                     })
-                    .collect::<Vec<_>>()
-                    .join("\n");
+                    .collect::<Vec<_>>();
+
+                let raw = if options.is_empty() {
+                    self.formatter.no_matches()
+                } else if self.position_indicator && !(page.first && page.last) {
+                    format!(
+                        "{}\n{}",
+                        options.join("\n"),
+                        self.formatter.position_indicator(page.position_label())
+                    )
+                } else {
+                    options.join("\n")
+                };
 
-                let raw = options.to_string();
+                let payload = if self.filterable {
+                    payload.input(PromptInput::Cursor(self.filter.clone()))
+                } else {
+                    payload
+                };
 
                 Ok(payload.body(PromptBody::Raw(raw)))
             }
@@ -253,6 +813,7 @@ impl<T: Default + Clone> Prompt for Select<T> {
 mod tests {
     use super::*;
     use crate::test_prompt;
+    use crate::KeyBinding;
 
     macro_rules! options {
         ($count: expr) => {{
@@ -341,4 +902,355 @@ mod tests {
             (KeyCode::Enter, KeyModifiers::NONE),
         ]
     );
+
+    test_prompt!(
+        test_custom_keybindings,
+        Select::new("test message", options!(5)).with_keybindings({
+            let mut kb = KeyBindings::vi();
+            kb.move_down.push(KeyBinding::any(KeyCode::Tab));
+            kb
+        }),
+        vec![
+            (KeyCode::Tab, KeyModifiers::NONE),
+            (KeyCode::Char('j'), KeyModifiers::NONE),
+            (KeyCode::Char('n'), KeyModifiers::CONTROL),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_option_eq() {
+        assert_eq!(
+            options!(2),
+            vec![
+                SelectOption::new("Value1", "value1".to_string()).with_hint("hint"),
+                SelectOption::new("Value2", "value2".to_string()),
+            ]
+        );
+        assert_ne!(
+            SelectOption::new("Value1", 1),
+            SelectOption::new("Value2", 1)
+        );
+
+        let set: std::collections::HashSet<_> =
+            options!(3).into_iter().chain(options!(2)).collect();
+        assert_eq!(set.len(), 3);
+    }
+
+    test_prompt!(
+        test_description,
+        Select::new("test message", options!(3)).with_description("line1\nline2"),
+        vec![(KeyCode::Enter, KeyModifiers::NONE)]
+    );
+
+    test_prompt!(
+        test_filterable,
+        Select::new(
+            "test message",
+            vec![
+                SelectOption::new("Rust", "rust".to_string()),
+                SelectOption::new("Ruby", "ruby".to_string()),
+                SelectOption::new("Go", "go".to_string()),
+                SelectOption::new("TypeScript", "typescript".to_string()),
+            ]
+        )
+        .with_filterable(true),
+        vec![
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Char('R'), KeyModifiers::SHIFT),
+            (KeyCode::Char('u'), KeyModifiers::NONE),
+            (KeyCode::Char('x'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    macro_rules! disabled_options {
+        () => {{
+            vec![
+                SelectOption::disabled("Value1", "value1".to_string()),
+                SelectOption::new("Value2", "value2".to_string()),
+                SelectOption::disabled("Value3", "value3".to_string()),
+                SelectOption::new("Value4", "value4".to_string()),
+                SelectOption::disabled("Value5", "value5".to_string()),
+            ]
+        }};
+    }
+
+    test_prompt!(
+        test_disabled,
+        Select::new("test message", disabled_options!()).as_mut(),
+        vec![
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_position_indicator,
+        Select::new("test message", options!(10))
+            .with_page_size(4)
+            .with_position_indicator(true),
+        vec![
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_separator,
+        Select::new(
+            "test message",
+            vec![
+                SelectOption::separator("Group1"),
+                SelectOption::new("Value1", "value1".to_string()),
+                SelectOption::new("Value2", "value2".to_string()),
+                SelectOption::separator("Group2"),
+                SelectOption::new("Value3", "value3".to_string()),
+            ]
+        )
+        .as_mut(),
+        vec![
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_separator_filter() {
+        let mut prompt = Select::new(
+            "test message",
+            vec![
+                SelectOption::separator("Group1"),
+                SelectOption::new("Value1", "value1"),
+            ],
+        );
+        prompt.with_filterable(true);
+        prompt.setup().unwrap();
+        prompt.handle(KeyCode::Char('g'), KeyModifiers::NONE);
+        assert_eq!(prompt.selected_index(), None);
+        prompt.handle(KeyCode::Backspace, KeyModifiers::NONE);
+        assert_eq!(prompt.selected_index(), Some(1));
+    }
+
+    #[test]
+    fn test_disabled_submit() {
+        let mut prompt = Select::new("test message", disabled_options!());
+        prompt.setup().unwrap();
+        assert_eq!(prompt.index, 1);
+
+        prompt.index = 2;
+        assert_eq!(
+            prompt.handle(KeyCode::Enter, KeyModifiers::NONE),
+            PromptState::Active
+        );
+        assert_eq!(
+            prompt.handle(KeyCode::Char(' '), KeyModifiers::NONE),
+            PromptState::Active
+        );
+    }
+
+    #[test]
+    fn test_all_disabled() {
+        let mut prompt = Select::new(
+            "test message",
+            vec![
+                SelectOption::disabled("Value1", "value1"),
+                SelectOption::disabled("Value2", "value2"),
+            ],
+        );
+        assert!(matches!(
+            prompt.setup(),
+            Err(Error::Config { field: Some(field), .. }) if field == "options"
+        ));
+    }
+
+    #[test]
+    fn test_option_disabled() {
+        assert!(SelectOption::disabled("Label", "value").disabled);
+        assert!(!SelectOption::new("Label", "value").disabled);
+        assert!(
+            SelectOption::new("Label", "value")
+                .with_disabled(true)
+                .disabled
+        );
+    }
+
+    test_prompt!(
+        test_default_index,
+        Select::new("test message", options!(10))
+            .with_page_size(5)
+            .with_default_index(6),
+        vec![(KeyCode::Enter, KeyModifiers::NONE)]
+    );
+
+    #[test]
+    fn test_default_index_clamp() {
+        let mut prompt = Select::new("test message", options!(3));
+        prompt.with_default_index(100).setup().unwrap();
+        assert_eq!(prompt.index, 2);
+
+        let mut prompt = Select::new("test message", disabled_options!());
+        prompt.with_default_index(2).setup().unwrap();
+        assert_eq!(prompt.index, 3);
+    }
+
+    #[test]
+    fn test_default_value() {
+        let mut prompt = Select::new("test message", options!(5));
+        prompt
+            .with_default_value(&"value4".to_string())
+            .setup()
+            .unwrap();
+        assert_eq!(prompt.index, 3);
+
+        let mut prompt = Select::new("test message", options!(5));
+        prompt
+            .with_default_value(&"unknown".to_string())
+            .setup()
+            .unwrap();
+        assert_eq!(prompt.index, 0);
+    }
+
+    test_prompt!(
+        test_loop,
+        Select::new("test message", options!(5))
+            .with_page_size(3)
+            .with_loop(true),
+        vec![
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_page_jump,
+        Select::new("test message", options!(20))
+            .with_page_size(5)
+            .with_default_index(10),
+        vec![
+            (KeyCode::PageDown, KeyModifiers::NONE),
+            (KeyCode::PageUp, KeyModifiers::NONE),
+            (KeyCode::PageUp, KeyModifiers::NONE),
+            (KeyCode::PageUp, KeyModifiers::NONE),
+            (KeyCode::End, KeyModifiers::NONE),
+            (KeyCode::PageDown, KeyModifiers::NONE),
+            (KeyCode::Home, KeyModifiers::NONE),
+        ]
+    );
+
+    macro_rules! languages {
+        () => {{
+            ["Go", "Java", "JavaScript", "Perl", "PHP", "Python", "Rust"]
+                .into_iter()
+                .map(|label| SelectOption::new(label, label.to_lowercase()))
+                .collect::<Vec<_>>()
+        }};
+    }
+
+    test_prompt!(
+        test_typeahead,
+        Select::new("test message", languages!()).with_typeahead(true),
+        vec![
+            (KeyCode::Char('p'), KeyModifiers::NONE),
+            (KeyCode::Char('p'), KeyModifiers::NONE),
+            (KeyCode::Char('p'), KeyModifiers::NONE),
+            (KeyCode::Char('p'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_typeahead_prefix() {
+        let mut prompt = Select::new("test message", languages!());
+        prompt.with_typeahead(true).setup().unwrap();
+
+        prompt.handle(KeyCode::Char('J'), KeyModifiers::SHIFT);
+        prompt.handle(KeyCode::Char('a'), KeyModifiers::NONE);
+        prompt.handle(KeyCode::Char('v'), KeyModifiers::NONE);
+        prompt.handle(KeyCode::Char('a'), KeyModifiers::NONE);
+        prompt.handle(KeyCode::Char('s'), KeyModifiers::NONE);
+        assert_eq!(prompt.index, 2);
+
+        // The buffer is reset once the window has passed.
+        prompt.typeahead_at = std::time::Instant::now().checked_sub(TYPEAHEAD_TIMEOUT * 2);
+        prompt.handle(KeyCode::Char('r'), KeyModifiers::NONE);
+        assert_eq!(prompt.index, 6);
+    }
+
+    #[test]
+    fn test_set_page_size() {
+        let mut prompt = Select::new("test message", options!(10));
+        prompt.set_page_size(3);
+        assert_eq!(prompt.page_size, 3);
+
+        let mut prompt = Select::new("test message", options!(10));
+        prompt.with_page_size(5).set_page_size(3);
+        assert_eq!(prompt.page_size, 5);
+    }
+
+    #[test]
+    fn test_option_display() {
+        assert_eq!(SelectOption::new("Label", "value").to_string(), "Label");
+        assert_eq!(
+            SelectOption::new("Label", "value")
+                .with_hint("hint")
+                .to_string(),
+            "Label (hint)"
+        );
+    }
+
+    #[test]
+    fn test_truncate_to_width() {
+        let mut prompt = Select::new(
+            "test message",
+            vec![
+                SelectOption::new("日本語ABCDEF", "value1"),
+                SelectOption::new("Short", "value2"),
+            ],
+        );
+        prompt.with_formatter(PlainFormatter);
+        prompt.set_width(12);
+
+        let payload = prompt.render(&PromptState::Active).unwrap();
+        assert_eq!(payload.body.visible_content().unwrap(), "> 日本…\n  Short");
+    }
+
+    struct PlainFormatter;
+
+    impl SelectFormatter for PlainFormatter {
+        fn option_icon(&self, active: bool) -> String {
+            if active { ">" } else { " " }.into()
+        }
+
+        fn option_label(&self, label: String, _active: bool, _disabled: bool) -> String {
+            label
+        }
+    }
+
+    #[test]
+    fn test_select_index() {
+        let mut select = Select::new("test message", options!(12));
+        select.with_page_size(5).with_filterable(true);
+        let mut prompt = SelectIndex::new(select);
+        prompt.setup().unwrap();
+
+        prompt.handle(KeyCode::Char('1'), KeyModifiers::NONE);
+        prompt.handle(KeyCode::Down, KeyModifiers::NONE);
+        assert_eq!(
+            prompt.handle(KeyCode::Down, KeyModifiers::NONE),
+            PromptState::Active
+        );
+        assert_eq!(prompt.submit(), 10);
+    }
 }
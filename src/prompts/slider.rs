@@ -0,0 +1,335 @@
+use crate::event::*;
+use crate::style::{Color, Styled, Symbol};
+use crate::{
+    Error, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptBody, PromptInput, PromptState,
+    RenderPayload,
+};
+
+const S_ICON: Symbol = Symbol("?", "?");
+const S_FILLED: Symbol = Symbol("■", "#");
+const S_EMPTY: Symbol = Symbol("□", "-");
+
+/// A trait for formatting the [`Slider`] prompt.
+///
+/// All methods have default implementations, allowing you to override only the specific formatting process you need.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{Slider, SliderFormatter};
+///
+/// struct CustomFormatter;
+///
+/// impl SliderFormatter for CustomFormatter {
+///     fn value(&self, value: isize) -> String {
+///         format!("{}%", value)
+///     }
+/// }
+///
+/// let _ = Slider::new("Volume", 0, 100).with_formatter(CustomFormatter);
+/// ```
+pub trait SliderFormatter {
+    /// Formats the bar. `filled` is the number of filled cells out of `width`.
+    fn bar(&self, filled: usize, width: usize) -> String {
+        format!(
+            "[{}{}]",
+            Styled::new(S_FILLED.to_string().repeat(filled)).fg(Color::Cyan),
+            Styled::new(S_EMPTY.to_string().repeat(width - filled)).fg(Color::DarkGrey)
+        )
+    }
+
+    /// Formats the current value displayed next to the bar and on submission.
+    fn value(&self, value: isize) -> String {
+        value.to_string()
+    }
+
+    /// Formats the layout of the active prompt.
+    fn layout(&self, bar: String, value: String) -> String {
+        format!("{} {}", bar, value)
+    }
+}
+
+/// The default formatter for [`Slider`].
+#[derive(Default)]
+pub struct DefaultSliderFormatter;
+
+impl DefaultSliderFormatter {
+    /// Creates a new [`DefaultSliderFormatter`].
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SliderFormatter for DefaultSliderFormatter {}
+
+/// A prompt for choosing a number within a range with a horizontal bar.
+///
+/// <kbd>Left</kbd> and <kbd>Right</kbd> move the value by the step, and <kbd>Home</kbd> and <kbd>End</kbd> move it to the bounds. The value never leaves the range.
+///
+/// # Options
+///
+/// - **Formatter**: Customizes the prompt display. See [`SliderFormatter`].
+/// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the bar. Defaults to `None`.
+/// - **Step**: The amount the value moves per key press. Defaults to `1`.
+/// - **Default Value**: The initial value, clamped to the range. Defaults to `min`.
+/// - **Width**: The number of cells in the bar. Defaults to `20`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::Slider;
+///
+/// let _ = Slider::new("Volume", 0, 100).with_step(10).with_default(40);
+/// ```
+pub struct Slider {
+    formatter: Box<dyn SliderFormatter>,
+    message: String,
+    hint: Option<String>,
+    description: Option<String>,
+    min: isize,
+    max: isize,
+    step: isize,
+    width: usize,
+    keybindings: KeyBindings,
+    value: isize,
+}
+
+impl Slider {
+    /// Creates a new [`Slider`] prompt with the range from `min` to `max`, inclusive.
+    pub fn new(message: impl std::fmt::Display, min: isize, max: isize) -> Self {
+        Self {
+            formatter: Box::new(DefaultSliderFormatter),
+            message: message.to_string(),
+            hint: None,
+            description: None,
+            min,
+            max,
+            step: 1,
+            width: 20,
+            keybindings: KeyBindings::default(),
+            value: min,
+        }
+    }
+
+    /// Sets the formatter for the prompt.
+    pub fn with_formatter(&mut self, formatter: impl SliderFormatter + 'static) -> &mut Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Sets the hint message for the prompt.
+    pub fn with_hint(&mut self, hint: impl std::fmt::Display) -> &mut Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the bar.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Sets the amount the value moves per key press. Must be greater than `0`.
+    pub fn with_step(&mut self, step: isize) -> &mut Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the default value for the prompt. It is clamped to the range when the prompt starts.
+    pub fn with_default(&mut self, value: isize) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the number of cells in the bar.
+    pub fn with_width(&mut self, width: usize) -> &mut Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        self.value = self.value.saturating_add(delta).clamp(self.min, self.max);
+    }
+
+    fn filled(&self) -> usize {
+        let range = self.max as i128 - self.min as i128;
+        if range == 0 {
+            return self.width;
+        }
+        let offset = self.value as i128 - self.min as i128;
+        ((offset * self.width as i128 + range / 2) / range) as usize
+    }
+}
+
+impl AsMut<Slider> for Slider {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl Prompt for Slider {
+    type Output = isize;
+
+    fn name(&self) -> &'static str {
+        "Slider"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
+    fn setup(&mut self) -> Result<(), Error> {
+        if self.min > self.max {
+            return Err(Error::Config {
+                message: format!(
+                    "min cannot be greater than max (min={}, max={})",
+                    self.min, self.max
+                ),
+                field: Some("min".into()),
+                prompt: Some("Slider".into()),
+                source: None,
+            });
+        }
+
+        if self.step <= 0 {
+            return Err(Error::Config {
+                message: format!("step must be greater than 0 (step={})", self.step),
+                field: Some("step".into()),
+                prompt: Some("Slider".into()),
+                source: None,
+            });
+        }
+
+        self.value = self.value.clamp(self.min, self.max);
+
+        Ok(())
+    }
+
+    fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.submit.contains_key(code, modifiers) => PromptState::Submit,
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.move_left.contains_key(code, modifiers) => {
+                self.move_by(-self.step);
+                PromptState::Active
+            }
+            _ if kb.move_right.contains_key(code, modifiers) => {
+                self.move_by(self.step);
+                PromptState::Active
+            }
+            _ if kb.move_home.contains_key(code, modifiers) => {
+                self.value = self.min;
+                PromptState::Active
+            }
+            _ if kb.move_end.contains_key(code, modifiers) => {
+                self.value = self.max;
+                PromptState::Active
+            }
+            _ => PromptState::Active,
+        }
+    }
+
+    fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
+        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), None)
+            .description(self.description.clone());
+
+        match state {
+            PromptState::Submit => {
+                Ok(payload.input(PromptInput::Raw(self.formatter.value(self.value))))
+            }
+
+            _ => Ok(payload.body(PromptBody::Raw(self.formatter.layout(
+                self.formatter.bar(self.filled(), self.width),
+                self.formatter.value(self.value),
+            )))),
+        }
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prompt;
+
+    test_prompt!(
+        test_hint,
+        Slider::new("test message", 0, 100).with_hint("hint message"),
+        vec![]
+    );
+
+    test_prompt!(
+        test_move,
+        Slider::new("test message", 0, 100)
+            .with_step(10)
+            .with_default(40)
+            .with_width(10),
+        vec![
+            (KeyCode::Right, KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::End, KeyModifiers::NONE),
+            (KeyCode::Home, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_clamp() {
+        let mut prompt = Slider::new("test message", 1, 10);
+        prompt.with_step(4).with_default(3);
+        prompt.setup().unwrap();
+
+        prompt.handle(KeyCode::Left, KeyModifiers::NONE);
+        assert_eq!(prompt.submit(), 1);
+        prompt.handle(KeyCode::Left, KeyModifiers::NONE);
+        assert_eq!(prompt.submit(), 1);
+
+        prompt.handle(KeyCode::Right, KeyModifiers::NONE);
+        prompt.handle(KeyCode::Right, KeyModifiers::NONE);
+        assert_eq!(prompt.submit(), 9);
+        prompt.handle(KeyCode::Right, KeyModifiers::NONE);
+        assert_eq!(prompt.submit(), 10);
+    }
+
+    #[test]
+    fn test_clamp_default() {
+        let mut prompt = Slider::new("test message", 0, 10);
+        prompt.with_default(20);
+        prompt.setup().unwrap();
+        assert_eq!(prompt.submit(), 10);
+
+        let mut prompt = Slider::new("test message", isize::MIN, isize::MAX);
+        prompt.with_default(isize::MAX).with_step(isize::MAX);
+        prompt.setup().unwrap();
+        prompt.handle(KeyCode::Right, KeyModifiers::NONE);
+        assert_eq!(prompt.submit(), isize::MAX);
+        assert_eq!(prompt.filled(), 20);
+    }
+
+    #[test]
+    fn test_setup_error() {
+        assert!(Slider::new("test message", 10, 0).setup().is_err());
+        assert!(Slider::new("test message", 0, 10)
+            .with_step(0)
+            .setup()
+            .is_err());
+    }
+}
@@ -0,0 +1,299 @@
+use crate::event::*;
+use crate::pagination::paginate;
+use crate::style::*;
+use crate::{
+    Error, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptBody, PromptInput, PromptState,
+    RenderPayload,
+};
+
+use super::{DefaultSelectFormatter, SelectFormatter, SelectOption};
+
+const S_ICON: Symbol = Symbol("▶", ">");
+
+/// A prompt for reordering a list of options.
+///
+/// <kbd>Up</kbd> and <kbd>Down</kbd> move the cursor, and <kbd>Shift+Up</kbd> and <kbd>Shift+Down</kbd> move the option under the cursor. Submitting returns the values in the new order.
+///
+/// # Options
+///
+/// - **Formatter**: Customizes the display of the options. See [`SelectFormatter`].
+/// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the options. Defaults to `None`.
+/// - **Page Size**: The total number of options to displayed per page, used for pagination. Defaults to `8`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
+///
+/// # Notes
+///
+/// Passing an empty `options` will result in an error. The disabled and separator flags of the options are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{SelectOption, Sort};
+///
+/// let _ = Sort::new("Order by priority", vec![
+///     SelectOption::new("Performance", "perf"),
+///     SelectOption::new("Security", "security"),
+///     SelectOption::new("Usability", "usability"),
+/// ]);
+/// ```
+pub struct Sort<T: Default + Clone> {
+    formatter: Box<dyn SelectFormatter>,
+    message: String,
+    hint: Option<String>,
+    description: Option<String>,
+    page_size: usize,
+    page_size_explicit: bool,
+    options: Vec<SelectOption<T>>,
+    keybindings: KeyBindings,
+    index: usize,
+}
+
+impl<T: Default + Clone> Sort<T> {
+    /// Creates a new [`Sort`] prompt with the given message and options.
+    pub fn new(message: impl std::fmt::Display, options: Vec<SelectOption<T>>) -> Self {
+        Self {
+            formatter: Box::<DefaultSelectFormatter>::default(),
+            message: message.to_string(),
+            hint: None,
+            description: None,
+            page_size: 8,
+            page_size_explicit: false,
+            options,
+            keybindings: KeyBindings::default(),
+            index: 0,
+        }
+    }
+
+    /// Sets the formatter for the prompt.
+    pub fn with_formatter(&mut self, formatter: impl SelectFormatter + 'static) -> &mut Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Sets the hint message for the prompt.
+    pub fn with_hint(&mut self, hint: impl std::fmt::Display) -> &mut Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the options.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Sets the page size for the prompt.
+    pub fn with_page_size(&mut self, page_size: usize) -> &mut Self {
+        self.page_size = page_size;
+        self.page_size_explicit = true;
+        self
+    }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+
+    fn move_item_up(&mut self) {
+        if self.index > 0 {
+            self.options.swap(self.index, self.index - 1);
+            self.index -= 1;
+        }
+    }
+
+    fn move_item_down(&mut self) {
+        if self.index + 1 < self.options.len() {
+            self.options.swap(self.index, self.index + 1);
+            self.index += 1;
+        }
+    }
+}
+
+impl<T: Default + Clone> AsMut<Sort<T>> for Sort<T> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl<T: Default + Clone> Prompt for Sort<T> {
+    type Output = Vec<T>;
+
+    fn name(&self) -> &'static str {
+        "Sort"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
+    fn set_page_size(&mut self, page_size: usize) {
+        if !self.page_size_explicit {
+            self.page_size = page_size;
+        }
+    }
+
+    fn setup(&mut self) -> Result<(), Error> {
+        if self.options.is_empty() {
+            return Err(Error::Config {
+                message: "options cannot be empty.".into(),
+                field: Some("options".into()),
+                prompt: Some("Sort".into()),
+                source: None,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.submit.contains_key(code, modifiers) => PromptState::Submit,
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.move_item_up.contains_key(code, modifiers) => {
+                self.move_item_up();
+                PromptState::Active
+            }
+            _ if kb.move_item_down.contains_key(code, modifiers) => {
+                self.move_item_down();
+                PromptState::Active
+            }
+            _ if kb.move_up.contains_key(code, modifiers) => {
+                self.index = self.index.saturating_sub(1);
+                PromptState::Active
+            }
+            _ if kb.move_down.contains_key(code, modifiers) => {
+                self.index = (self.index + 1).min(self.options.len() - 1);
+                PromptState::Active
+            }
+            _ if kb.move_home.contains_key(code, modifiers) => {
+                self.index = 0;
+                PromptState::Active
+            }
+            _ if kb.move_end.contains_key(code, modifiers) => {
+                self.index = self.options.len() - 1;
+                PromptState::Active
+            }
+            _ => PromptState::Active,
+        }
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        self.options
+            .iter()
+            .map(|option| option.value.clone())
+            .collect()
+    }
+
+    fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
+        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), None)
+            .description(self.description.clone());
+
+        match state {
+            PromptState::Submit => {
+                let labels = self
+                    .options
+                    .iter()
+                    .map(|option| option.label.clone())
+                    .collect::<Vec<_>>();
+                Ok(payload.input(PromptInput::Raw(labels.join(", "))))
+            }
+
+            _ => {
+                let page = paginate(self.page_size, &self.options, self.index);
+                let options = page
+                    .items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, option)| {
+                        let active = i == page.cursor;
+                        self.formatter.option(
+                            self.formatter.option_icon(active),
+                            self.formatter
+                                .option_label(option.label.clone(), active, false),
+                            self.formatter.option_hint(option.hint.clone(), active),
+                            active,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                Ok(payload.body(PromptBody::Raw(options)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prompt;
+
+    fn options() -> Vec<SelectOption<String>> {
+        ["a", "b", "c", "d"]
+            .iter()
+            .map(|v| SelectOption::new(v.to_uppercase(), v.to_string()))
+            .collect()
+    }
+
+    test_prompt!(
+        test_hint,
+        Sort::new("test message", options()).with_hint("hint message"),
+        vec![]
+    );
+
+    test_prompt!(
+        test_move_item,
+        Sort::new("test message", options()).as_mut(),
+        vec![
+            (KeyCode::Down, KeyModifiers::SHIFT),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::SHIFT),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_page_size,
+        Sort::new("test message", options()).with_page_size(2),
+        vec![
+            (KeyCode::Down, KeyModifiers::SHIFT),
+            (KeyCode::Down, KeyModifiers::SHIFT),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_submit_order() {
+        let mut prompt = Sort::new("test message", options());
+        prompt.setup().unwrap();
+
+        for (code, modifiers) in [
+            (KeyCode::Down, KeyModifiers::SHIFT),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::SHIFT),
+        ] {
+            prompt.handle(code, modifiers);
+        }
+        assert_eq!(prompt.submit(), vec!["b", "a", "d", "c"]);
+
+        for (code, modifiers) in [
+            (KeyCode::Home, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::SHIFT),
+            (KeyCode::End, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::SHIFT),
+        ] {
+            prompt.handle(code, modifiers);
+        }
+        assert_eq!(prompt.submit(), vec!["b", "a", "d", "c"]);
+    }
+}
@@ -0,0 +1,263 @@
+use crate::style::{Color, Styled, Symbol};
+use crate::{Error, Terminal, Theme};
+
+const FRAMES: [Symbol; 10] = [
+    Symbol("⠋", "|"),
+    Symbol("⠙", "/"),
+    Symbol("⠹", "-"),
+    Symbol("⠸", "\\"),
+    Symbol("⠼", "|"),
+    Symbol("⠴", "/"),
+    Symbol("⠦", "-"),
+    Symbol("⠧", "\\"),
+    Symbol("⠇", "|"),
+    Symbol("⠏", "/"),
+];
+const S_SUCCESS: Symbol = Symbol("✔", "√");
+const S_FAIL: Symbol = Symbol("✘", "x");
+
+/// A spinner for indicating the progress of long-running operations.
+///
+/// Unlike the other built-in prompts, [`Spinner`] does not implement the [`crate::Prompt`] trait and does not read any keys.
+/// It is a standalone utility that draws on a [`Terminal`], so it does not depend on threads: call [`Spinner::tick`] periodically while the work is in progress to advance the animation.
+///
+/// # Usage with Promptuity
+///
+/// Spinners render on a single line and are meant to be used **between** prompts, never while a prompt is running.
+/// [`crate::Promptuity::begin`] enables raw mode, so the spinner always returns the cursor to the first column itself and does not rely on the terminal translating newlines. Use the terminal owned by the session via [`crate::Promptuity::term`] so that the output goes to the same writer as the prompts.
+/// Always call [`Spinner::finish_with_message`] or [`Spinner::fail`] before running the next prompt; they clear the spinner line and show the cursor again.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{Input, Spinner};
+/// use promptuity::themes::FancyTheme;
+/// use promptuity::{Promptuity, Term};
+///
+/// # fn main() -> Result<(), promptuity::Error> {
+/// let mut term = Term::default();
+/// let mut theme = FancyTheme::default();
+/// let mut p = Promptuity::new(&mut term, &mut theme);
+///
+/// p.begin()?;
+///
+/// let name = p.prompt(Input::new("Please enter a package name").as_mut())?;
+///
+/// let mut spinner = Spinner::new(format!("Installing {name}..."));
+/// spinner.start(p.term())?;
+/// for _ in 0..20 {
+///     std::thread::sleep(std::time::Duration::from_millis(80));
+///     spinner.tick(p.term())?;
+/// }
+/// spinner.finish_with_message(p.term(), "Installed")?;
+///
+/// p.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Spinner {
+    message: String,
+    frame: usize,
+}
+
+impl Spinner {
+    /// Creates a new [`Spinner`] with the given message.
+    pub fn new(message: impl std::fmt::Display) -> Self {
+        Self {
+            message: message.to_string(),
+            frame: 0,
+        }
+    }
+
+    /// Sets the message displayed next to the spinner.  
+    /// The new message is displayed from the next [`Spinner::tick`].
+    pub fn set_message(&mut self, message: impl std::fmt::Display) -> &mut Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// Hides the cursor and renders the first frame.
+    pub fn start<W: std::io::Write>(&mut self, term: &mut dyn Terminal<W>) -> Result<(), Error> {
+        self.frame = 0;
+        term.cursor_hide()?;
+        self.render(term)
+    }
+
+    /// Advances the spinner to the next frame and renders it.
+    pub fn tick<W: std::io::Write>(&mut self, term: &mut dyn Terminal<W>) -> Result<(), Error> {
+        self.frame = (self.frame + 1) % FRAMES.len();
+        self.render(term)
+    }
+
+    /// Clears the spinner and prints a completion message.
+    pub fn finish_with_message<W: std::io::Write>(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        message: impl std::fmt::Display,
+    ) -> Result<(), Error> {
+        self.finalize(
+            term,
+            Styled::new(S_SUCCESS).fg(Color::Green).to_string(),
+            message,
+        )
+    }
+
+    /// Clears the spinner and prints a failure message.
+    pub fn fail<W: std::io::Write>(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        message: impl std::fmt::Display,
+    ) -> Result<(), Error> {
+        self.finalize(
+            term,
+            Styled::new(S_FAIL).fg(Color::Red).to_string(),
+            message,
+        )
+    }
+
+    fn render<W: std::io::Write>(&self, term: &mut dyn Terminal<W>) -> Result<(), Error> {
+        term.move_column(0)?;
+        term.clear_current_line()?;
+        term.write(&format!(
+            "{}  {}",
+            Styled::new(FRAMES[self.frame]).fg(Color::Cyan),
+            self.message
+        ))?;
+        term.flush()?;
+        Ok(())
+    }
+
+    fn finalize<W: std::io::Write>(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        icon: String,
+        message: impl std::fmt::Display,
+    ) -> Result<(), Error> {
+        term.move_column(0)?;
+        term.clear_current_line()?;
+        term.writeln(&format!("{}  {}", icon, message))?;
+        term.cursor_show()?;
+        term.flush()?;
+        Ok(())
+    }
+}
+
+/// A spinner rendered by the theme of a session, created with [`crate::Promptuity::spinner`].
+///
+/// Each frame is drawn with [`crate::Theme::frame`], so the spinner matches the style of the prompts. Call [`SpinnerHandle::tick`] periodically while the work is in progress, then [`SpinnerHandle::finish`] or [`SpinnerHandle::fail`] to replace it with a step or an error message.  
+/// If the handle is dropped without finishing, the spinner line is cleared.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::themes::FancyTheme;
+/// use promptuity::{Promptuity, Term};
+///
+/// # fn main() -> Result<(), promptuity::Error> {
+/// let mut term = Term::default();
+/// let mut theme = FancyTheme::default();
+/// let mut p = Promptuity::new(&mut term, &mut theme);
+///
+/// p.begin()?;
+///
+/// let mut spinner = p.spinner("Installing...")?;
+/// for i in 0..20 {
+///     std::thread::sleep(std::time::Duration::from_millis(80));
+///     if i == 10 {
+///         spinner.set_message("Linking...")?;
+///     }
+///     spinner.tick()?;
+/// }
+/// spinner.finish("Installed")?;
+///
+/// p.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct SpinnerHandle<'a, W: std::io::Write> {
+    term: &'a mut dyn Terminal<W>,
+    theme: &'a mut dyn Theme<W>,
+    message: String,
+    frame: usize,
+    animate: bool,
+    finished: bool,
+}
+
+impl<'a, W: std::io::Write> SpinnerHandle<'a, W> {
+    pub(crate) fn new(
+        term: &'a mut dyn Terminal<W>,
+        theme: &'a mut dyn Theme<W>,
+        message: String,
+        animate: bool,
+    ) -> Result<Self, Error> {
+        let mut spinner = Self {
+            term,
+            theme,
+            message,
+            frame: 0,
+            animate,
+            finished: false,
+        };
+        if animate {
+            spinner.term.cursor_hide()?;
+        }
+        spinner.render()?;
+        Ok(spinner)
+    }
+
+    /// Advances the spinner to the next frame and renders it.
+    pub fn tick(&mut self) -> Result<(), Error> {
+        self.frame = (self.frame + 1) % FRAMES.len();
+        self.render()
+    }
+
+    /// Sets the message displayed next to the spinner and renders it.
+    pub fn set_message(&mut self, message: impl std::fmt::Display) -> Result<(), Error> {
+        self.message = message.to_string();
+        self.render()
+    }
+
+    /// Clears the spinner and displays the message with [`crate::Theme::step`].
+    pub fn finish(mut self, message: impl std::fmt::Display) -> Result<(), Error> {
+        self.clear()?;
+        self.theme.step(self.term, message.to_string())
+    }
+
+    /// Clears the spinner and displays the message with [`crate::Theme::error`].
+    pub fn fail(mut self, message: impl std::fmt::Display) -> Result<(), Error> {
+        self.clear()?;
+        self.theme.error(self.term, message.to_string())
+    }
+
+    fn render(&mut self) -> Result<(), Error> {
+        if !self.animate {
+            return Ok(());
+        }
+        self.term.move_column(0)?;
+        self.term.clear_current_line()?;
+        self.theme.frame(
+            self.term,
+            FRAMES[self.frame].to_string(),
+            self.message.clone(),
+        )
+    }
+
+    fn clear(&mut self) -> Result<(), Error> {
+        self.finished = true;
+        if self.animate {
+            self.term.move_column(0)?;
+            self.term.clear_current_line()?;
+            self.term.cursor_show()?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, W: std::io::Write> Drop for SpinnerHandle<'a, W> {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = self.clear();
+            let _ = self.term.flush();
+        }
+    }
+}
@@ -13,6 +13,10 @@ pub fn render_payload_display(payload: RenderPayload, full: bool) -> String {
         ));
     }
 
+    if let Some(description) = payload.description {
+        segments.push(format!("description:\n{}", description));
+    }
+
     segments.push(match payload.input {
         PromptInput::Raw(raw) => format!("input(raw): {}", raw),
         PromptInput::Cursor(cursor) => {
@@ -22,8 +26,17 @@ pub fn render_payload_display(payload: RenderPayload, full: bool) -> String {
         PromptInput::None => "input(none):".into(),
     });
 
+    if let Some(validation_hint) = payload.validation_hint {
+        segments.push(format!("validation_hint: {}", validation_hint));
+    }
+
     segments.push(match payload.body {
         PromptBody::Raw(raw) => format!("body(raw):\n{}", raw),
+        PromptBody::Scrollable { scroll_offset, .. } => format!(
+            "body(scrollable:{}):\n{}",
+            scroll_offset,
+            payload.body.visible_content().unwrap_or_default()
+        ),
         PromptBody::None => "body(none):".into(),
     });
 
@@ -39,28 +52,47 @@ pub fn render_display(state: PromptState, prompt: &mut impl Prompt, full: bool)
 }
 
 pub fn handle_actions(prompt: &mut impl Prompt, actions: Vec<(KeyCode, KeyModifiers)>) -> String {
+    handle_actions_with(prompt, actions, false)
+}
+
+pub fn handle_actions_with(
+    prompt: &mut impl Prompt,
+    actions: Vec<(KeyCode, KeyModifiers)>,
+    full: bool,
+) -> String {
+    crate::style::Styled::set_color_override(Some(true));
+    Prompt::setup(prompt).unwrap();
+
     let mut output = Vec::new();
 
     output.push(render_display(PromptState::Active, prompt, true));
 
+    let mut prev = PromptState::Active;
     for (code, modifiers) in actions {
         let state = Prompt::handle(prompt, code, modifiers);
         let state = match state {
             PromptState::Submit => {
-                if let Err(msg) = Prompt::validate(prompt) {
-                    PromptState::Error(msg)
+                if let Err(err) = Prompt::validate(prompt) {
+                    err.into()
                 } else {
-                    PromptState::Submit
+                    match (Prompt::warn(prompt), &prev) {
+                        (Some(msg), state) if !matches!(state, PromptState::Warning(_)) => {
+                            PromptState::Warning(msg)
+                        }
+                        _ => PromptState::Submit,
+                    }
                 }
             }
             state => state,
         };
-        output.push(render_display(state, prompt, false));
+        prev = state.clone();
+        output.push(render_display(state, prompt, full));
     }
 
     output.join("\n---\n")
 }
 
+/// Defines a snapshot test that renders the prompt after each action.
 #[allow(clippy::crate_in_macro_def)]
 #[macro_export]
 macro_rules! test_prompt {
@@ -73,4 +105,42 @@ macro_rules! test_prompt {
             });
         }
     };
+    ($name: ident, $prompt: expr, $actions: expr, full) => {
+        #[test]
+        fn $name() {
+            let output = crate::prompts::test::handle_actions_with($prompt, $actions, true);
+            insta::with_settings!({ omit_expression => true }, {
+                insta::assert_snapshot!(output);
+            });
+        }
+    };
+}
+
+/// Asserts the state returned by the prompt for each action.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! assert_prompt_output {
+    ($prompt: expr, $actions: expr, $expected: expr) => {
+        match $prompt {
+            prompt => {
+                let actions: Vec<(KeyCode, KeyModifiers)> = $actions;
+                let expected: Vec<crate::PromptState> = $expected;
+                assert_eq!(
+                    actions.len(),
+                    expected.len(),
+                    "the number of actions and expected states must match"
+                );
+                for (i, ((code, modifiers), expected)) in
+                    actions.into_iter().zip(expected).enumerate()
+                {
+                    let state = crate::Prompt::handle(prompt, code, modifiers);
+                    assert_eq!(
+                        state, expected,
+                        "unexpected state at action #{} ({:?}, {:?})",
+                        i, code, modifiers
+                    );
+                }
+            }
+        }
+    };
 }
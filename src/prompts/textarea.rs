@@ -0,0 +1,445 @@
+use crate::event::*;
+use crate::style::{Styled, Symbol};
+use crate::{
+    DynValidator, InputCursor, KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptBody,
+    PromptState, RenderPayload, ValidationError, Validator,
+};
+
+const S_ICON: Symbol = Symbol("?", "?");
+
+/// A trait for formatting the [`Textarea`] prompt.
+///
+/// All methods have default implementations, allowing you to override only the specific formatting process you need.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{Textarea, TextareaFormatter};
+///
+/// struct CustomFormatter;
+///
+/// impl TextareaFormatter for CustomFormatter {
+///     fn submit_hint(&self) -> Option<String> {
+///         Some("Alt+Enter to save".into())
+///     }
+/// }
+///
+/// let _ = Textarea::new("...").with_formatter(CustomFormatter);
+/// ```
+pub trait TextareaFormatter {
+    /// Formats the error message when the input is empty and required.
+    fn err_required(&self) -> String {
+        "This field is required.".into()
+    }
+
+    /// Formats the hint describing how to submit, displayed when no hint is set.
+    fn submit_hint(&self) -> Option<String> {
+        Some("Ctrl+D to submit".into())
+    }
+
+    /// Formats the line containing the cursor.
+    fn cursor_line(&self, left: String, cursor: String, right: String) -> String {
+        format!("{}{}{}", left, Styled::new(cursor).rev(), right)
+    }
+}
+
+/// The default formatter for [`Textarea`].
+#[derive(Default)]
+pub struct DefaultTextareaFormatter;
+
+impl DefaultTextareaFormatter {
+    /// Creates a new [`DefaultTextareaFormatter`].
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl TextareaFormatter for DefaultTextareaFormatter {}
+
+/// A prompt for multi-line text input.
+///
+/// <kbd>Enter</kbd> inserts a new line. The input is submitted with <kbd>Ctrl</kbd> + <kbd>D</kbd> or <kbd>Alt</kbd> + <kbd>Enter</kbd>, and the lines are joined with `\n`.  
+/// The buffer is displayed in the body of the prompt.
+///
+/// # Options
+///
+/// - **Formatter**: Customizes the prompt display. See [`TextareaFormatter`].
+/// - **Hint**: A message to assist with field input. Defaults to [`TextareaFormatter::submit_hint`].
+/// - **Description**: A multi-line help text displayed before the input. Defaults to `None`.
+/// - **Required**: A flag indicating whether to allow no input.
+/// - **Default Value**: The default value of `String`.
+/// - **Validator**: A function to validate the value at the time of submission.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::Textarea;
+///
+/// let _ = Textarea::new("Please enter a commit message").with_required(false);
+/// ```
+pub struct Textarea {
+    formatter: Box<dyn TextareaFormatter>,
+    message: String,
+    hint: Option<String>,
+    description: Option<String>,
+    required: bool,
+    validator: Option<DynValidator<String>>,
+    lines: Vec<InputCursor>,
+    row: usize,
+    cancel_keys: Vec<KeyBinding>,
+}
+
+impl Textarea {
+    /// Creates a new [`Textarea`] prompt.
+    pub fn new(message: impl std::fmt::Display) -> Self {
+        Self {
+            formatter: Box::<DefaultTextareaFormatter>::default(),
+            message: message.to_string(),
+            hint: None,
+            description: None,
+            required: true,
+            validator: None,
+            lines: vec![InputCursor::default()],
+            row: 0,
+            cancel_keys: KeyBindings::default().cancel,
+        }
+    }
+
+    /// Sets the formatter for the prompt.
+    pub fn with_formatter(&mut self, formatter: impl TextareaFormatter + 'static) -> &mut Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Sets the hint message for the prompt.
+    pub fn with_hint(&mut self, hint: impl std::fmt::Display) -> &mut Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the input.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Sets the required flag for the prompt.
+    pub fn with_required(&mut self, required: bool) -> &mut Self {
+        self.required = required;
+        self
+    }
+
+    /// Sets the default value for the prompt.  
+    /// The cursor is placed at the end of the last line.
+    pub fn with_default(&mut self, value: impl std::fmt::Display) -> &mut Self {
+        self.lines = value
+            .to_string()
+            .split('\n')
+            .map(|line| InputCursor::from(line.into()))
+            .collect();
+        self.row = self.lines.len() - 1;
+        self
+    }
+
+    /// Sets the validator for the prompt.
+    pub fn with_validator(&mut self, f: impl Validator<String> + 'static) -> &mut Self {
+        self.validator = Some(Box::new(f));
+        self
+    }
+}
+
+impl Textarea {
+    fn value(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.value())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lines.iter().all(|line| line.is_empty())
+    }
+
+    fn current(&mut self) -> &mut InputCursor {
+        &mut self.lines[self.row]
+    }
+
+    fn line_len(&self, row: usize) -> usize {
+        self.lines[row].value().chars().count()
+    }
+
+    fn move_row(&mut self, row: usize) {
+        let col = self.lines[self.row].cursor().min(self.line_len(row));
+        self.row = row;
+        self.current().set_cursor(col);
+    }
+
+    fn move_left(&mut self) {
+        if self.lines[self.row].cursor() > 0 {
+            self.current().move_left();
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.current().move_end();
+        }
+    }
+
+    fn move_right(&mut self) {
+        if self.lines[self.row].cursor() < self.line_len(self.row) {
+            self.current().move_right();
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.current().move_home();
+        }
+    }
+
+    fn insert_newline(&mut self) {
+        let (left, cursor, right) = self.lines[self.row].split();
+        let rest = if self.lines[self.row].cursor() < self.line_len(self.row) {
+            format!("{}{}", cursor, right)
+        } else {
+            String::new()
+        };
+        self.lines[self.row] = InputCursor::from(left);
+        self.lines.insert(self.row + 1, InputCursor::new(rest, 0));
+        self.row += 1;
+    }
+
+    fn delete_left_char(&mut self) {
+        if self.lines[self.row].cursor() > 0 {
+            self.current().delete_left_char();
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            let col = self.line_len(self.row);
+            let value = format!("{}{}", self.lines[self.row].value(), line.value());
+            self.lines[self.row] = InputCursor::new(value, col);
+        }
+    }
+
+    fn delete_right_char(&mut self) {
+        if self.lines[self.row].cursor() < self.line_len(self.row) {
+            self.current().delete_right_char();
+        } else if self.row + 1 < self.lines.len() {
+            let line = self.lines.remove(self.row + 1);
+            let col = self.lines[self.row].cursor();
+            let value = format!("{}{}", self.lines[self.row].value(), line.value());
+            self.lines[self.row] = InputCursor::new(value, col);
+        }
+    }
+
+    fn render_lines(&self) -> String {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                if i == self.row {
+                    let (left, cursor, right) = line.split();
+                    self.formatter.cursor_line(left, cursor, right)
+                } else {
+                    line.value()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl AsMut<Textarea> for Textarea {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl Prompt for Textarea {
+    type Output = String;
+
+    fn name(&self) -> &'static str {
+        "Textarea"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.cancel_keys = keys.to_vec();
+    }
+
+    fn setup(&mut self) -> Result<(), crate::Error> {
+        self.validator
+            .as_mut()
+            .map_or(Ok(()), |validator| validator.prepare())
+    }
+
+    fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
+        match (code, modifiers) {
+            _ if self.cancel_keys.contains_key(code, modifiers) => PromptState::Cancel,
+            (KeyCode::Char('d'), KeyModifiers::CONTROL) | (KeyCode::Enter, KeyModifiers::ALT) => {
+                if self.is_empty() && self.required {
+                    PromptState::Error(self.formatter.err_required())
+                } else {
+                    PromptState::Submit
+                }
+            }
+            (KeyCode::Enter, _) => {
+                self.insert_newline();
+                PromptState::Active
+            }
+            (KeyCode::Up, _) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                if self.row > 0 {
+                    self.move_row(self.row - 1);
+                }
+                PromptState::Active
+            }
+            (KeyCode::Down, _) | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                if self.row + 1 < self.lines.len() {
+                    self.move_row(self.row + 1);
+                }
+                PromptState::Active
+            }
+            (KeyCode::Left, _) | (KeyCode::Char('b'), KeyModifiers::CONTROL) => {
+                self.move_left();
+                PromptState::Active
+            }
+            (KeyCode::Right, _) | (KeyCode::Char('f'), KeyModifiers::CONTROL) => {
+                self.move_right();
+                PromptState::Active
+            }
+            (KeyCode::Home, _) | (KeyCode::Char('a'), KeyModifiers::CONTROL) => {
+                self.current().move_home();
+                PromptState::Active
+            }
+            (KeyCode::End, _) | (KeyCode::Char('e'), KeyModifiers::CONTROL) => {
+                self.current().move_end();
+                PromptState::Active
+            }
+            (KeyCode::Backspace, _) | (KeyCode::Char('h'), KeyModifiers::CONTROL) => {
+                self.delete_left_char();
+                PromptState::Active
+            }
+            (KeyCode::Delete, _) => {
+                self.delete_right_char();
+                PromptState::Active
+            }
+            (KeyCode::Char('w'), KeyModifiers::CONTROL) => {
+                self.current().delete_left_word();
+                PromptState::Active
+            }
+            (KeyCode::Char('k'), KeyModifiers::CONTROL) => {
+                self.current().delete_rest_line();
+                PromptState::Active
+            }
+            (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
+                self.current().delete_line();
+                PromptState::Active
+            }
+            (KeyCode::Char(c), _) => {
+                self.current().insert(c);
+                PromptState::Active
+            }
+            _ => PromptState::Active,
+        }
+    }
+
+    fn handle_paste(&mut self, text: String) -> PromptState {
+        let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+        for (i, line) in normalized.split('\n').enumerate() {
+            if i > 0 {
+                self.insert_newline();
+            }
+            let line = line.chars().filter(|c| !c.is_control()).collect::<String>();
+            self.current().insert_str(&line);
+        }
+        PromptState::Active
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        self.value()
+    }
+
+    fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
+        let hint = self.hint.clone().or_else(|| self.formatter.submit_hint());
+        let payload = RenderPayload::new(self.message.clone(), hint, None)
+            .description(self.description.clone());
+
+        match state {
+            PromptState::Submit => Ok(payload.body(PromptBody::Raw(self.value()))),
+            _ => Ok(payload.body(PromptBody::Raw(self.render_lines()))),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.validator
+            .as_ref()
+            .map_or(Ok(()), |validator| validator.validate(&self.value()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prompt;
+
+    test_prompt!(
+        test_default,
+        Textarea::new("test message").as_mut(),
+        vec![
+            (KeyCode::Char('a'), KeyModifiers::NONE),
+            (KeyCode::Char('b'), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Char('c'), KeyModifiers::NONE),
+            (KeyCode::Char('d'), KeyModifiers::CONTROL),
+        ]
+    );
+
+    test_prompt!(
+        test_required_error,
+        Textarea::new("test message").as_mut(),
+        vec![
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::ALT),
+        ]
+    );
+
+    test_prompt!(
+        test_move_lines,
+        Textarea::new("test message").with_default("abcdef\nab\nabcd"),
+        vec![
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Down, KeyModifiers::NONE),
+            (KeyCode::Home, KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Right, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_editing,
+        Textarea::new("test message").with_default("abc\ndef"),
+        vec![
+            (KeyCode::Home, KeyModifiers::NONE),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+            (KeyCode::Up, KeyModifiers::NONE),
+            (KeyCode::End, KeyModifiers::NONE),
+            (KeyCode::Delete, KeyModifiers::NONE),
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    #[test]
+    fn test_submit_value() {
+        let mut prompt = Textarea::new("test message");
+        prompt.with_default("line1\n\nline3");
+        assert_eq!(
+            prompt.handle(KeyCode::Enter, KeyModifiers::ALT),
+            PromptState::Submit
+        );
+        assert_eq!(prompt.submit(), "line1\n\nline3");
+    }
+}
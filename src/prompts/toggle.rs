@@ -0,0 +1,277 @@
+use crate::event::*;
+use crate::style::{Color, Styled, Symbol};
+use crate::{
+    KeyBinding, KeyBindingList, KeyBindings, Prompt, PromptInput, PromptState, RenderPayload,
+};
+
+const S_ACTIVE: Symbol = Symbol("●", ">");
+const S_INACTIVE: Symbol = Symbol("○", " ");
+const S_ICON: Symbol = Symbol("●", "o");
+
+/// A trait for formatting the [`Toggle`] prompt.
+///
+/// All methods have default implementations, allowing you to override only the specific formatting process you need.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::{Toggle, ToggleFormatter};
+///
+/// struct CustomFormatter;
+///
+/// impl ToggleFormatter for CustomFormatter {
+///     fn layout(&self, on: String, off: String) -> String {
+///         format!("[{} | {}]", on, off)
+///     }
+/// }
+///
+/// let _ = Toggle::new("...", "On", "Off").with_formatter(CustomFormatter);
+/// ```
+pub trait ToggleFormatter {
+    /// Formats a label. `active` is `true` when the label is the chosen one.
+    fn label(&self, label: &str, active: bool) -> String {
+        if active {
+            format!(
+                "{} {}",
+                Styled::new(S_ACTIVE).fg(Color::Green),
+                Styled::new(label).bold()
+            )
+        } else {
+            format!(
+                "{} {}",
+                Styled::new(S_INACTIVE).fg(Color::DarkGrey),
+                Styled::new(label).fg(Color::DarkGrey)
+            )
+        }
+    }
+
+    /// Formats the layout of the active prompt.
+    fn layout(&self, on: String, off: String) -> String {
+        format!("{}  /  {}", on, off)
+    }
+
+    /// Formats the submitted label.
+    fn submit(&self, label: &str) -> String {
+        label.into()
+    }
+}
+
+/// The default formatter for [`Toggle`].
+#[derive(Default)]
+pub struct DefaultToggleFormatter;
+
+impl DefaultToggleFormatter {
+    /// Creates a new [`DefaultToggleFormatter`].
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ToggleFormatter for DefaultToggleFormatter {}
+
+/// A prompt for switching between two inline labels.
+///
+/// The first label corresponds to `true` and the second to `false`. <kbd>Left</kbd> and <kbd>Right</kbd> choose a label, and <kbd>Space</kbd> switches between them.
+///
+/// # Options
+///
+/// - **Formatter**: Customizes the prompt display. See [`ToggleFormatter`].
+/// - **Hint**: A message to assist with field input. Defaults to `None`.
+/// - **Description**: A multi-line help text displayed before the input. Defaults to `None`.
+/// - **Default Value**: The default value of `bool`. Defaults to `false`.
+/// - **Key Bindings**: The keys mapped to each action. See [`KeyBindings`]. Defaults to [`KeyBindings::default`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::prompts::Toggle;
+///
+/// let _ = Toggle::new("Theme", "Light", "Dark").with_default(true);
+/// ```
+pub struct Toggle {
+    formatter: Box<dyn ToggleFormatter>,
+    message: String,
+    on: String,
+    off: String,
+    hint: Option<String>,
+    description: Option<String>,
+    keybindings: KeyBindings,
+    value: bool,
+}
+
+impl Toggle {
+    /// Creates a new [`Toggle`] prompt with the labels for `true` and `false`.
+    pub fn new(
+        message: impl std::fmt::Display,
+        on: impl std::fmt::Display,
+        off: impl std::fmt::Display,
+    ) -> Self {
+        Self {
+            formatter: Box::new(DefaultToggleFormatter),
+            message: message.to_string(),
+            on: on.to_string(),
+            off: off.to_string(),
+            hint: None,
+            description: None,
+            keybindings: KeyBindings::default(),
+            value: false,
+        }
+    }
+
+    /// Sets the formatter for the prompt.
+    pub fn with_formatter(&mut self, formatter: impl ToggleFormatter + 'static) -> &mut Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Sets the hint message for the prompt.
+    pub fn with_hint(&mut self, hint: impl std::fmt::Display) -> &mut Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    /// Sets the description for the prompt.  
+    /// It may span multiple lines, and is displayed before the input.
+    pub fn with_description(&mut self, description: impl std::fmt::Display) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Sets the default value for the prompt.
+    pub fn with_default(&mut self, value: bool) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the key bindings for the prompt.
+    pub fn with_keybindings(&mut self, keybindings: KeyBindings) -> &mut Self {
+        self.keybindings = keybindings;
+        self
+    }
+}
+
+impl AsMut<Toggle> for Toggle {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl Prompt for Toggle {
+    type Output = bool;
+
+    fn name(&self) -> &'static str {
+        "Toggle"
+    }
+
+    fn icon(&self) -> Option<String> {
+        Some(S_ICON.to_string())
+    }
+
+    fn set_cancel_keys(&mut self, keys: &[KeyBinding]) {
+        self.keybindings.cancel = keys.to_vec();
+    }
+
+    fn handle(&mut self, code: KeyCode, modifiers: KeyModifiers) -> PromptState {
+        let kb = &self.keybindings;
+        match code {
+            _ if kb.submit.contains_key(code, modifiers) => PromptState::Submit,
+            _ if kb.cancel.contains_key(code, modifiers) => PromptState::Cancel,
+            _ if kb.toggle.contains_key(code, modifiers) => {
+                self.value = !self.value;
+                PromptState::Active
+            }
+            _ if kb.move_left.contains_key(code, modifiers) => {
+                self.value = true;
+                PromptState::Active
+            }
+            _ if kb.move_right.contains_key(code, modifiers) => {
+                self.value = false;
+                PromptState::Active
+            }
+            _ => PromptState::Active,
+        }
+    }
+
+    fn render(&mut self, state: &PromptState) -> Result<RenderPayload, String> {
+        let payload = RenderPayload::new(self.message.clone(), self.hint.clone(), None)
+            .description(self.description.clone());
+
+        match state {
+            PromptState::Submit => {
+                let label = if self.value { &self.on } else { &self.off };
+                Ok(payload.input(PromptInput::Raw(self.formatter.submit(label))))
+            }
+
+            _ => Ok(payload.input(PromptInput::Raw(self.formatter.layout(
+                self.formatter.label(&self.on, self.value),
+                self.formatter.label(&self.off, !self.value),
+            )))),
+        }
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_prompt_output, test_prompt};
+
+    test_prompt!(
+        test_hint,
+        Toggle::new("test message", "Light", "Dark").with_hint("hint message"),
+        vec![]
+    );
+
+    test_prompt!(
+        test_default_enter,
+        Toggle::new("test message", "Light", "Dark").as_mut(),
+        vec![(KeyCode::Enter, KeyModifiers::NONE)]
+    );
+
+    test_prompt!(
+        test_default_on,
+        Toggle::new("test message", "Light", "Dark").with_default(true),
+        vec![(KeyCode::Enter, KeyModifiers::NONE)]
+    );
+
+    test_prompt!(
+        test_move,
+        Toggle::new("test message", "Light", "Dark").as_mut(),
+        vec![
+            (KeyCode::Left, KeyModifiers::NONE),
+            (KeyCode::Right, KeyModifiers::NONE),
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::Enter, KeyModifiers::NONE),
+        ]
+    );
+
+    test_prompt!(
+        test_cancel,
+        Toggle::new("test message", "Light", "Dark").as_mut(),
+        vec![(KeyCode::Esc, KeyModifiers::NONE)]
+    );
+
+    #[test]
+    fn test_submit_value() {
+        let mut prompt = Toggle::new("test message", "Light", "Dark");
+        assert_prompt_output!(
+            prompt.as_mut(),
+            vec![
+                (KeyCode::Char(' '), KeyModifiers::NONE),
+                (KeyCode::Char(' '), KeyModifiers::NONE),
+                (KeyCode::Char(' '), KeyModifiers::NONE),
+                (KeyCode::Enter, KeyModifiers::NONE),
+            ],
+            vec![
+                PromptState::Active,
+                PromptState::Active,
+                PromptState::Active,
+                PromptState::Submit,
+            ]
+        );
+        assert!(prompt.submit());
+    }
+}
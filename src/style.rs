@@ -11,12 +11,15 @@
 //!
 //! ```
 //! use promptuity::style::{Color, Styled};
+//! # Styled::set_color_override(Some(true));
 //!
 //! let styled_text = Styled::new("Colored text").fg(Color::Green).bold().to_string();
 //!
 //! assert_eq!(styled_text, "\u{1b}[38;5;10m\u{1b}[1mColored text\u{1b}[0m");
 //! ```
 //!
+//! Styling is omitted when the [`NO_COLOR`](https://no-color.org/) environment variable is set to a non-empty value. Use [`Styled::set_color_override`] to force it on or off.
+//!
 //! This is an example of changing the displayed text based on whether Unicode is supported or not.  
 //! Useful for representing icons and other symbols.
 //!
@@ -31,9 +34,16 @@
 
 /// [`Color`] re-exports from [`crossterm::style::Color`].
 pub use crossterm::style::Color;
-use crossterm::style::{Attribute, Attributes, ContentStyle, Stylize};
+use crossterm::style::{Attribute, Attributes, Colored, ContentStyle, Stylize};
+use std::sync::atomic::{AtomicU8, Ordering};
 use unicode_width::UnicodeWidthChar;
 
+const COLOR_AUTO: u8 = 0;
+const COLOR_ALWAYS: u8 = 1;
+const COLOR_NEVER: u8 = 2;
+
+static COLOR_OVERRIDE: AtomicU8 = AtomicU8::new(COLOR_AUTO);
+
 /// A styling utility for strings wrapped in [`crossterm::style::ContentStyle`].
 #[derive(Debug)]
 pub struct Styled {
@@ -44,6 +54,7 @@ pub struct Styled {
 }
 
 impl Styled {
+    /// Creates a new [`Styled`] with the given content.
     pub fn new(content: impl std::fmt::Display) -> Self {
         Self {
             content: content.to_string(),
@@ -53,36 +64,78 @@ impl Styled {
         }
     }
 
+    /// Forces styling on or off for all [`Styled`] values, regardless of the `NO_COLOR` environment variable.  
+    /// Passing `None` restores the detection from the environment. This is useful for keeping test output deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use promptuity::style::{Color, Styled};
+    ///
+    /// Styled::set_color_override(Some(false));
+    /// assert_eq!(Styled::new("text").fg(Color::Green).to_string(), "text");
+    ///
+    /// Styled::set_color_override(None);
+    /// ```
+    pub fn set_color_override(enabled: Option<bool>) {
+        let value = match enabled {
+            None => COLOR_AUTO,
+            Some(true) => COLOR_ALWAYS,
+            Some(false) => COLOR_NEVER,
+        };
+        COLOR_OVERRIDE.store(value, Ordering::Relaxed);
+        // crossterm checks `NO_COLOR` on its own when rendering colors, so keep it in sync.
+        Colored::set_ansi_color_disabled(enabled.map_or_else(Colored::ansi_color_disabled, |v| !v));
+    }
+
+    /// Sets the foreground color.
     pub fn fg(&mut self, color: Color) -> &mut Self {
         self.fg = Some(color);
         self
     }
 
+    /// Sets the background color.
     pub fn bg(&mut self, color: Color) -> &mut Self {
         self.bg = Some(color);
         self
     }
 
+    /// Sets the foreground color as RGB.
+    pub fn fg_rgb(&mut self, r: u8, g: u8, b: u8) -> &mut Self {
+        self.fg(Color::Rgb { r, g, b })
+    }
+
+    /// Sets the background color as RGB.
+    pub fn bg_rgb(&mut self, r: u8, g: u8, b: u8) -> &mut Self {
+        self.bg(Color::Rgb { r, g, b })
+    }
+
+    /// Makes the content bold.
     pub fn bold(&mut self) -> &mut Self {
         self.attr(Attribute::Bold)
     }
 
+    /// Makes the content italic.
     pub fn italic(&mut self) -> &mut Self {
         self.attr(Attribute::Italic)
     }
 
+    /// Underlines the content.
     pub fn underline(&mut self) -> &mut Self {
         self.attr(Attribute::Underlined)
     }
 
+    /// Dims the content.
     pub fn dim(&mut self) -> &mut Self {
         self.attr(Attribute::Dim)
     }
 
+    /// Swaps the foreground and background colors.
     pub fn rev(&mut self) -> &mut Self {
         self.attr(Attribute::Reverse)
     }
 
+    /// Makes the content blink.
     pub fn blink(&mut self) -> &mut Self {
         self.attr(Attribute::SlowBlink)
     }
@@ -98,6 +151,9 @@ impl Styled {
 
 impl std::fmt::Display for Styled {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !color_enabled() {
+            return write!(f, "{}", self.content);
+        }
         let mut style = ContentStyle::new();
         if let Some(fg) = self.fg {
             style = style.with(fg);
@@ -112,6 +168,58 @@ impl std::fmt::Display for Styled {
     }
 }
 
+fn color_enabled() -> bool {
+    resolve_color(
+        COLOR_OVERRIDE.load(Ordering::Relaxed),
+        std::env::var_os("NO_COLOR"),
+    )
+}
+
+fn resolve_color(color_override: u8, no_color: Option<std::ffi::OsString>) -> bool {
+    match color_override {
+        COLOR_ALWAYS => true,
+        COLOR_NEVER => false,
+        _ => match no_color {
+            Some(value) => value.is_empty(),
+            None => true,
+        },
+    }
+}
+
+/// Parses a hex color code, such as `#ff8800` or `#f80`, into a [`Color::Rgb`].  
+/// The leading `#` is optional. Returns `None` if the code is malformed.
+///
+/// # Examples
+///
+/// ```
+/// use promptuity::style::{hex, Color};
+///
+/// assert_eq!(hex("#ff8800"), Some(Color::Rgb { r: 255, g: 136, b: 0 }));
+/// assert_eq!(hex("f80"), Some(Color::Rgb { r: 255, g: 136, b: 0 }));
+/// assert_eq!(hex("#ff88"), None);
+/// ```
+pub fn hex(code: &str) -> Option<Color> {
+    let code = code.strip_prefix('#').unwrap_or(code);
+    if !code.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    let (r, g, b) = match code.len() {
+        3 => {
+            let short = |i: usize| channel(&code[i..i + 1]).map(|v| v * 17);
+            (short(0)?, short(1)?, short(2)?)
+        }
+        6 => (
+            channel(&code[0..2])?,
+            channel(&code[2..4])?,
+            channel(&code[4..6])?,
+        ),
+        _ => return None,
+    };
+    Some(Color::Rgb { r, g, b })
+}
+
 #[cfg(windows)]
 fn is_unicode_supported() -> bool {
     use std::env;
@@ -168,29 +276,54 @@ impl<'a, 'b> std::fmt::Display for Symbol<'a, 'b> {
     }
 }
 
-/// A utility function to wrap text at a specified character count.
+/// A utility function to wrap text at the specified display width.  
+/// Wide characters are measured by their display width. ANSI escape sequences (such as SGR colors) are preserved and ignored when measuring.
+///
+/// This is the same wrapping the built-in themes use to count the lines they have rendered.
 ///
 /// # Examples
 ///
 /// ```
-/// use promptuity::style::wrap_text;
+/// use promptuity::style::{wrap_text, Color, Styled};
+/// # Styled::set_color_override(Some(true));
 ///
 /// let text = "This is a long text that will be wrapped at 10 characters.";
-///
 /// assert_eq!(wrap_text(text, 10), "This is a \nlong text \nthat will \nbe wrapped\n at 10 cha\nracters.");
+///
+/// assert_eq!(wrap_text("日本語テキスト", 6), "日本語\nテキス\nト");
+///
+/// let styled = Styled::new("Hello World").fg(Color::Green).to_string();
+/// assert_eq!(wrap_text(&styled, 6), "\u{1b}[38;5;10mHello \nWorld\u{1b}[39m");
 /// ```
-pub fn wrap_text(input: &str, col: u16) -> String {
+pub fn wrap_text(input: &str, width: u16) -> String {
+    let width = width as usize;
     let mut output = String::new();
     let mut cw = 0;
+    let mut chars = input.chars().peekable();
 
-    for (_, c) in input.char_indices() {
-        let w = c.width().unwrap_or(0);
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            output.push(c);
+            if let Some(n) = chars.next_if_eq(&'[') {
+                output.push(n);
+                for n in chars.by_ref() {
+                    output.push(n);
+                    if ('@'..='~').contains(&n) {
+                        break;
+                    }
+                }
+            } else if let Some(n) = chars.next() {
+                output.push(n);
+            }
+            continue;
+        }
         if c == '\n' {
             cw = 0;
             output.push(c);
             continue;
         }
-        if cw + w > col as usize {
+        let w = c.width().unwrap_or(0);
+        if cw + w > width {
             output.push('\n');
             cw = 0;
         }
@@ -200,3 +333,149 @@ pub fn wrap_text(input: &str, col: u16) -> String {
 
     output
 }
+
+const S_ELLIPSIS: Symbol = Symbol("…", "...");
+
+/// A utility function to truncate text to fit within the specified display width.  
+/// If the text is truncated, an ellipsis is appended. ANSI escape sequences are preserved and do not count towards the width.
+///
+/// # Examples
+///
+/// ```
+/// use promptuity::style::{truncate_to_width, Color, Styled};
+/// # Styled::set_color_override(Some(true));
+///
+/// assert_eq!(truncate_to_width("Hello", 10), "Hello");
+/// assert_eq!(truncate_to_width("Hello World", 8), "Hello W…");
+///
+/// let styled = Styled::new("Hello World").fg(Color::Green).to_string();
+/// assert_eq!(truncate_to_width(&styled, 8), "\u{1b}[38;5;10mHello W…\u{1b}[39m");
+/// ```
+pub fn truncate_to_width(input: &str, max_cols: u16) -> String {
+    let max_cols = max_cols as usize;
+    let ellipsis = S_ELLIPSIS.to_string();
+    let ellipsis_width = ellipsis.chars().filter_map(|c| c.width()).sum::<usize>();
+
+    let width = strip_ansi_escapes::strip_str(input)
+        .chars()
+        .filter_map(|c| c.width())
+        .sum::<usize>();
+    if width <= max_cols {
+        return input.to_string();
+    }
+
+    let budget = max_cols.saturating_sub(ellipsis_width);
+    let mut output = String::new();
+    let mut cw = 0;
+    let mut truncated = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            output.push(c);
+            if let Some(n) = chars.next_if_eq(&'[') {
+                output.push(n);
+                for n in chars.by_ref() {
+                    output.push(n);
+                    if ('@'..='~').contains(&n) {
+                        break;
+                    }
+                }
+            } else if let Some(n) = chars.next() {
+                output.push(n);
+            }
+            continue;
+        }
+
+        if truncated {
+            continue;
+        }
+
+        let w = c.width().unwrap_or(0);
+        if cw + w > budget {
+            truncated = true;
+            if max_cols >= ellipsis_width {
+                output.push_str(&ellipsis);
+            }
+            continue;
+        }
+        output.push(c);
+        cw += w;
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_color() {
+        assert!(resolve_color(COLOR_AUTO, None));
+        assert!(resolve_color(COLOR_AUTO, Some("".into())));
+        assert!(!resolve_color(COLOR_AUTO, Some("1".into())));
+        assert!(resolve_color(COLOR_ALWAYS, Some("1".into())));
+        assert!(!resolve_color(COLOR_NEVER, None));
+    }
+
+    #[test]
+    fn test_wrap_text_ansi() {
+        let input = "\u{1b}[1mabc\u{1b}[0mdef\u{1b}[38;5;10mgh\u{1b}[39m";
+        assert_eq!(
+            wrap_text(input, 4),
+            "\u{1b}[1mabc\u{1b}[0md\nef\u{1b}[38;5;10mgh\u{1b}[39m"
+        );
+        assert_eq!(wrap_text("あいう\nえお", 4), "あい\nう\nえお");
+    }
+
+    #[test]
+    fn test_truncate_to_width_wide_chars() {
+        assert_eq!(truncate_to_width("日本語ABCDEF", 12), "日本語ABCDEF");
+        assert_eq!(truncate_to_width("日本語ABCDEF", 11), "日本語ABCD…");
+        assert_eq!(truncate_to_width("日本語ABCDEF", 7), "日本語…");
+        assert_eq!(truncate_to_width("日本語ABCDEF", 6), "日本…");
+        assert_eq!(truncate_to_width("日本語ABCDEF", 1), "…");
+        assert_eq!(truncate_to_width("日本語ABCDEF", 0), "");
+
+        let styled = "\u{1b}[38;5;10m日本語ABCDEF\u{1b}[39m";
+        assert_eq!(
+            truncate_to_width(styled, 6),
+            "\u{1b}[38;5;10m日本…\u{1b}[39m"
+        );
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(hex("#000000"), Some(Color::Rgb { r: 0, g: 0, b: 0 }));
+        assert_eq!(
+            hex("#1A2b3C"),
+            Some(Color::Rgb {
+                r: 0x1a,
+                g: 0x2b,
+                b: 0x3c
+            })
+        );
+        assert_eq!(
+            hex("fff"),
+            Some(Color::Rgb {
+                r: 255,
+                g: 255,
+                b: 255
+            })
+        );
+
+        for code in [
+            "",
+            "#",
+            "#12345",
+            "#1234567",
+            "#gg0000",
+            "##ff8800",
+            "#ff 800",
+            "#ｆｆ８",
+        ] {
+            assert_eq!(hex(code), None, "{:?}", code);
+        }
+    }
+}
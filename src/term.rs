@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
 use crossterm::style::Print;
 use crossterm::terminal::{self, disable_raw_mode, enable_raw_mode, ClearType};
@@ -6,7 +8,16 @@ use crossterm::{cursor, Command, QueueableCommand};
 use crate::Error;
 
 /// A struct to represent the terminal size.
-#[derive(Debug)]
+///
+/// # Examples
+///
+/// ```
+/// use promptuity::TermSize;
+///
+/// assert_eq!(TermSize::new(80, 24), TermSize::new(80, 24));
+/// assert_ne!(TermSize::new(80, 24), TermSize::new(120, 40));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TermSize {
     /// The width of the terminal.
     pub width: u16,
@@ -22,7 +33,16 @@ impl TermSize {
 }
 
 /// A struct to represent the cursor position.
-#[derive(Debug)]
+///
+/// # Examples
+///
+/// ```
+/// use promptuity::CursorPosition;
+///
+/// assert_eq!(CursorPosition::new(5, 3), CursorPosition::new(5, 3));
+/// assert_ne!(CursorPosition::new(5, 3), CursorPosition::new(3, 5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CursorPosition {
     /// The column of the cursor.
     pub col: u16,
@@ -50,6 +70,22 @@ pub trait Terminal<T: std::io::Write> {
     fn enable_raw(&mut self) -> Result<(), Error>;
     /// Disables raw mode.
     fn disable_raw(&mut self) -> Result<(), Error>;
+    /// Enables mouse capture, so mouse events are read with [`Terminal::read_event`]. Does nothing by default.
+    fn enable_mouse_capture(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+    /// Disables mouse capture. Does nothing by default.
+    fn disable_mouse_capture(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+    /// Enables bracketed paste, so pasted text is read as a single [`Event::Paste`] with [`Terminal::read_event`]. Does nothing by default.
+    fn enable_bracketed_paste(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+    /// Disables bracketed paste. Does nothing by default.
+    fn disable_bracketed_paste(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
     /// Shows the cursor.
     fn cursor_show(&mut self) -> Result<(), Error>;
     /// Hides the cursor.
@@ -78,6 +114,10 @@ pub trait Terminal<T: std::io::Write> {
     fn clear_cursor_up(&mut self) -> Result<(), Error>;
     /// Clears the terminal screen from the cursor position downwards.
     fn clear_cursor_down(&mut self) -> Result<(), Error>;
+    /// Sets the terminal title. Does nothing by default.
+    fn set_title(&mut self, _title: &str) -> Result<(), Error> {
+        Ok(())
+    }
     /// Writes the specified value to the terminal.
     fn write(&mut self, value: &str) -> Result<(), Error>;
     /// Writes the specified value to the terminal and appends a newline.
@@ -86,6 +126,32 @@ pub trait Terminal<T: std::io::Write> {
     fn flush(&mut self) -> Result<(), Error>;
     /// Reads a key from the terminal.
     fn read_key(&mut self) -> Result<(KeyCode, KeyModifiers), Error>;
+    /// Reads a key event from the terminal, including its [`crate::event::KeyEventKind`].  
+    /// Unlike [`Terminal::read_key`], release events are returned as well, so callers can tell them apart. By default, it wraps [`Terminal::read_key`] as a press event.
+    fn read_key_event(&mut self) -> Result<KeyEvent, Error> {
+        let (code, modifiers) = self.read_key()?;
+        Ok(KeyEvent::new(code, modifiers))
+    }
+    /// Reads an event from the terminal, such as a key event or text pasted with bracketed paste.  
+    /// [`crate::Promptuity`] enables bracketed paste with [`Terminal::enable_bracketed_paste`] and handles key, paste, resize and mouse events read here. By default, it wraps [`Terminal::read_key_event`].
+    fn read_event(&mut self) -> Result<Event, Error> {
+        self.read_key_event().map(Event::Key)
+    }
+    /// Reads a key from the terminal, waiting at most `timeout`.  
+    /// Returns `None` when no key is pressed before the timeout. By default, it waits indefinitely using [`Terminal::read_key`].
+    fn read_key_timeout(
+        &mut self,
+        _timeout: Duration,
+    ) -> Result<Option<(KeyCode, KeyModifiers)>, Error> {
+        self.read_key().map(Some)
+    }
+    /// Reads an event from the terminal, waiting at most `timeout`.  
+    /// Returns `None` when no event arrives before the timeout. By default, it wraps [`Terminal::read_key_timeout`].
+    fn read_event_timeout(&mut self, timeout: Duration) -> Result<Option<Event>, Error> {
+        Ok(self
+            .read_key_timeout(timeout)?
+            .map(|(code, modifiers)| Event::Key(KeyEvent::new(code, modifiers))))
+    }
 }
 
 /// A struct to represent a terminal.
@@ -162,6 +228,31 @@ impl<T: std::io::Write> Terminal<T> for Term<T> {
         Ok(())
     }
 
+    fn enable_bracketed_paste(&mut self) -> Result<(), Error> {
+        // Bracketed paste is not supported by legacy Windows consoles, where pastes arrive as key presses.
+        let _ = self.cmd(event::EnableBracketedPaste);
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    fn disable_bracketed_paste(&mut self) -> Result<(), Error> {
+        let _ = self.cmd(event::DisableBracketedPaste);
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    fn enable_mouse_capture(&mut self) -> Result<(), Error> {
+        self.cmd(event::EnableMouseCapture)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    fn disable_mouse_capture(&mut self) -> Result<(), Error> {
+        self.cmd(event::DisableMouseCapture)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
     fn cursor_show(&mut self) -> Result<(), Error> {
         self.cmd(cursor::Show)
     }
@@ -222,6 +313,10 @@ impl<T: std::io::Write> Terminal<T> for Term<T> {
         self.cmd(terminal::Clear(ClearType::FromCursorDown))
     }
 
+    fn set_title(&mut self, title: &str) -> Result<(), Error> {
+        self.cmd(terminal::SetTitle(title))
+    }
+
     fn write(&mut self, value: &str) -> Result<(), Error> {
         self.cmd(Print(value))?;
         Ok(())
@@ -240,8 +335,38 @@ impl<T: std::io::Write> Terminal<T> for Term<T> {
         Ok(())
     }
 
+    /// Reads a key press, skipping release events. Repeated presses while a key is held down are returned as well.
     fn read_key(&mut self) -> Result<(KeyCode, KeyModifiers), Error> {
         loop {
+            let event = self.read_key_event()?;
+            if event.kind != event::KeyEventKind::Release {
+                return Ok((event.code, event.modifiers));
+            }
+        }
+    }
+
+    fn read_key_event(&mut self) -> Result<KeyEvent, Error> {
+        loop {
+            if let Event::Key(event) = self.read_event()? {
+                return Ok(event);
+            }
+        }
+    }
+
+    fn read_event(&mut self) -> Result<Event, Error> {
+        Ok(event::read()?)
+    }
+
+    fn read_key_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<Option<(KeyCode, KeyModifiers)>, Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if !event::poll(remaining)? {
+                return Ok(None);
+            }
             if let Event::Key(KeyEvent {
                 code,
                 modifiers,
@@ -249,10 +374,204 @@ impl<T: std::io::Write> Terminal<T> for Term<T> {
                 ..
             }) = event::read()?
             {
-                if kind == event::KeyEventKind::Press {
-                    return Ok((code, modifiers));
+                if kind != event::KeyEventKind::Release {
+                    return Ok(Some((code, modifiers)));
                 }
             }
         }
     }
+    fn read_event_timeout(&mut self, timeout: Duration) -> Result<Option<Event>, Error> {
+        if event::poll(timeout)? {
+            Ok(Some(event::read()?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// A terminal that does nothing.
+///
+/// Every method of [`Terminal`] returns `Ok(())` without writing anything, and [`Terminal::size`] always returns `80x24`.  
+/// Useful for constructing a [`crate::Promptuity`] in unit tests where the terminal output does not matter.  
+/// Since there is no input, [`Terminal::read_key`] returns an [`Error::Io`] of [`std::io::ErrorKind::UnexpectedEof`].
+///
+/// # Examples
+///
+/// ```
+/// use promptuity::themes::MinimalTheme;
+/// use promptuity::{NullTerminal, Promptuity};
+///
+/// # fn main() -> Result<(), promptuity::Error> {
+/// let mut term = NullTerminal::new(std::io::sink());
+/// let mut theme = MinimalTheme::default();
+/// let mut p = Promptuity::new(&mut term, &mut theme);
+///
+/// p.begin()?;
+/// p.info("Nothing is written")?;
+/// p.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct NullTerminal<W: std::io::Write> {
+    writer: W,
+}
+
+impl<W: std::io::Write> NullTerminal<W> {
+    /// Creates a new [`NullTerminal`] instance.  
+    /// The writer is only returned by [`Terminal::writer`] and is never written by the terminal itself.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl Default for NullTerminal<std::io::Sink> {
+    fn default() -> Self {
+        Self::new(std::io::sink())
+    }
+}
+
+impl<W: std::io::Write> Terminal<W> for NullTerminal<W> {
+    fn writer(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    fn size(&self) -> Result<TermSize, Error> {
+        Ok(TermSize::new(80, 24))
+    }
+
+    fn enable_raw(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn disable_raw(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cursor_pos(&self) -> Result<CursorPosition, Error> {
+        Ok(CursorPosition::new(0, 0))
+    }
+
+    fn move_to(&mut self, _: u16, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn move_column(&mut self, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn move_next_line(&mut self, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn move_previous_line(&mut self, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn scroll_up(&mut self, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn scroll_down(&mut self, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear_purge(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear_current_line(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear_cursor_up(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear_cursor_down(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn write(&mut self, _: &str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn writeln(&mut self, _: &str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn read_key(&mut self) -> Result<(KeyCode, KeyModifiers), Error> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "NullTerminal has no input",
+        )
+        .into())
+    }
+}
+
+/// A guard that keeps Raw Mode enabled while it is alive.
+///
+/// Created by [`crate::Promptuity::raw_mode_guard`]. Raw Mode is enabled on creation and disabled when the guard is dropped, even on early returns or panics.  
+/// When the guard is created inside a session started by [`crate::Promptuity::begin`], Raw Mode is already enabled and stays enabled after the guard is dropped, so subsequent prompts keep working.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::event::KeyCode;
+/// use promptuity::themes::MinimalTheme;
+/// use promptuity::{Promptuity, Term};
+///
+/// # fn main() -> Result<(), promptuity::Error> {
+/// let mut term = Term::default();
+/// let mut theme = MinimalTheme::default();
+/// let mut p = Promptuity::new(&mut term, &mut theme);
+///
+/// {
+///     let mut guard = p.raw_mode_guard()?;
+///     while guard.term().read_key()?.0 != KeyCode::Enter {}
+/// } // Raw Mode is disabled here.
+/// # Ok(())
+/// # }
+/// ```
+pub struct RawModeGuard<'a, W: std::io::Write> {
+    term: &'a mut dyn Terminal<W>,
+    disable_on_drop: bool,
+}
+
+impl<'a, W: std::io::Write> RawModeGuard<'a, W> {
+    pub(crate) fn new(term: &'a mut dyn Terminal<W>, disable_on_drop: bool) -> Result<Self, Error> {
+        term.enable_raw()?;
+        Ok(Self {
+            term,
+            disable_on_drop,
+        })
+    }
+
+    /// Returns the terminal in Raw Mode.
+    pub fn term(&mut self) -> &mut dyn Terminal<W> {
+        self.term
+    }
+}
+
+impl<'a, W: std::io::Write> Drop for RawModeGuard<'a, W> {
+    fn drop(&mut self) {
+        if self.disable_on_drop {
+            let _ = self.term.disable_raw();
+        }
+    }
 }
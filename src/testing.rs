@@ -0,0 +1,181 @@
+//! A module that provides utilities for testing prompts.
+//!
+//! This module is available with the `testing` feature.
+//!
+//! # Testing
+//!
+//! [`FakeTerm`] is a [`Terminal`] that replays the given key presses and records the output, allowing you to test your own [`crate::Prompt`] and [`crate::Theme`] implementations without a real terminal.
+//!
+//! ## Examples
+//!
+//! ```
+//! use promptuity::event::{KeyCode, KeyModifiers};
+//! use promptuity::prompts::Input;
+//! use promptuity::testing::FakeTerm;
+//! use promptuity::themes::MinimalTheme;
+//! use promptuity::Promptuity;
+//!
+//! let mut term = FakeTerm::new(&[
+//!     (KeyCode::Char('a'), KeyModifiers::NONE),
+//!     (KeyCode::Enter, KeyModifiers::NONE),
+//! ]);
+//! let mut theme = MinimalTheme::default();
+//!
+//! let value = {
+//!     let mut p = Promptuity::new(&mut term, &mut theme);
+//!     p.prompt(Input::new("Name").as_mut()).unwrap()
+//! };
+//!
+//! assert_eq!(value, "a");
+//! assert!(term.output().contains("Name"));
+//! ```
+
+use std::collections::VecDeque;
+use std::io::Write;
+use std::time::Duration;
+
+use crate::event::*;
+use crate::{CursorPosition, Error, TermSize, Terminal};
+
+/// A [`Terminal`] that replays the given key presses and records the output.
+pub struct FakeTerm {
+    output: Vec<u8>,
+    actions: VecDeque<(KeyCode, KeyModifiers)>,
+    size: (u16, u16),
+}
+
+impl FakeTerm {
+    /// Creates a new [`FakeTerm`] that returns the given key presses in order.
+    pub fn new(actions: &[(KeyCode, KeyModifiers)]) -> Self {
+        Self {
+            output: vec![],
+            actions: VecDeque::from(actions.to_vec()),
+            size: (80, 40),
+        }
+    }
+
+    /// Sets the terminal size. Defaults to `80x40`.
+    pub fn with_size(&mut self, width: u16, height: u16) -> &mut Self {
+        self.size = (width, height);
+        self
+    }
+
+    /// Returns the recorded output.
+    pub fn output(&self) -> String {
+        String::from_utf8_lossy(&self.output).into_owned()
+    }
+}
+
+impl Terminal<Vec<u8>> for FakeTerm {
+    fn writer(&mut self) -> &mut Vec<u8> {
+        &mut self.output
+    }
+
+    fn size(&self) -> Result<TermSize, Error> {
+        Ok(TermSize::new(self.size.0, self.size.1))
+    }
+
+    fn enable_raw(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn disable_raw(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cursor_pos(&self) -> Result<CursorPosition, Error> {
+        Ok(CursorPosition::new(0, 0))
+    }
+
+    fn move_to(&mut self, _: u16, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn move_column(&mut self, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn move_next_line(&mut self, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn move_previous_line(&mut self, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn scroll_up(&mut self, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn scroll_down(&mut self, _: u16) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear_purge(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear_current_line(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear_cursor_up(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear_cursor_down(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn set_title(&mut self, title: &str) -> Result<(), Error> {
+        self.write(&format!("\x1b]0;{}\x07", title))
+    }
+
+    fn write(&mut self, value: &str) -> Result<(), Error> {
+        self.output.write_all(value.as_bytes())?;
+        Ok(())
+    }
+
+    fn writeln(&mut self, value: &str) -> Result<(), Error> {
+        for line in value.lines() {
+            self.write(line)?;
+            self.write("\n")?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Returns the next key press, or an [`Error::Io`] when all key presses have been consumed.
+    fn read_key(&mut self) -> Result<(KeyCode, KeyModifiers), Error> {
+        self.actions.pop_front().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "no more key presses to replay",
+            )
+            .into()
+        })
+    }
+
+    /// Returns the next key press, or `None` to simulate a timeout when all key presses have been consumed.
+    fn read_key_timeout(
+        &mut self,
+        _timeout: Duration,
+    ) -> Result<Option<(KeyCode, KeyModifiers)>, Error> {
+        Ok(self.actions.pop_front())
+    }
+}
@@ -1,14 +1,123 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::style::{truncate_to_width, Styled};
 use crate::{Error, PromptBody, PromptInput, PromptState, Terminal};
 
 /// A struct aggregating the content for rendering.
 #[derive(Debug)]
 pub struct RenderSnapshot<'a> {
+    /// The current state of the prompt.
     pub state: &'a PromptState,
+    /// The message of the prompt.
     pub message: String,
+    /// The hint message of the prompt.
     pub hint: Option<String>,
+    /// The placeholder displayed when no input is given.
     pub placeholder: Option<String>,
+    /// The input of the prompt.
     pub input: PromptInput,
+    /// The body of the prompt.
     pub body: PromptBody,
+    /// The icon that represents the type of the prompt.
+    pub icon: Option<String>,
+    /// The multi-line description displayed before the input or options.
+    pub description: Option<String>,
+    /// The advisory validation message displayed while the prompt is active.
+    pub validation_hint: Option<String>,
+    /// The row displayed above the body when there are more items before the current page.
+    pub page_indicator_before: Option<String>,
+    /// The row displayed below the body when there are more items after the current page.
+    pub page_indicator_after: Option<String>,
+    /// Whether the prompt was left to return to the previous prompt. The state is [`PromptState::Cancel`] in this case. See [`crate::Promptuity::prompt_flow`].
+    pub back: bool,
+}
+
+impl<'a> RenderSnapshot<'a> {
+    /// Returns whether the prompt is in the [`PromptState::Active`] state.
+    pub fn is_active(&self) -> bool {
+        matches!(self.state, PromptState::Active)
+    }
+
+    /// Returns whether the prompt is in the [`PromptState::Submit`] state.
+    pub fn is_submitted(&self) -> bool {
+        matches!(self.state, PromptState::Submit)
+    }
+
+    /// Returns whether the prompt is in the [`PromptState::Cancel`] state.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.state, PromptState::Cancel)
+    }
+
+    /// Returns the error message if the prompt is in the [`PromptState::Error`] or [`PromptState::Fatal`] state.
+    pub fn error_message(&self) -> Option<&str> {
+        match self.state {
+            PromptState::Error(msg) | PromptState::Fatal(msg) => Some(msg.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// A struct representing the metadata of a prompt session, passed to [`Theme::begin`].
+///
+/// # Examples
+///
+/// ```
+/// use promptuity::IntroMetadata;
+///
+/// let meta = IntroMetadata::new("Survey").with_version("1.0.0").with_author("wadackel");
+/// assert_eq!(meta.title, "Survey");
+/// assert_eq!(IntroMetadata::from("Survey").version, None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntroMetadata {
+    /// The title of the session.
+    pub title: String,
+    /// The version of the application.
+    pub version: Option<String>,
+    /// The author of the application.
+    pub author: Option<String>,
+    /// The description of the session.
+    pub description: Option<String>,
+}
+
+impl IntroMetadata {
+    /// Creates a new [`IntroMetadata`] with the given title.
+    pub fn new(title: impl std::fmt::Display) -> Self {
+        Self {
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the version of the application.
+    pub fn with_version(mut self, version: impl std::fmt::Display) -> Self {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Sets the author of the application.
+    pub fn with_author(mut self, author: impl std::fmt::Display) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    /// Sets the description of the session.
+    pub fn with_description(mut self, description: impl std::fmt::Display) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+}
+
+impl From<&str> for IntroMetadata {
+    fn from(title: &str) -> Self {
+        Self::new(title)
+    }
+}
+
+impl From<String> for IntroMetadata {
+    fn from(title: String) -> Self {
+        Self::new(title)
+    }
 }
 
 /// A trait for the Theme that determines what Promptuity renders.
@@ -27,10 +136,50 @@ pub trait Theme<W: std::io::Write> {
     /// Output of messages with a step decoration.
     fn step(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error>;
 
+    /// Output of messages with a step decoration and an optional `hint`, a brief sub-message associated with the step.  
+    /// By default, the hint is appended to the message in parentheses and passed to [`Theme::step`].
+    fn step_with_hint(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        message: String,
+        hint: Option<String>,
+    ) -> Result<(), Error> {
+        match hint {
+            Some(hint) => self.step(term, format!("{} ({})", message, hint)),
+            None => self.step(term, message),
+        }
+    }
+
+    /// Output of a key-value pair.  
+    /// By default, the key is rendered in bold and padded to 20 columns, then passed to [`Theme::log`].
+    fn log_kv(&mut self, term: &mut dyn Terminal<W>, key: &str, value: &str) -> Result<(), Error> {
+        self.log(term, fmt_kv(key, value, KV_KEY_WIDTH))
+    }
+
+    /// Output of multiple key-value pairs.  
+    /// By default, the keys are padded to the widest key so that all values are aligned.
+    fn log_kvs(&mut self, term: &mut dyn Terminal<W>, pairs: &[(&str, &str)]) -> Result<(), Error> {
+        let width = pairs.iter().map(|(key, _)| key.width()).max().unwrap_or(0);
+        for (key, value) in pairs {
+            self.log(term, fmt_kv(key, value, width))?;
+        }
+        Ok(())
+    }
+
     /// Renders the start of a prompt session.  
-    /// It can render a title or message received as `intro`.
+    /// It can render a title received as `intro`.
     fn begin(&mut self, term: &mut dyn Terminal<W>, intro: Option<String>) -> Result<(), Error>;
 
+    /// Renders the start of a prompt session with the metadata set by [`crate::Promptuity::with_intro_metadata`].  
+    /// [`crate::Promptuity::begin`] calls this method. By default, only the title is passed to [`Theme::begin`], so override it to render the other fields.
+    fn begin_with_intro(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        intro: Option<IntroMetadata>,
+    ) -> Result<(), Error> {
+        self.begin(term, intro.map(|intro| intro.title))
+    }
+
     /// Renders the prompt.
     fn render(&mut self, term: &mut dyn Terminal<W>, payload: RenderSnapshot) -> Result<(), Error>;
 
@@ -42,4 +191,396 @@ pub trait Theme<W: std::io::Write> {
         state: &PromptState,
         outro: Option<String>,
     ) -> Result<(), Error>;
+
+    /// Renders a frame of a spinner started with [`crate::Promptuity::spinner`].  
+    /// The current line is cleared before each frame, so the frame must be written without a newline. By default, the frame and the message are written separated by two spaces.
+    fn frame(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        frame: String,
+        message: String,
+    ) -> Result<(), Error> {
+        term.write(&format!("{}  {}", frame, message))?;
+        term.flush()?;
+        Ok(())
+    }
+
+    /// Called when the terminal is resized while a prompt is active, before the prompt is rendered again.  
+    /// Themes that erase the previous render by counting wrapped lines should recount them for the new `width`. Does nothing by default.
+    fn resize(
+        &mut self,
+        _term: &mut dyn Terminal<W>,
+        _width: u16,
+        _height: u16,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Returns the number of rows from the first line of the body to the cursor after the last [`Theme::render`].  
+    /// Used to map mouse events to body lines, see [`crate::Prompt::handle_mouse`]. Returns `None` by default, meaning the position is unknown.
+    fn body_offset(&self) -> Option<u16> {
+        None
+    }
+}
+
+const KV_KEY_WIDTH: usize = 20;
+
+fn fmt_kv(key: &str, value: &str, width: usize) -> String {
+    let padding = " ".repeat(width.saturating_sub(key.width()));
+    format!("{}{}  {}", Styled::new(key).bold(), padding, value)
+}
+
+/// A trait for decorating the calls of a [`Theme`].
+///
+/// Every method receives the wrapped `theme` and delegates to it by default, allowing you to override only the calls you need to intercept.  
+/// A middleware can modify the arguments, call the wrapped theme, and post-process the result.  
+/// Use [`MiddlewareTheme`] to apply the middleware to a theme.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::themes::FancyTheme;
+/// use promptuity::{Error, MiddlewareTheme, Terminal, Theme, ThemeMiddleware};
+///
+/// struct PrefixMiddleware;
+///
+/// impl<W: std::io::Write> ThemeMiddleware<W> for PrefixMiddleware {
+///     fn log(
+///         &mut self,
+///         theme: &mut dyn Theme<W>,
+///         term: &mut dyn Terminal<W>,
+///         message: String,
+///     ) -> Result<(), Error> {
+///         theme.log(term, format!("[app] {message}"))
+///     }
+/// }
+///
+/// let mut theme = MiddlewareTheme::new(FancyTheme::default(), PrefixMiddleware);
+/// ```
+pub trait ThemeMiddleware<W: std::io::Write> {
+    /// Intercepts [`Theme::log`].
+    fn log(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        message: String,
+    ) -> Result<(), Error> {
+        theme.log(term, message)
+    }
+
+    /// Intercepts [`Theme::info`].
+    fn info(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        message: String,
+    ) -> Result<(), Error> {
+        theme.info(term, message)
+    }
+
+    /// Intercepts [`Theme::warn`].
+    fn warn(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        message: String,
+    ) -> Result<(), Error> {
+        theme.warn(term, message)
+    }
+
+    /// Intercepts [`Theme::error`].
+    fn error(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        message: String,
+    ) -> Result<(), Error> {
+        theme.error(term, message)
+    }
+
+    /// Intercepts [`Theme::success`].
+    fn success(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        message: String,
+    ) -> Result<(), Error> {
+        theme.success(term, message)
+    }
+
+    /// Intercepts [`Theme::step_with_hint`]. Calls to [`Theme::step`] are intercepted here as well, without a hint.
+    fn step_with_hint(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        message: String,
+        hint: Option<String>,
+    ) -> Result<(), Error> {
+        theme.step_with_hint(term, message, hint)
+    }
+
+    /// Intercepts [`Theme::frame`].
+    fn frame(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        frame: String,
+        message: String,
+    ) -> Result<(), Error> {
+        theme.frame(term, frame, message)
+    }
+
+    /// Intercepts [`Theme::log_kv`].
+    fn log_kv(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        key: &str,
+        value: &str,
+    ) -> Result<(), Error> {
+        theme.log_kv(term, key, value)
+    }
+
+    /// Intercepts [`Theme::log_kvs`].
+    fn log_kvs(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        pairs: &[(&str, &str)],
+    ) -> Result<(), Error> {
+        theme.log_kvs(term, pairs)
+    }
+
+    /// Intercepts [`Theme::begin_with_intro`]. Calls to [`Theme::begin`] are intercepted here as well.
+    fn begin_with_intro(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        intro: Option<IntroMetadata>,
+    ) -> Result<(), Error> {
+        theme.begin_with_intro(term, intro)
+    }
+
+    /// Intercepts [`Theme::render`].
+    fn render(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        payload: RenderSnapshot,
+    ) -> Result<(), Error> {
+        theme.render(term, payload)
+    }
+
+    /// Intercepts [`Theme::finish`].
+    fn finish(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        state: &PromptState,
+        outro: Option<String>,
+    ) -> Result<(), Error> {
+        theme.finish(term, state, outro)
+    }
+}
+
+/// A Theme that wraps another theme with a [`ThemeMiddleware`].
+///
+/// Multiple middlewares can be layered with [`MiddlewareTheme::wrap`]. The last wrapped middleware is called first.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::themes::FancyTheme;
+/// use promptuity::{MiddlewareTheme, ThemeMiddleware};
+///
+/// struct Noop;
+///
+/// impl<W: std::io::Write> ThemeMiddleware<W> for Noop {}
+///
+/// let mut theme = MiddlewareTheme::new(FancyTheme::default(), Noop).wrap(Noop);
+/// ```
+pub struct MiddlewareTheme<T, M> {
+    theme: T,
+    middleware: M,
+}
+
+impl<T, M> MiddlewareTheme<T, M> {
+    /// Creates a new [`MiddlewareTheme`] applying `middleware` to `theme`.
+    pub fn new(theme: T, middleware: M) -> Self {
+        Self { theme, middleware }
+    }
+
+    /// Wraps this theme with another middleware, which is called before the existing ones.
+    pub fn wrap<N>(self, middleware: N) -> MiddlewareTheme<Self, N> {
+        MiddlewareTheme::new(self, middleware)
+    }
+
+    /// Returns a reference to the wrapped theme.
+    pub fn theme(&self) -> &T {
+        &self.theme
+    }
+
+    /// Returns a reference to the middleware.
+    pub fn middleware(&self) -> &M {
+        &self.middleware
+    }
+
+    /// Unwraps this theme, returning the wrapped theme and the middleware.
+    pub fn into_inner(self) -> (T, M) {
+        (self.theme, self.middleware)
+    }
+}
+
+impl<W, T, M> Theme<W> for MiddlewareTheme<T, M>
+where
+    W: std::io::Write,
+    T: Theme<W>,
+    M: ThemeMiddleware<W>,
+{
+    fn log(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.middleware.log(&mut self.theme, term, message)
+    }
+
+    fn info(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.middleware.info(&mut self.theme, term, message)
+    }
+
+    fn warn(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.middleware.warn(&mut self.theme, term, message)
+    }
+
+    fn error(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.middleware.error(&mut self.theme, term, message)
+    }
+
+    fn success(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.middleware.success(&mut self.theme, term, message)
+    }
+
+    fn step(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.step_with_hint(term, message, None)
+    }
+
+    fn step_with_hint(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        message: String,
+        hint: Option<String>,
+    ) -> Result<(), Error> {
+        self.middleware
+            .step_with_hint(&mut self.theme, term, message, hint)
+    }
+
+    fn log_kv(&mut self, term: &mut dyn Terminal<W>, key: &str, value: &str) -> Result<(), Error> {
+        self.middleware.log_kv(&mut self.theme, term, key, value)
+    }
+
+    fn log_kvs(&mut self, term: &mut dyn Terminal<W>, pairs: &[(&str, &str)]) -> Result<(), Error> {
+        self.middleware.log_kvs(&mut self.theme, term, pairs)
+    }
+
+    fn begin(&mut self, term: &mut dyn Terminal<W>, intro: Option<String>) -> Result<(), Error> {
+        self.begin_with_intro(term, intro.map(IntroMetadata::new))
+    }
+
+    fn begin_with_intro(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        intro: Option<IntroMetadata>,
+    ) -> Result<(), Error> {
+        self.middleware
+            .begin_with_intro(&mut self.theme, term, intro)
+    }
+
+    fn render(&mut self, term: &mut dyn Terminal<W>, payload: RenderSnapshot) -> Result<(), Error> {
+        self.middleware.render(&mut self.theme, term, payload)
+    }
+
+    fn finish(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        state: &PromptState,
+        outro: Option<String>,
+    ) -> Result<(), Error> {
+        self.middleware.finish(&mut self.theme, term, state, outro)
+    }
+
+    fn frame(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        frame: String,
+        message: String,
+    ) -> Result<(), Error> {
+        self.middleware.frame(&mut self.theme, term, frame, message)
+    }
+
+    fn resize(&mut self, term: &mut dyn Terminal<W>, width: u16, height: u16) -> Result<(), Error> {
+        self.theme.resize(term, width, height)
+    }
+
+    fn body_offset(&self) -> Option<u16> {
+        self.theme.body_offset()
+    }
+}
+
+/// A trait for transforming the [`RenderSnapshot`] before it is passed to [`Theme::render`].
+///
+/// Middlewares are registered with [`crate::Promptuity::with_render_middleware`] and applied in the order of registration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::{RenderMiddleware, RenderSnapshot};
+///
+/// struct Uppercase;
+///
+/// impl RenderMiddleware for Uppercase {
+///     fn transform<'a>(&self, mut snapshot: RenderSnapshot<'a>) -> RenderSnapshot<'a> {
+///         snapshot.message = snapshot.message.to_uppercase();
+///         snapshot
+///     }
+/// }
+/// ```
+pub trait RenderMiddleware {
+    /// Transforms the snapshot of the prompt.
+    fn transform<'a>(&self, snapshot: RenderSnapshot<'a>) -> RenderSnapshot<'a>;
+}
+
+/// A [`RenderMiddleware`] that prefixes the message with the step number, such as `[1/3]`.
+///
+/// The step advances each time a prompt is submitted.
+pub struct StepCounterMiddleware {
+    current: std::cell::Cell<usize>,
+    total: usize,
+}
+
+impl StepCounterMiddleware {
+    /// Creates a new [`StepCounterMiddleware`] for the given total number of steps.
+    pub fn new(total: usize) -> Self {
+        Self {
+            current: std::cell::Cell::new(1),
+            total,
+        }
+    }
+}
+
+impl RenderMiddleware for StepCounterMiddleware {
+    fn transform<'a>(&self, mut snapshot: RenderSnapshot<'a>) -> RenderSnapshot<'a> {
+        let current = self.current.get();
+        snapshot.message = format!("[{}/{}] {}", current, self.total, snapshot.message);
+        if snapshot.is_submitted() {
+            self.current.set(current + 1);
+        }
+        snapshot
+    }
+}
+
+/// A [`RenderMiddleware`] that truncates the message to the given display width.
+pub struct TruncateMiddleware(pub u16);
+
+impl RenderMiddleware for TruncateMiddleware {
+    fn transform<'a>(&self, mut snapshot: RenderSnapshot<'a>) -> RenderSnapshot<'a> {
+        snapshot.message = truncate_to_width(&snapshot.message, self.0);
+        snapshot
+    }
 }
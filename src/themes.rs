@@ -6,9 +6,16 @@
 //!
 //! - [`MinimalTheme`]: A Theme that offers a compact and minimalistic display.
 //! - [`FancyTheme`]: A Theme that displays with a rich UI.
+//! - [`PlainTheme`]: A Theme that writes plain text without decorations, suitable for logs and non-TTY output.
+//!
+//! The colors of [`MinimalTheme`] and [`FancyTheme`] can be changed with a [`ThemePalette`].
 
 mod fancy;
 mod minimal;
+mod palette;
+mod plain;
 
 pub use fancy::*;
 pub use minimal::*;
+pub use palette::*;
+pub use plain::*;
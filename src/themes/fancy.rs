@@ -1,8 +1,11 @@
 use strip_ansi_escapes::strip_str;
+use unicode_width::UnicodeWidthStr;
 
 use crate::style::*;
+use crate::themes::ThemePalette;
 use crate::{
-    Error, InputCursor, PromptBody, PromptInput, PromptState, RenderSnapshot, Terminal, Theme,
+    Error, InputCursor, IntroMetadata, PromptBody, PromptInput, PromptState, RenderSnapshot,
+    Terminal, Theme,
 };
 
 const S_STEP_ACTIVE: Symbol = Symbol("◆", "*");
@@ -18,14 +21,104 @@ const S_WARN: Symbol = Symbol("▲", "!");
 const S_ERROR: Symbol = Symbol("✘", "x");
 const S_SUCCESS: Symbol = Symbol("✔", "√");
 
+/// The symbols used to draw [`FancyTheme`].
+///
+/// Defaults to the symbols of the built-in design. Override only the fields you need with the struct update syntax.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::style::Symbol;
+/// use promptuity::themes::{FancySymbols, FancyTheme};
+///
+/// let mut theme = FancyTheme::default();
+/// theme.with_symbols(FancySymbols {
+///     bar: Symbol("┃", "|"),
+///     step_active: Symbol("▶", ">"),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FancySymbols {
+    /// The icon of an active prompt.
+    pub step_active: Symbol<'static, 'static>,
+    /// The icon of a prompt with an error.
+    pub step_error: Symbol<'static, 'static>,
+    /// The icon of a submitted prompt and of steps.
+    pub step_submit: Symbol<'static, 'static>,
+    /// The start of the bar, drawn at the beginning of a session.
+    pub bar_start: Symbol<'static, 'static>,
+    /// The bar drawn to the left of the prompts.
+    pub bar: Symbol<'static, 'static>,
+    /// The end of the bar, drawn below an active prompt and at the end of a session.
+    pub bar_end: Symbol<'static, 'static>,
+    /// The icon of info messages.
+    pub info: Symbol<'static, 'static>,
+    /// The icon of warning messages and warnings of prompts.
+    pub warn: Symbol<'static, 'static>,
+    /// The icon of error messages.
+    pub error: Symbol<'static, 'static>,
+    /// The icon of success messages.
+    pub success: Symbol<'static, 'static>,
+}
+
+impl Default for FancySymbols {
+    fn default() -> Self {
+        Self {
+            step_active: S_STEP_ACTIVE,
+            step_error: S_STEP_ERROR,
+            step_submit: S_STEP_SUBMIT,
+            bar_start: S_BAR_START,
+            bar: S_BAR,
+            bar_end: S_BAR_END,
+            info: S_INFO,
+            warn: S_WARN,
+            error: S_ERROR,
+            success: S_SUCCESS,
+        }
+    }
+}
+
 /// A Theme that displays with a rich UI.
 pub struct FancyTheme {
     prev_lines: u16,
+    prev_output: String,
+    body_offset: Option<u16>,
+    right_align_hint: bool,
+    palette: ThemePalette,
+    symbols: FancySymbols,
 }
 
 impl FancyTheme {
+    /// Creates a new [`FancyTheme`].
     pub fn new() -> Self {
-        Self { prev_lines: 0 }
+        Self {
+            prev_lines: 0,
+            prev_output: String::new(),
+            body_offset: None,
+            right_align_hint: false,
+            palette: ThemePalette::default(),
+            symbols: FancySymbols::default(),
+        }
+    }
+
+    /// Sets whether to right-align the hint to the terminal width.  
+    /// If the terminal is too narrow, the hint is placed next to the message.
+    pub fn with_right_align_hint(&mut self, right_align_hint: bool) -> &mut Self {
+        self.right_align_hint = right_align_hint;
+        self
+    }
+
+    /// Sets the colors of the theme.
+    pub fn with_palette(&mut self, palette: ThemePalette) -> &mut Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets the symbols used to draw the theme.
+    pub fn with_symbols(&mut self, symbols: FancySymbols) -> &mut Self {
+        self.symbols = symbols;
+        self
     }
 
     fn fmt_line_with(
@@ -37,7 +130,7 @@ impl FancyTheme {
     }
 
     fn fmt_line(&self, color: Color, line: impl std::fmt::Display) -> String {
-        self.fmt_line_with(Styled::new(S_BAR).fg(color), line)
+        self.fmt_line_with(Styled::new(self.symbols.bar).fg(color), line)
     }
 
     fn fmt_message(
@@ -50,7 +143,7 @@ impl FancyTheme {
             .map(|hint| {
                 format!(
                     " {}",
-                    Styled::new(format!("({})", hint)).fg(Color::DarkGrey)
+                    Styled::new(format!("({})", hint)).fg(self.palette.muted)
                 )
             })
             .unwrap_or_default();
@@ -58,6 +151,76 @@ impl FancyTheme {
         self.fmt_line_with(icon, format!("{}{}", message, hint))
     }
 
+    fn fit_hint(&self, hint: Option<String>, message: &str, width: u16) -> Option<String> {
+        // icon + spaces, and the parentheses surrounding the hint
+        let used = 3 + strip_str(message).width() + 3;
+        let width = u16::try_from(used).map_or(0, |used| width.saturating_sub(used));
+        hint.map(|hint| truncate_to_width(&hint, width))
+    }
+
+    fn wrap_lines(&self, text: &str, width: u16) -> Vec<String> {
+        // icon + spaces
+        let text = if width > 3 {
+            wrap_text(text, width - 3)
+        } else {
+            text.to_string()
+        };
+        let lines = text.lines().map(String::from).collect::<Vec<_>>();
+        if lines.is_empty() {
+            vec![String::new()]
+        } else {
+            lines
+        }
+    }
+
+    fn fmt_message_with_hint(
+        &self,
+        icon: impl std::fmt::Display,
+        color: Color,
+        message: String,
+        hint: Option<String>,
+        width: u16,
+    ) -> String {
+        let mut lines = self.wrap_lines(&message, width);
+        let message = lines.pop().unwrap_or_default();
+        let hint = self.fit_hint(hint, &message, width);
+
+        if let (true, Some(hint)) = (self.right_align_hint, &hint) {
+            // icon + spaces, and the parentheses surrounding the hint
+            let used = 3 + strip_str(&message).width() + strip_str(hint).width() + 2;
+            let pad = (width as usize).saturating_sub(used);
+            if pad > 0 && lines.is_empty() {
+                return self.fmt_line_with(
+                    icon,
+                    format!(
+                        "{}{}{}",
+                        Styled::new(message).bold(),
+                        " ".repeat(pad),
+                        Styled::new(format!("({})", hint)).fg(self.palette.muted)
+                    ),
+                );
+            }
+        }
+
+        let gutter = Styled::new(self.symbols.bar).fg(color).to_string();
+        let mut output = String::new();
+        for (i, line) in lines.iter().enumerate() {
+            let prefix = if i == 0 {
+                icon.to_string()
+            } else {
+                gutter.clone()
+            };
+            output.push_str(&self.fmt_line_with(prefix, Styled::new(line).bold()));
+        }
+        let prefix = if lines.is_empty() {
+            icon.to_string()
+        } else {
+            gutter
+        };
+        output.push_str(&self.fmt_message(prefix, Styled::new(message).bold(), hint));
+        output
+    }
+
     fn fmt_cursor(&self, cursor: InputCursor) -> String {
         let (left, cursor, right) = cursor.split();
         format!("{left}{}{right}", Styled::new(cursor).rev())
@@ -69,7 +232,7 @@ impl FancyTheme {
         format!(
             "{}{}",
             Styled::new(cursor).rev(),
-            Styled::new(right).fg(Color::DarkGrey),
+            Styled::new(right).fg(self.palette.muted),
         )
     }
 
@@ -83,7 +246,7 @@ impl FancyTheme {
             PromptInput::Raw(s) => {
                 let input = if s.is_empty() {
                     Styled::new(placeholder.unwrap_or_default())
-                        .fg(Color::DarkGrey)
+                        .fg(self.palette.muted)
                         .to_string()
                 } else {
                     s.to_string()
@@ -104,45 +267,67 @@ impl FancyTheme {
 
     fn fmt_input_submit(&self, color: Color, input: PromptInput) -> String {
         match input {
-            PromptInput::Raw(s) => self.fmt_line(color, Styled::new(s).fg(Color::DarkGrey)),
+            PromptInput::Raw(s) => self.fmt_line(color, Styled::new(s).fg(self.palette.muted)),
             PromptInput::Cursor(c) => {
-                self.fmt_line(color, Styled::new(c.value()).fg(Color::DarkGrey))
+                self.fmt_line(color, Styled::new(c.value()).fg(self.palette.muted))
             }
             _ => String::new(),
         }
     }
 
+    fn fmt_description(&self, color: Color, description: Option<String>) -> String {
+        description.map_or_else(String::new, |description| {
+            description
+                .lines()
+                .map(|line| self.fmt_line(color, Styled::new(line).fg(self.palette.muted)))
+                .collect::<Vec<_>>()
+                .join("")
+        })
+    }
+
     fn fmt_body_active(&self, color: Color, body: PromptBody) -> String {
-        match body {
-            PromptBody::Raw(s) => s
+        match body.visible_content() {
+            Some(s) => s
                 .lines()
                 .map(|line| self.fmt_line(color, line))
                 .collect::<Vec<_>>()
                 .join("")
                 .to_string(),
-            _ => String::new(),
+            None => String::new(),
         }
     }
 
+    fn fmt_page_indicator(&self, indicator: Option<String>) -> String {
+        indicator.map_or_else(String::new, |indicator| {
+            self.fmt_line(self.palette.muted, indicator)
+        })
+    }
+
     fn fmt_body_submit(&self, body: PromptBody) -> String {
-        match body {
-            PromptBody::Raw(s) => s
+        match body.visible_content() {
+            Some(s) => s
                 .lines()
-                .map(|line| self.fmt_line(Color::DarkGrey, Styled::new(line).fg(Color::DarkGrey)))
+                .map(|line| {
+                    self.fmt_line(self.palette.muted, Styled::new(line).fg(self.palette.muted))
+                })
                 .collect::<Vec<_>>()
                 .join("")
                 .to_string(),
-            _ => String::new(),
+            None => String::new(),
         }
     }
 
     fn fmt_end(&self, color: Color, edge: bool) -> String {
-        let symbol = if edge { S_BAR_END } else { S_BAR };
+        let symbol = if edge {
+            self.symbols.bar_end
+        } else {
+            self.symbols.bar
+        };
         Styled::new(symbol).fg(color).to_string()
     }
 
     fn fmt_error(&self, message: String) -> String {
-        Styled::new(message).fg(Color::Yellow).to_string()
+        Styled::new(message).fg(self.palette.error).to_string()
     }
 }
 
@@ -154,7 +339,9 @@ impl Default for FancyTheme {
 
 impl<W: std::io::Write> Theme<W> for FancyTheme {
     fn log(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
-        let prefix = Styled::new(S_BAR).fg(Color::DarkGrey).to_string();
+        let prefix = Styled::new(self.symbols.bar)
+            .fg(self.palette.muted)
+            .to_string();
         if message.is_empty() {
             term.writeln(&prefix)?;
         } else {
@@ -169,37 +356,62 @@ impl<W: std::io::Write> Theme<W> for FancyTheme {
     fn info(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
         self.log(
             term,
-            format!("{} {}", Styled::new(S_INFO).fg(Color::Cyan), message),
+            format!(
+                "{} {}",
+                Styled::new(self.symbols.info).fg(self.palette.info),
+                message
+            ),
         )
     }
 
     fn warn(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
         self.log(
             term,
-            format!("{} {}", Styled::new(S_WARN).fg(Color::Yellow), message),
+            format!(
+                "{} {}",
+                Styled::new(self.symbols.warn).fg(self.palette.warning),
+                message
+            ),
         )
     }
 
     fn error(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
         self.log(
             term,
-            format!("{} {}", Styled::new(S_ERROR).fg(Color::Red), message),
+            format!(
+                "{} {}",
+                Styled::new(self.symbols.error).fg(self.palette.fatal),
+                message
+            ),
         )
     }
 
     fn success(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
         self.log(
             term,
-            format!("{} {}", Styled::new(S_SUCCESS).fg(Color::Green), message),
+            format!(
+                "{} {}",
+                Styled::new(self.symbols.success).fg(self.palette.success),
+                message
+            ),
         )
     }
 
     fn step(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.step_with_hint(term, message, None)
+    }
+
+    fn step_with_hint(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        message: String,
+        hint: Option<String>,
+    ) -> Result<(), Error> {
         term.writeln(
             self.fmt_message(
-                Styled::new(S_STEP_SUBMIT).fg(Color::Green),
+                Styled::new(self.symbols.step_submit).fg(self.palette.submit),
                 Styled::new(message).bold(),
-                None,
+                hint,
             )
             .trim(),
         )?;
@@ -208,15 +420,54 @@ impl<W: std::io::Write> Theme<W> for FancyTheme {
     }
 
     fn begin(&mut self, term: &mut dyn Terminal<W>, intro: Option<String>) -> Result<(), Error> {
+        self.begin_with_intro(term, intro.map(IntroMetadata::new))
+    }
+
+    fn begin_with_intro(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        intro: Option<IntroMetadata>,
+    ) -> Result<(), Error> {
+        let intro = intro.unwrap_or_else(|| IntroMetadata::new("INTRO"));
+        let width = term.size()?.width;
         term.cursor_hide()?;
-        term.writeln(&format!(
-            "{}  {}",
-            Styled::new(S_BAR_START).fg(Color::DarkGrey),
-            Styled::new(format!(" {} ", intro.unwrap_or("INTRO".into())))
-                .rev()
-                .fg(Color::Cyan)
-        ))?;
-        term.writeln(&Styled::new(S_BAR).fg(Color::DarkGrey).to_string())?;
+        // The title is padded with a space on each side.
+        for (i, line) in self
+            .wrap_lines(&intro.title, width.saturating_sub(2))
+            .iter()
+            .enumerate()
+        {
+            let symbol = if i == 0 {
+                self.symbols.bar_start
+            } else {
+                self.symbols.bar
+            };
+            term.writeln(&format!(
+                "{}  {}",
+                Styled::new(symbol).fg(self.palette.muted),
+                Styled::new(format!(" {} ", line))
+                    .rev()
+                    .fg(self.palette.active)
+            ))?;
+        }
+        let details = [intro.version.map(|v| format!("v{}", v)), intro.author]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if !details.is_empty() {
+            for line in self.wrap_lines(&details.join(" · "), width) {
+                term.writeln(&format!(
+                    "{}  {}",
+                    Styled::new(self.symbols.bar).fg(self.palette.muted),
+                    Styled::new(line).fg(self.palette.muted)
+                ))?;
+            }
+        }
+        term.writeln(
+            &Styled::new(self.symbols.bar)
+                .fg(self.palette.muted)
+                .to_string(),
+        )?;
         term.flush()?;
         Ok(())
     }
@@ -231,44 +482,76 @@ impl<W: std::io::Write> Theme<W> for FancyTheme {
 
         match payload.state {
             PromptState::Active => {
-                output.push_str(&self.fmt_message(
-                    Styled::new(S_STEP_ACTIVE).fg(Color::Cyan),
-                    Styled::new(payload.message).bold(),
+                let icon = payload
+                    .icon
+                    .unwrap_or_else(|| self.symbols.step_active.to_string());
+                output.push_str(&self.fmt_message_with_hint(
+                    Styled::new(icon).fg(self.palette.active),
+                    self.palette.active,
+                    payload.message,
                     payload.hint,
+                    term.size()?.width,
                 ));
 
+                output.push_str(&self.fmt_description(self.palette.active, payload.description));
+
                 output.push_str(&self.fmt_input_active(
-                    Color::Cyan,
+                    self.palette.active,
                     payload.input,
                     payload.placeholder,
                 ));
 
-                output.push_str(&self.fmt_body_active(Color::Cyan, payload.body));
-                output.push_str(&self.fmt_end(Color::Cyan, true));
+                let body_line = wrap_text(&strip_str(&output), term.size()?.width)
+                    .lines()
+                    .count() as u16;
+                let has_body = !matches!(payload.body, PromptBody::None);
+                output.push_str(&self.fmt_page_indicator(payload.page_indicator_before));
+                output.push_str(&self.fmt_body_active(self.palette.active, payload.body));
+                output.push_str(&self.fmt_page_indicator(payload.page_indicator_after));
+                if let Some(message) = payload.validation_hint {
+                    output.push_str(&self.fmt_line(
+                        self.palette.active,
+                        Styled::new(message).fg(self.palette.muted),
+                    ));
+                }
+                output.push_str(&self.fmt_end(self.palette.active, true));
 
-                self.prev_lines = wrap_text(&strip_str(&output), term.size()?.width)
+                self.prev_output = strip_str(&output);
+                self.prev_lines = wrap_text(&self.prev_output, term.size()?.width)
                     .lines()
                     .count() as u16;
+                self.body_offset = has_body.then(|| self.prev_lines.saturating_sub(body_line));
             }
 
-            PromptState::Error(msg) | PromptState::Fatal(msg) => {
-                let color = match payload.state {
-                    PromptState::Error(_) => Color::Yellow,
-                    PromptState::Fatal(_) => Color::Red,
+            PromptState::Error(msg) | PromptState::Warning(msg) | PromptState::Fatal(msg) => {
+                let (icon, color) = match payload.state {
+                    PromptState::Error(_) => (self.symbols.step_error, self.palette.error),
+                    PromptState::Warning(_) => (self.symbols.warn, self.palette.warning),
+                    PromptState::Fatal(_) => (self.symbols.step_error, self.palette.fatal),
                     _ => unreachable!(),
                 };
 
                 let mut out = String::new();
 
-                out.push_str(&self.fmt_message(
-                    Styled::new(S_STEP_ERROR).fg(color),
-                    Styled::new(payload.message).bold(),
+                out.push_str(&self.fmt_message_with_hint(
+                    Styled::new(icon).fg(color),
+                    color,
+                    payload.message,
                     payload.hint,
+                    term.size()?.width,
                 ));
 
+                out.push_str(&self.fmt_description(color, payload.description));
+
                 out.push_str(&self.fmt_input_active(color, payload.input, payload.placeholder));
 
+                let body_line = wrap_text(&strip_str(&out), term.size()?.width)
+                    .lines()
+                    .count() as u16;
+                let has_body = !matches!(payload.body, PromptBody::None);
+                out.push_str(&self.fmt_page_indicator(payload.page_indicator_before));
                 out.push_str(&self.fmt_body_active(color, payload.body));
+                out.push_str(&self.fmt_page_indicator(payload.page_indicator_after));
 
                 if out.lines().count() < 2 {
                     out.push_str(&self.fmt_line(color, ""));
@@ -282,36 +565,44 @@ impl<W: std::io::Write> Theme<W> for FancyTheme {
                     self.fmt_error(msg.clone()),
                 ));
 
-                self.prev_lines = wrap_text(&strip_str(&output), term.size()?.width)
+                self.prev_output = strip_str(&output);
+                self.prev_lines = wrap_text(&self.prev_output, term.size()?.width)
                     .lines()
                     .count() as u16;
+                self.body_offset = has_body.then(|| self.prev_lines.saturating_sub(body_line));
             }
 
             PromptState::Submit => {
-                output.push_str(&self.fmt_message(
-                    Styled::new(S_STEP_SUBMIT).fg(Color::Green),
-                    Styled::new(payload.message).bold(),
+                output.push_str(&self.fmt_message_with_hint(
+                    Styled::new(self.symbols.step_submit).fg(self.palette.submit),
+                    self.palette.muted,
+                    payload.message,
                     None,
+                    term.size()?.width,
                 ));
 
-                output.push_str(&self.fmt_input_submit(Color::DarkGrey, payload.input));
+                output.push_str(&self.fmt_input_submit(self.palette.muted, payload.input));
                 output.push_str(&self.fmt_body_submit(payload.body));
-                output.push_str(&self.fmt_end(Color::DarkGrey, false));
+                output.push_str(&self.fmt_end(self.palette.muted, false));
 
                 self.prev_lines = 0;
+                self.body_offset = None;
             }
 
             PromptState::Cancel => {
-                output.push_str(&self.fmt_message(
-                    Styled::new(S_STEP_SUBMIT).fg(Color::Yellow),
-                    Styled::new(payload.message).bold(),
+                output.push_str(&self.fmt_message_with_hint(
+                    Styled::new(self.symbols.step_submit).fg(self.palette.cancel),
+                    self.palette.muted,
+                    payload.message,
                     None,
+                    term.size()?.width,
                 ));
 
-                output.push_str(&self.fmt_input_submit(Color::Yellow, payload.input));
-                output.push_str(&self.fmt_end(Color::Yellow, false));
+                output.push_str(&self.fmt_input_submit(self.palette.cancel, payload.input));
+                output.push_str(&self.fmt_end(self.palette.cancel, false));
 
                 self.prev_lines = 0;
+                self.body_offset = None;
             }
         }
 
@@ -321,6 +612,36 @@ impl<W: std::io::Write> Theme<W> for FancyTheme {
         Ok(())
     }
 
+    fn frame(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        frame: String,
+        message: String,
+    ) -> Result<(), Error> {
+        term.write(
+            self.fmt_message(Styled::new(frame).fg(self.palette.active), message, None)
+                .trim_end(),
+        )?;
+        term.flush()?;
+        Ok(())
+    }
+
+    fn resize(
+        &mut self,
+        _term: &mut dyn Terminal<W>,
+        width: u16,
+        _height: u16,
+    ) -> Result<(), Error> {
+        if self.prev_lines > 0 {
+            self.prev_lines = wrap_text(&self.prev_output, width).lines().count() as u16;
+        }
+        Ok(())
+    }
+
+    fn body_offset(&self) -> Option<u16> {
+        self.body_offset
+    }
+
     fn finish(
         &mut self,
         term: &mut dyn Terminal<W>,
@@ -331,17 +652,30 @@ impl<W: std::io::Write> Theme<W> for FancyTheme {
 
         match state {
             PromptState::Cancel => {
-                term.writeln(&self.fmt_line_with(
-                    Styled::new(S_BAR_END).fg(Color::Yellow),
-                    Styled::new(format!("Operation canceled {}", S_WARN)).fg(Color::Yellow),
-                ))?;
+                term.writeln(
+                    &self.fmt_line_with(
+                        Styled::new(self.symbols.bar_end).fg(self.palette.cancel),
+                        Styled::new(format!("Operation canceled {}", self.symbols.warn))
+                            .fg(self.palette.cancel),
+                    ),
+                )?;
             }
             _ => {
                 if let Some(outro) = outro {
-                    term.writeln(&self.fmt_line_with(
-                        Styled::new(S_BAR_END).fg(Color::DarkGrey),
-                        Styled::new(outro).bold(),
-                    ))?;
+                    let width = term.size()?.width;
+                    let mut output = String::new();
+                    for (i, line) in self.wrap_lines(&outro, width).iter().enumerate() {
+                        // Continuation lines are indented, since the bar ends at the first line.
+                        let symbol = if i == 0 {
+                            Styled::new(self.symbols.bar_end)
+                                .fg(self.palette.muted)
+                                .to_string()
+                        } else {
+                            " ".into()
+                        };
+                        output.push_str(&self.fmt_line_with(symbol, Styled::new(line).bold()));
+                    }
+                    term.writeln(&output)?;
                 }
             }
         }
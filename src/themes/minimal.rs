@@ -1,6 +1,8 @@
 use strip_ansi_escapes::strip_str;
+use unicode_width::UnicodeWidthStr;
 
 use crate::style::*;
+use crate::themes::ThemePalette;
 use crate::{Error, InputCursor, PromptBody, PromptInput, PromptState, Terminal, Theme};
 
 const S_STEP_ACTIVE: Symbol = Symbol("?", "?");
@@ -16,20 +18,56 @@ const S_SUCCESS: Symbol = Symbol("✓", "√");
 /// A Theme that offers a compact and minimalistic display.
 pub struct MinimalTheme {
     prev_lines: u16,
+    prev_output: String,
+    body_offset: Option<u16>,
+    right_align_hint: bool,
+    palette: ThemePalette,
 }
 
 impl MinimalTheme {
+    /// Creates a new [`MinimalTheme`].
     pub fn new() -> Self {
-        Self { prev_lines: 0 }
+        Self {
+            prev_lines: 0,
+            prev_output: String::new(),
+            body_offset: None,
+            right_align_hint: false,
+            palette: ThemePalette::default(),
+        }
+    }
+
+    /// Sets whether to right-align the hint to the terminal width.  
+    /// If the terminal is too narrow, the hint is placed at the default position.
+    pub fn with_right_align_hint(&mut self, right_align_hint: bool) -> &mut Self {
+        self.right_align_hint = right_align_hint;
+        self
+    }
+
+    /// Sets the colors of the theme.
+    pub fn with_palette(&mut self, palette: ThemePalette) -> &mut Self {
+        self.palette = palette;
+        self
     }
 
     fn fmt_message(&self, icon: impl std::fmt::Display, message: impl std::fmt::Display) -> String {
         format!("{} {}", icon, message)
     }
 
-    fn fmt_hint(&self, hint: Option<String>) -> String {
-        hint.map(|hint| format!("\n  {}", Styled::new(hint).fg(Color::DarkGrey),))
-            .unwrap_or_default()
+    fn fmt_hint(&self, hint: Option<String>, width: u16) -> String {
+        hint.map(|hint| {
+            let hint = truncate_to_width(&hint, width.saturating_sub(2));
+            let pad = if self.right_align_hint {
+                (width as usize).saturating_sub(strip_str(&hint).width())
+            } else {
+                2
+            };
+            format!(
+                "\n{}{}",
+                " ".repeat(std::cmp::max(pad, 2)),
+                Styled::new(hint).fg(self.palette.muted),
+            )
+        })
+        .unwrap_or_default()
     }
 
     fn fmt_cursor(&self, cursor: InputCursor) -> String {
@@ -43,7 +81,7 @@ impl MinimalTheme {
         format!(
             "{}{}",
             Styled::new(cursor).rev(),
-            Styled::new(right).fg(Color::DarkGrey),
+            Styled::new(right).fg(self.palette.muted),
         )
     }
 
@@ -75,34 +113,59 @@ impl MinimalTheme {
 
     fn fmt_input_submit(&self, input: PromptInput) -> String {
         match input {
-            PromptInput::Raw(s) => self.fmt_input_layout(Styled::new(s).fg(Color::Cyan)),
-            PromptInput::Cursor(c) => self.fmt_input_layout(Styled::new(c.value()).fg(Color::Cyan)),
+            PromptInput::Raw(s) => self.fmt_input_layout(Styled::new(s).fg(self.palette.active)),
+            PromptInput::Cursor(c) => {
+                self.fmt_input_layout(Styled::new(c.value()).fg(self.palette.active))
+            }
             _ => String::new(),
         }
     }
 
+    fn fmt_description(&self, description: Option<String>) -> String {
+        description.map_or_else(String::new, |description| {
+            description
+                .lines()
+                .map(|line| format!("\n  {}", Styled::new(line).fg(self.palette.muted)))
+                .collect::<Vec<_>>()
+                .join("")
+        })
+    }
+
     fn fmt_body_active(&self, body: PromptBody) -> String {
-        match body {
-            PromptBody::Raw(s) => {
+        match body.visible_content() {
+            Some(s) => {
                 format!("\n{}", s)
             }
-            _ => String::new(),
+            None => String::new(),
         }
     }
 
+    fn fmt_page_indicator(&self, indicator: Option<String>) -> String {
+        indicator.map_or_else(String::new, |indicator| format!("\n  {}", indicator))
+    }
+
     fn fmt_body_submit(&self, body: PromptBody) -> String {
-        match body {
-            PromptBody::Raw(s) => {
-                format!("\n{}", Styled::new(s).fg(Color::DarkGrey))
+        match body.visible_content() {
+            Some(s) => {
+                format!("\n{}", Styled::new(s).fg(self.palette.muted))
             }
-            _ => String::new(),
+            None => String::new(),
         }
     }
 
+    fn fmt_validation_hint(&self, message: Option<String>) -> String {
+        message.map_or_else(String::new, |message| {
+            format!(
+                "\n{}",
+                Styled::new(format!("{} {}", S_ERROR_BAR, message)).fg(self.palette.muted),
+            )
+        })
+    }
+
     fn fmt_error(&self, message: String) -> String {
         format!(
             "\n{}",
-            Styled::new(format!("{} {}", S_ERROR_BAR, message)).fg(Color::Yellow),
+            Styled::new(format!("{} {}", S_ERROR_BAR, message)).fg(self.palette.error),
         )
     }
 }
@@ -123,35 +186,59 @@ impl<W: std::io::Write> Theme<W> for MinimalTheme {
     fn info(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
         self.log(
             term,
-            format!("{} {}", Styled::new(S_INFO).fg(Color::Cyan), message),
+            format!("{} {}", Styled::new(S_INFO).fg(self.palette.info), message),
         )
     }
 
     fn warn(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
         self.log(
             term,
-            format!("{} {}", Styled::new(S_WARN).fg(Color::Yellow), message),
+            format!(
+                "{} {}",
+                Styled::new(S_WARN).fg(self.palette.warning),
+                message
+            ),
         )
     }
 
     fn error(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
         self.log(
             term,
-            format!("{} {}", Styled::new(S_ERROR).fg(Color::Red), message),
+            format!(
+                "{} {}",
+                Styled::new(S_ERROR).fg(self.palette.fatal),
+                message
+            ),
         )
     }
 
     fn success(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
         self.log(
             term,
-            format!("{} {}", Styled::new(S_SUCCESS).fg(Color::Green), message),
+            format!(
+                "{} {}",
+                Styled::new(S_SUCCESS).fg(self.palette.success),
+                message
+            ),
         )
     }
 
     fn step(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.step_with_hint(term, message, None)
+    }
+
+    fn step_with_hint(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        message: String,
+        hint: Option<String>,
+    ) -> Result<(), Error> {
+        let hint = hint
+            .map(|hint| format!(" {}", Styled::new(hint).fg(self.palette.muted)))
+            .unwrap_or_default();
         term.writeln(&self.fmt_message(
-            Styled::new(S_STEP_SUBMIT).fg(Color::Green),
-            Styled::new(message).bold(),
+            Styled::new(S_STEP_SUBMIT).fg(self.palette.submit),
+            format!("{}{}", Styled::new(message).bold(), hint),
         ))?;
         term.flush()?;
         Ok(())
@@ -178,44 +265,64 @@ impl<W: std::io::Write> Theme<W> for MinimalTheme {
         match payload.state {
             PromptState::Active => {
                 output.push_str(&self.fmt_message(
-                    Styled::new(S_STEP_ACTIVE).fg(Color::Cyan),
+                    Styled::new(S_STEP_ACTIVE).fg(self.palette.active),
                     Styled::new(payload.message).bold(),
                 ));
 
                 output.push_str(&self.fmt_input_active(payload.input, payload.placeholder));
+                output.push_str(&self.fmt_description(payload.description));
+                let body_line = wrap_text(&strip_str(&output), term.size()?.width)
+                    .lines()
+                    .count() as u16;
+                let has_body = !matches!(payload.body, PromptBody::None);
+                output.push_str(&self.fmt_page_indicator(payload.page_indicator_before));
                 output.push_str(&self.fmt_body_active(payload.body));
-                output.push_str(&self.fmt_hint(payload.hint));
+                output.push_str(&self.fmt_page_indicator(payload.page_indicator_after));
+                output.push_str(&self.fmt_validation_hint(payload.validation_hint));
+                output.push_str(&self.fmt_hint(payload.hint, term.size()?.width));
 
-                self.prev_lines = wrap_text(&strip_str(&output), term.size()?.width)
+                self.prev_output = strip_str(&output);
+                self.prev_lines = wrap_text(&self.prev_output, term.size()?.width)
                     .lines()
                     .count() as u16;
+                self.body_offset = has_body.then(|| self.prev_lines.saturating_sub(body_line));
             }
 
-            PromptState::Error(msg) | PromptState::Fatal(msg) => {
-                let color = match payload.state {
-                    PromptState::Error(_) => Color::Yellow,
-                    PromptState::Fatal(_) => Color::Red,
+            PromptState::Error(msg) | PromptState::Warning(msg) | PromptState::Fatal(msg) => {
+                let (icon, color) = match payload.state {
+                    PromptState::Error(_) => (S_STEP_ERROR, self.palette.error),
+                    PromptState::Warning(_) => (S_WARN, self.palette.warning),
+                    PromptState::Fatal(_) => (S_STEP_ERROR, self.palette.fatal),
                     _ => unreachable!(),
                 };
 
                 output.push_str(&self.fmt_message(
-                    Styled::new(S_STEP_ERROR).fg(color),
+                    Styled::new(icon).fg(color),
                     Styled::new(payload.message).bold(),
                 ));
 
                 output.push_str(&self.fmt_input_active(payload.input, payload.placeholder));
+                output.push_str(&self.fmt_description(payload.description));
+                let body_line = wrap_text(&strip_str(&output), term.size()?.width)
+                    .lines()
+                    .count() as u16;
+                let has_body = !matches!(payload.body, PromptBody::None);
+                output.push_str(&self.fmt_page_indicator(payload.page_indicator_before));
                 output.push_str(&self.fmt_body_active(payload.body));
+                output.push_str(&self.fmt_page_indicator(payload.page_indicator_after));
                 output.push_str(&self.fmt_error(msg.clone()));
-                output.push_str(&self.fmt_hint(payload.hint));
+                output.push_str(&self.fmt_hint(payload.hint, term.size()?.width));
 
-                self.prev_lines = wrap_text(&strip_str(&output), term.size()?.width)
+                self.prev_output = strip_str(&output);
+                self.prev_lines = wrap_text(&self.prev_output, term.size()?.width)
                     .lines()
                     .count() as u16;
+                self.body_offset = has_body.then(|| self.prev_lines.saturating_sub(body_line));
             }
 
             PromptState::Submit => {
                 output.push_str(&self.fmt_message(
-                    Styled::new(S_STEP_SUBMIT).fg(Color::Green),
+                    Styled::new(S_STEP_SUBMIT).fg(self.palette.submit),
                     Styled::new(payload.message).bold(),
                 ));
 
@@ -223,15 +330,17 @@ impl<W: std::io::Write> Theme<W> for MinimalTheme {
                 output.push_str(&self.fmt_body_submit(payload.body));
 
                 self.prev_lines = 0;
+                self.body_offset = None;
             }
 
             PromptState::Cancel => {
                 output.push_str(&self.fmt_message(
-                    Styled::new(S_WARN).fg(Color::Yellow),
+                    Styled::new(S_WARN).fg(self.palette.cancel),
                     Styled::new(payload.message).bold(),
                 ));
 
                 self.prev_lines = 0;
+                self.body_offset = None;
             }
         }
 
@@ -241,13 +350,58 @@ impl<W: std::io::Write> Theme<W> for MinimalTheme {
         Ok(())
     }
 
+    fn frame(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        frame: String,
+        message: String,
+    ) -> Result<(), Error> {
+        term.write(&self.fmt_message(Styled::new(frame).fg(self.palette.active), message))?;
+        term.flush()?;
+        Ok(())
+    }
+
+    fn resize(
+        &mut self,
+        _term: &mut dyn Terminal<W>,
+        width: u16,
+        _height: u16,
+    ) -> Result<(), Error> {
+        if self.prev_lines > 0 {
+            self.prev_lines = wrap_text(&self.prev_output, width).lines().count() as u16;
+        }
+        Ok(())
+    }
+
+    fn body_offset(&self) -> Option<u16> {
+        self.body_offset
+    }
+
     fn finish(
         &mut self,
         term: &mut dyn Terminal<W>,
-        _: &crate::PromptState,
-        _: Option<String>,
+        state: &crate::PromptState,
+        outro: Option<String>,
     ) -> Result<(), Error> {
         term.cursor_show()?;
+
+        match state {
+            PromptState::Cancel => {
+                term.writeln(&self.fmt_message(
+                    Styled::new(S_WARN).fg(self.palette.cancel),
+                    Styled::new("Operation canceled").fg(self.palette.cancel),
+                ))?;
+            }
+            _ => {
+                if let Some(outro) = outro {
+                    term.writeln(&self.fmt_message(
+                        Styled::new(S_STEP_SUBMIT).fg(self.palette.submit),
+                        Styled::new(outro).bold(),
+                    ))?;
+                }
+            }
+        }
+
         term.flush()?;
         Ok(())
     }
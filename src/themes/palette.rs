@@ -0,0 +1,56 @@
+use crate::style::Color;
+
+/// The colors used by the built-in themes.
+///
+/// Defaults to the colors of the built-in design. Override only the fields you need with the struct update syntax.
+///
+/// # Examples
+///
+/// ```no_run
+/// use promptuity::style::Color;
+/// use promptuity::themes::{FancyTheme, ThemePalette};
+///
+/// let mut theme = FancyTheme::default();
+/// theme.with_palette(ThemePalette {
+///     active: Color::Magenta,
+///     submit: Color::Blue,
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemePalette {
+    /// The color of active prompts and highlighted text. Defaults to [`Color::Cyan`].
+    pub active: Color,
+    /// The color of submitted prompts and steps. Defaults to [`Color::Green`].
+    pub submit: Color,
+    /// The color of prompts with a validation error. Defaults to [`Color::Yellow`].
+    pub error: Color,
+    /// The color of prompts with a warning and of warning messages. Defaults to [`Color::Yellow`].
+    pub warning: Color,
+    /// The color of prompts with a fatal error and of error messages. Defaults to [`Color::Red`].
+    pub fatal: Color,
+    /// The color of cancelled prompts. Defaults to [`Color::Yellow`].
+    pub cancel: Color,
+    /// The color of secondary text such as hints, placeholders and descriptions. Defaults to [`Color::DarkGrey`].
+    pub muted: Color,
+    /// The color of info messages. Defaults to [`Color::Cyan`].
+    pub info: Color,
+    /// The color of success messages. Defaults to [`Color::Green`].
+    pub success: Color,
+}
+
+impl Default for ThemePalette {
+    fn default() -> Self {
+        Self {
+            active: Color::Cyan,
+            submit: Color::Green,
+            error: Color::Yellow,
+            warning: Color::Yellow,
+            fatal: Color::Red,
+            cancel: Color::Yellow,
+            muted: Color::DarkGrey,
+            info: Color::Cyan,
+            success: Color::Green,
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use strip_ansi_escapes::strip_str;
+
+use crate::{Error, IntroMetadata, PromptBody, PromptInput, PromptState, Terminal, Theme};
+
+/// A Theme that writes plain text without decorations, suitable for logs and non-TTY output.
+///
+/// Active prompts are not displayed. Each finished prompt is written as a single line such as `Username: abc`, with any ANSI escape sequences removed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::io::IsTerminal;
+///
+/// use promptuity::themes::{MinimalTheme, PlainTheme};
+/// use promptuity::{Promptuity, Term, Theme};
+///
+/// let mut term = Term::default();
+/// let mut theme: Box<dyn Theme<_>> = if std::io::stderr().is_terminal() {
+///     Box::new(MinimalTheme::default())
+/// } else {
+///     Box::new(PlainTheme::default())
+/// };
+/// let mut p = Promptuity::new(&mut term, theme.as_mut());
+/// ```
+#[derive(Default)]
+pub struct PlainTheme;
+
+impl PlainTheme {
+    /// Creates a new [`PlainTheme`].
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn fmt_value(&self, input: PromptInput, body: PromptBody) -> String {
+        let value = match input {
+            PromptInput::Raw(s) => s,
+            PromptInput::Cursor(c) => c.value(),
+            _ => body
+                .visible_content()
+                .map(|s| s.lines().map(str::trim).collect::<Vec<_>>().join(" "))
+                .unwrap_or_default(),
+        };
+        strip_str(value)
+    }
+
+    fn fmt_result(&self, message: &str, value: &str) -> String {
+        let message = strip_str(message);
+        if value.is_empty() {
+            message
+        } else {
+            format!("{}: {}", message, value)
+        }
+    }
+}
+
+impl<W: std::io::Write> Theme<W> for PlainTheme {
+    fn log(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        term.writeln(&strip_str(message))?;
+        term.flush()?;
+        Ok(())
+    }
+
+    fn info(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.log(term, format!("info: {}", message))
+    }
+
+    fn warn(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.log(term, format!("warning: {}", message))
+    }
+
+    fn error(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.log(term, format!("error: {}", message))
+    }
+
+    fn success(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.log(term, format!("success: {}", message))
+    }
+
+    fn step(&mut self, term: &mut dyn Terminal<W>, message: String) -> Result<(), Error> {
+        self.step_with_hint(term, message, None)
+    }
+
+    fn step_with_hint(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        message: String,
+        hint: Option<String>,
+    ) -> Result<(), Error> {
+        match hint {
+            Some(hint) => self.log(term, format!("{} ({})", message, hint)),
+            None => self.log(term, message),
+        }
+    }
+
+    fn begin(&mut self, term: &mut dyn Terminal<W>, intro: Option<String>) -> Result<(), Error> {
+        self.begin_with_intro(term, intro.map(IntroMetadata::new))
+    }
+
+    fn begin_with_intro(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        intro: Option<IntroMetadata>,
+    ) -> Result<(), Error> {
+        if let Some(intro) = intro {
+            let details = [intro.version.map(|v| format!("v{}", v)), intro.author]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            if details.is_empty() {
+                self.log(term, intro.title)?;
+            } else {
+                self.log(term, format!("{} ({})", intro.title, details.join(", ")))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn frame(&mut self, _: &mut dyn Terminal<W>, _: String, _: String) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        payload: crate::RenderSnapshot,
+    ) -> Result<(), Error> {
+        let line = match payload.state {
+            PromptState::Submit => {
+                let value = self.fmt_value(payload.input, payload.body);
+                self.fmt_result(&payload.message, &value)
+            }
+            PromptState::Fatal(msg) => {
+                self.fmt_result(&payload.message, &format!("error: {}", strip_str(msg)))
+            }
+            PromptState::Cancel if payload.back => self.fmt_result(&payload.message, "(back)"),
+            PromptState::Cancel => self.fmt_result(&payload.message, "(canceled)"),
+            PromptState::Active | PromptState::Error(_) | PromptState::Warning(_) => {
+                return Ok(());
+            }
+        };
+
+        term.writeln(&line)?;
+        term.flush()?;
+
+        Ok(())
+    }
+
+    fn finish(
+        &mut self,
+        term: &mut dyn Terminal<W>,
+        state: &PromptState,
+        outro: Option<String>,
+    ) -> Result<(), Error> {
+        match state {
+            PromptState::Cancel => self.log(term, "Operation canceled".into()),
+            _ => match outro {
+                Some(outro) => self.log(term, outro),
+                None => Ok(()),
+            },
+        }
+    }
+}
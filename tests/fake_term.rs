@@ -1,26 +1,88 @@
 use std::collections::VecDeque;
 use std::io::Write;
+use std::time::Duration;
 
 use promptuity::event::*;
 use promptuity::{CursorPosition, Error, TermSize, Terminal};
 
 pub struct Term {
     output: Vec<u8>,
-    actions: VecDeque<(KeyCode, KeyModifiers)>,
+    raw: bool,
+    raw_history: Vec<bool>,
+    mouse_history: Vec<bool>,
+    paste_history: Vec<bool>,
+    previous_line_history: Vec<u16>,
+    size: TermSize,
+    tty: bool,
+    actions: VecDeque<Event>,
 }
 
 impl Term {
     pub fn new(actions: &[(KeyCode, KeyModifiers)]) -> Self {
-        let actions = VecDeque::from(actions.to_vec());
+        let events = actions
+            .iter()
+            .map(|(code, modifiers)| Event::Key(KeyEvent::new(*code, *modifiers)))
+            .collect::<Vec<_>>();
+        Self::with_events(&events)
+    }
+
+    pub fn with_events(events: &[Event]) -> Self {
+        promptuity::style::Styled::set_color_override(Some(true));
+        let actions = VecDeque::from(events.to_vec());
         Self {
             output: vec![],
+            raw: false,
+            raw_history: vec![],
+            mouse_history: vec![],
+            paste_history: vec![],
+            previous_line_history: vec![],
+            size: TermSize::new(80, 40),
+            tty: true,
             actions,
         }
     }
 
+    #[allow(dead_code)]
+    pub fn with_size(mut self, width: u16, height: u16) -> Self {
+        self.size = TermSize::new(width, height);
+        self
+    }
+
+    /// Makes the terminal behave as if it were not a TTY, so that querying the size fails.
+    #[allow(dead_code)]
+    pub fn without_tty(mut self) -> Self {
+        self.tty = false;
+        self
+    }
+
     pub fn output(&self) -> String {
         String::from_utf8(self.output.clone()).unwrap()
     }
+
+    #[allow(dead_code)]
+    pub fn is_raw(&self) -> bool {
+        self.raw
+    }
+
+    #[allow(dead_code)]
+    pub fn raw_history(&self) -> &[bool] {
+        &self.raw_history
+    }
+
+    #[allow(dead_code)]
+    pub fn mouse_history(&self) -> &[bool] {
+        &self.mouse_history
+    }
+
+    #[allow(dead_code)]
+    pub fn paste_history(&self) -> &[bool] {
+        &self.paste_history
+    }
+
+    #[allow(dead_code)]
+    pub fn previous_line_history(&self) -> &[u16] {
+        &self.previous_line_history
+    }
 }
 
 impl Terminal<Vec<u8>> for Term {
@@ -29,14 +91,21 @@ impl Terminal<Vec<u8>> for Term {
     }
 
     fn size(&self) -> Result<TermSize, Error> {
-        Ok(TermSize::new(80, 40))
+        if !self.tty {
+            return Err(std::io::Error::from(std::io::ErrorKind::WouldBlock).into());
+        }
+        Ok(self.size)
     }
 
     fn enable_raw(&mut self) -> Result<(), Error> {
+        self.raw = true;
+        self.raw_history.push(true);
         Ok(())
     }
 
     fn disable_raw(&mut self) -> Result<(), Error> {
+        self.raw = false;
+        self.raw_history.push(false);
         Ok(())
     }
 
@@ -48,8 +117,28 @@ impl Terminal<Vec<u8>> for Term {
         Ok(())
     }
 
+    fn enable_mouse_capture(&mut self) -> Result<(), Error> {
+        self.mouse_history.push(true);
+        Ok(())
+    }
+
+    fn disable_mouse_capture(&mut self) -> Result<(), Error> {
+        self.mouse_history.push(false);
+        Ok(())
+    }
+
+    fn enable_bracketed_paste(&mut self) -> Result<(), Error> {
+        self.paste_history.push(true);
+        Ok(())
+    }
+
+    fn disable_bracketed_paste(&mut self) -> Result<(), Error> {
+        self.paste_history.push(false);
+        Ok(())
+    }
+
     fn cursor_pos(&self) -> Result<CursorPosition, Error> {
-        todo!()
+        Ok(CursorPosition::new(0, 20))
     }
 
     fn move_to(&mut self, _: u16, _: u16) -> Result<(), Error> {
@@ -64,7 +153,8 @@ impl Terminal<Vec<u8>> for Term {
         Ok(())
     }
 
-    fn move_previous_line(&mut self, _: u16) -> Result<(), Error> {
+    fn move_previous_line(&mut self, to: u16) -> Result<(), Error> {
+        self.previous_line_history.push(to);
         Ok(())
     }
 
@@ -96,6 +186,10 @@ impl Terminal<Vec<u8>> for Term {
         Ok(())
     }
 
+    fn set_title(&mut self, title: &str) -> Result<(), Error> {
+        self.write(&format!("\x1b]0;{}\x07", title))
+    }
+
     fn write(&mut self, value: &str) -> Result<(), Error> {
         self.output.write_all(value.as_bytes())?;
         Ok(())
@@ -114,6 +208,44 @@ impl Terminal<Vec<u8>> for Term {
     }
 
     fn read_key(&mut self) -> Result<(KeyCode, KeyModifiers), Error> {
-        Ok(self.actions.pop_front().unwrap())
+        let event = self.read_key_event()?;
+        Ok((event.code, event.modifiers))
+    }
+
+    fn read_key_event(&mut self) -> Result<KeyEvent, Error> {
+        match self.actions.pop_front().unwrap() {
+            Event::Key(event) => Ok(event),
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+
+    fn read_event(&mut self) -> Result<Event, Error> {
+        let event = self.actions.pop_front().unwrap();
+        if let Event::Resize(width, height) = event {
+            self.size = TermSize::new(width, height);
+        }
+        Ok(event)
+    }
+
+    fn read_key_timeout(
+        &mut self,
+        _timeout: Duration,
+    ) -> Result<Option<(KeyCode, KeyModifiers)>, Error> {
+        if self.actions.is_empty() {
+            return Ok(None);
+        }
+        self.read_key().map(Some)
+    }
+
+    // `Event::FocusGained` is consumed as a timeout, simulating a quiet period between inputs.
+    fn read_event_timeout(&mut self, _timeout: Duration) -> Result<Option<Event>, Error> {
+        match self.actions.front() {
+            None => Ok(None),
+            Some(Event::FocusGained) => {
+                self.actions.pop_front();
+                Ok(None)
+            }
+            Some(_) => self.read_event().map(Some),
+        }
     }
 }
@@ -1,10 +1,17 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
 use pretty_assertions::assert_eq;
 use promptuity::event::*;
 use promptuity::prompts::{
-    Confirm, Input, MultiSelect, MultiSelectOption, Number, Select, SelectOption,
+    Confirm, Input, MultiSelect, MultiSelectOption, Number, Select, SelectOption, Spinner,
+};
+use promptuity::themes::{FancyTheme, MinimalTheme, PlainTheme};
+use promptuity::{
+    Error, Flow, KeyBinding, Prompt, PromptBody, PromptInput, PromptState, Promptuity,
+    RenderPayload, StepCounterMiddleware, TruncateMiddleware, ValidationError,
 };
-use promptuity::themes::MinimalTheme;
-use promptuity::Promptuity;
 
 mod fake_term;
 
@@ -61,7 +68,9 @@ fn test_prompts() {
         let mut p = Promptuity::new(&mut term, &mut theme);
         let input = p.prompt(Input::new("Input Message").as_mut()).unwrap();
         let password = p.prompt(Input::new("Password Message").as_mut()).unwrap();
-        let number = p.prompt(Number::new("Number Message").as_mut()).unwrap();
+        let number = p
+            .prompt(Number::new("Number Message").with_allow_sign_prefix(true))
+            .unwrap();
         let select = p
             .prompt(
                 Select::new(
@@ -117,3 +126,863 @@ fn test_prompts() {
         insta::assert_snapshot!(output);
     });
 }
+
+#[test]
+fn test_exit_code() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+        (KeyCode::Esc, KeyModifiers::NONE),
+    ]);
+
+    let mut theme = MinimalTheme::default();
+    let mut p = Promptuity::new(&mut term, &mut theme);
+    p.with_cancel_exit_code(2);
+
+    p.prompt(Input::new("Input Message").as_mut()).unwrap();
+    assert_eq!(p.exit_code(), 0);
+
+    let result = p.prompt(Input::new("Input Message").as_mut());
+    assert!(matches!(result, Err(Error::Cancel)));
+    assert_eq!(p.exit_code(), 2);
+}
+
+struct WarnPrompt {
+    value: String,
+}
+
+impl Prompt for WarnPrompt {
+    type Output = String;
+
+    fn handle(&mut self, code: KeyCode, _: KeyModifiers) -> PromptState {
+        match code {
+            KeyCode::Enter => PromptState::Submit,
+            KeyCode::Char(c) => {
+                self.value.push(c);
+                PromptState::Active
+            }
+            _ => PromptState::Active,
+        }
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        self.value.clone()
+    }
+
+    fn render(&mut self, _: &PromptState) -> Result<RenderPayload, String> {
+        Ok(RenderPayload::new("Warn Message".into(), None, None)
+            .input(PromptInput::Raw(self.value.clone())))
+    }
+
+    fn warn(&self) -> Option<String> {
+        if self.value.len() < 3 {
+            Some("Value is short.".into())
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn test_input_terminal_width() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Home, KeyModifiers::NONE),
+        (KeyCode::Right, KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ])
+    .with_size(20, 40);
+    let mut theme = MinimalTheme::default();
+    let value = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWX";
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.begin().unwrap();
+
+        let result = p.prompt(Input::new("Name").with_default(value).as_mut());
+        assert_eq!(result.unwrap(), value);
+
+        p.finish().unwrap();
+    }
+
+    insta::with_settings!({ omit_expression => true }, {
+        insta::assert_snapshot!(term.output());
+    });
+}
+
+#[test]
+fn test_warning() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ]);
+
+    let mut theme = FancyTheme::default();
+
+    let result = {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.prompt(&mut WarnPrompt {
+            value: String::new(),
+        })
+        .unwrap()
+    };
+
+    assert_eq!(result, "a");
+
+    insta::with_settings!({ omit_expression => true }, {
+        insta::assert_snapshot!(term.output());
+    });
+}
+
+#[test]
+fn test_accessibility_mode() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Char('b'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ]);
+
+    let mut theme = MinimalTheme::default();
+    let mut log = Vec::new();
+
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_accessibility_mode(true)
+            .with_accessibility_log(&mut log);
+        p.begin().unwrap();
+        p.prompt(Input::new("Input Message").as_mut()).unwrap();
+        p.finish().unwrap();
+    }
+
+    let output = term.output();
+    assert!(output.starts_with("Prompt session started\n"));
+    assert!(output.contains("\x1b]0;Input Message\x07"));
+    assert_eq!(
+        String::from_utf8(log).unwrap(),
+        "Input Message\nInput Message: a\nInput Message: ab\n"
+    );
+}
+
+#[test]
+fn test_render_middleware() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+        (KeyCode::Char('b'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ]);
+
+    let mut theme = MinimalTheme::default();
+
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_render_middleware(StepCounterMiddleware::new(2))
+            .with_render_middleware(TruncateMiddleware(20));
+        p.prompt(Input::new("First Message").as_mut()).unwrap();
+        p.prompt(Input::new("Second Message That Is Long").as_mut())
+            .unwrap();
+    }
+
+    insta::with_settings!({ omit_expression => true }, {
+        insta::assert_snapshot!(term.output());
+    });
+}
+
+#[test]
+fn test_interrupted_session() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ]);
+    let mut theme = MinimalTheme::default();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.begin().unwrap();
+        p.prompt(
+            Input::new("Input Message")
+                .with_validator(|_: &String| -> Result<(), String> { panic!("unexpected") })
+                .as_mut(),
+        )
+        .unwrap();
+    }));
+
+    assert!(result.is_err());
+    assert!(!term.is_raw());
+    assert!(term.output().ends_with("\nPrompt session interrupted\n"));
+}
+
+#[test]
+fn test_finished_session() {
+    let mut term = fake_term::Term::new(&[]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.begin().unwrap();
+        p.finish().unwrap();
+    }
+
+    assert!(!term.is_raw());
+    assert!(!term.output().contains("Prompt session interrupted"));
+}
+
+#[test]
+fn test_finish_on_cancel() {
+    let mut term = fake_term::Term::new(&[(KeyCode::Esc, KeyModifiers::NONE)]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_outro("Outro Message").begin().unwrap();
+        let result = p.prompt(Input::new("Input Message").as_mut());
+        assert!(matches!(result, Err(Error::Cancel)));
+    }
+
+    let output = term.output();
+    assert!(!term.is_raw());
+    assert!(output.contains("Operation canceled"));
+    assert!(!output.contains("Prompt session interrupted"));
+}
+
+#[test]
+fn test_no_finish_on_cancel() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Esc, KeyModifiers::NONE),
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_finish_on_cancel(false)
+            .with_outro("Outro Message")
+            .begin()
+            .unwrap();
+        let result = p.prompt(Input::new("Input Message").as_mut());
+        assert!(matches!(result, Err(Error::Cancel)));
+
+        let result = p.prompt(Input::new("Input Message").as_mut());
+        assert_eq!(result.unwrap(), "a");
+        p.finish().unwrap();
+    }
+
+    let output = term.output();
+    assert!(!term.is_raw());
+    assert!(output.contains("Outro Message"));
+    assert!(!output.contains("Prompt session interrupted"));
+}
+
+#[test]
+fn test_no_finish_on_cancel_dropped() {
+    let mut term = fake_term::Term::new(&[(KeyCode::Esc, KeyModifiers::NONE)]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_finish_on_cancel(false).begin().unwrap();
+        let result = p.prompt(Input::new("Input Message").as_mut());
+        assert!(matches!(result, Err(Error::Cancel)));
+    }
+
+    let output = term.output();
+    assert!(!term.is_raw());
+    assert!(!output.contains("Operation canceled"));
+    assert!(!output.contains("Prompt session interrupted"));
+}
+
+#[test]
+fn test_no_finish_on_cancel_interrupted() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Esc, KeyModifiers::NONE),
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ]);
+    let mut theme = MinimalTheme::default();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_finish_on_cancel(false).begin().unwrap();
+        let result = p.prompt(Input::new("Input Message").as_mut());
+        assert!(matches!(result, Err(Error::Cancel)));
+
+        p.prompt(
+            Input::new("Input Message")
+                .with_validator(|_: &String| -> Result<(), String> { panic!("unexpected") })
+                .as_mut(),
+        )
+        .unwrap();
+    }));
+
+    assert!(result.is_err());
+    assert!(!term.is_raw());
+    assert!(term.output().ends_with("\nPrompt session interrupted\n"));
+}
+
+struct ConfigPrompt;
+
+impl Prompt for ConfigPrompt {
+    type Output = ();
+
+    fn name(&self) -> &'static str {
+        "ConfigPrompt"
+    }
+
+    fn setup(&mut self) -> Result<(), Error> {
+        Err(Error::Config {
+            message: "value is invalid.".into(),
+            field: Some("value".into()),
+            prompt: None,
+            source: Some("abc".parse::<u8>().unwrap_err().into()),
+        })
+    }
+
+    fn handle(&mut self, _: KeyCode, _: KeyModifiers) -> PromptState {
+        PromptState::Submit
+    }
+
+    fn submit(&mut self) -> Self::Output {}
+
+    fn render(&mut self, _: &PromptState) -> Result<RenderPayload, String> {
+        Ok(RenderPayload::new("Config Message".into(), None, None))
+    }
+}
+
+#[test]
+fn test_config_error_prompt_name() {
+    let mut term = fake_term::Term::new(&[]);
+    let mut theme = MinimalTheme::default();
+    let mut p = Promptuity::new(&mut term, &mut theme);
+
+    let err = p.prompt(&mut ConfigPrompt).unwrap_err();
+    assert!(matches!(
+        &err,
+        Error::Config { field: Some(field), prompt: Some(prompt), .. }
+            if field == "value" && prompt == "ConfigPrompt"
+    ));
+    assert_eq!(
+        err.to_string(),
+        "Config error: ConfigPrompt.value: value is invalid."
+    );
+    assert!(std::error::Error::source(&err).is_some());
+
+    let err = p
+        .prompt(Select::<usize>::new("Select Message", vec![]).as_mut())
+        .unwrap_err();
+    assert!(matches!(
+        &err,
+        Error::Config { message, field: Some(field), prompt: Some(prompt), .. }
+            if message == "options cannot be empty." && field == "options" && prompt == "Select"
+    ));
+}
+
+#[test]
+fn test_spinner() {
+    let mut term = fake_term::Term::new(&[]);
+
+    let mut spinner = Spinner::new("Loading...");
+    spinner.start(&mut term).unwrap();
+    spinner.tick(&mut term).unwrap();
+    spinner.set_message("Still loading...");
+    spinner.tick(&mut term).unwrap();
+    spinner.finish_with_message(&mut term, "Loaded").unwrap();
+
+    let mut spinner = Spinner::new("Loading...");
+    spinner.start(&mut term).unwrap();
+    spinner.fail(&mut term, "Failed to load").unwrap();
+
+    insta::with_settings!({ omit_expression => true }, {
+        insta::assert_snapshot!(term.output());
+    });
+}
+
+#[test]
+fn test_raw_mode_guard() {
+    let mut term = fake_term::Term::new(&[]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        let guard = p.raw_mode_guard().unwrap();
+        drop(guard);
+        p.finish().unwrap();
+    }
+    assert_eq!(term.raw_history(), &[true, false, false]);
+    assert!(!term.is_raw());
+}
+
+#[test]
+fn test_raw_mode_guard_in_session() {
+    let mut term = fake_term::Term::new(&[(KeyCode::Enter, KeyModifiers::NONE)]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.begin().unwrap();
+        {
+            let mut guard = p.raw_mode_guard().unwrap();
+            assert!(guard.term().read_key().is_ok());
+        }
+        p.finish().unwrap();
+    }
+    assert_eq!(term.raw_history(), &[true, true, false]);
+}
+
+struct ScrollPrompt {
+    handled: usize,
+}
+
+impl Prompt for ScrollPrompt {
+    type Output = usize;
+
+    fn handle(&mut self, code: KeyCode, _: KeyModifiers) -> PromptState {
+        self.handled += 1;
+        match code {
+            KeyCode::Enter => PromptState::Submit,
+            _ => PromptState::Active,
+        }
+    }
+
+    fn submit(&mut self) -> Self::Output {
+        self.handled
+    }
+
+    fn render(&mut self, _: &PromptState) -> Result<RenderPayload, String> {
+        Ok(
+            RenderPayload::new("Scroll Message".into(), None, None).body(PromptBody::Scrollable {
+                content: "Line1\nLine2\nLine3\nLine4\nLine5".into(),
+                scroll_offset: 0,
+                visible_lines: 2,
+            }),
+        )
+    }
+}
+
+#[test]
+fn test_scrollable_body() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Down, KeyModifiers::SHIFT),
+        (KeyCode::Down, KeyModifiers::SHIFT),
+        (KeyCode::Down, KeyModifiers::SHIFT),
+        (KeyCode::Down, KeyModifiers::SHIFT),
+        (KeyCode::Up, KeyModifiers::ALT),
+        (KeyCode::Down, KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ]);
+    let mut theme = MinimalTheme::default();
+
+    let handled = {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.prompt(&mut ScrollPrompt { handled: 0 }).unwrap()
+    };
+
+    assert_eq!(handled, 2);
+
+    insta::with_settings!({ omit_expression => true }, {
+        insta::assert_snapshot!(term.output());
+    });
+}
+
+#[test]
+fn test_non_interactive() {
+    let mut term = fake_term::Term::new(&[]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_non_interactive(true).begin().unwrap();
+
+        let name = p.prompt(Input::new("Name").with_default("foo"));
+        assert_eq!(name.unwrap(), "foo");
+
+        let confirm = p.prompt(Confirm::new("Continue?").with_default(true));
+        assert!(confirm.unwrap());
+
+        let mut invalid = Input::new("Invalid");
+        invalid
+            .with_default("bar")
+            .with_validator(|_: &String| Err("Invalid value"));
+        let result = p.prompt(&mut invalid);
+        assert!(matches!(result, Err(Error::Prompt { message, .. }) if message == "Invalid value"));
+
+        let result = p.prompt_with_default(&mut invalid, "fallback".into());
+        assert_eq!(result.unwrap(), "fallback");
+
+        p.finish().unwrap();
+    }
+
+    let output = strip_ansi_escapes::strip_str(term.output());
+    assert!(!term.raw_history().contains(&true));
+    assert!(output.contains("Name  foo"));
+    assert!(output.contains("Continue?  Yes"));
+}
+
+#[test]
+fn test_validator_fatal() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+        (KeyCode::Char('b'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.begin().unwrap();
+
+        let result =
+            p.prompt(
+                Input::new("Input Message").with_validator(|v: &String| match v.as_str() {
+                    "a" => Err(ValidationError::new("Recoverable")),
+                    _ => Err(ValidationError::fatal("Unrecoverable")),
+                }),
+            );
+        assert!(matches!(result, Err(Error::Prompt { message, .. }) if message == "Unrecoverable"));
+    }
+
+    let output = strip_ansi_escapes::strip_str(term.output());
+    assert!(output.contains("Recoverable"));
+    assert!(output.contains("Unrecoverable"));
+    assert!(!term.raw_history().last().unwrap());
+}
+
+#[test]
+fn test_non_interactive_without_tty() {
+    let mut term = fake_term::Term::new(&[]).without_tty();
+    let mut theme = PlainTheme::new();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_non_interactive(true).begin().unwrap();
+
+        let name = p.prompt(Input::new("Name").with_default("foo"));
+        assert_eq!(name.unwrap(), "foo");
+
+        p.finish().unwrap();
+    }
+    assert_eq!(term.output(), "Name: foo\n");
+}
+
+#[test]
+fn test_prompt_with_default_cancel() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Esc, KeyModifiers::NONE),
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.begin().unwrap();
+
+        let result = p.prompt_with_default(Input::new("Input Message").as_mut(), "default".into());
+        assert_eq!(result.unwrap(), "default");
+
+        let result = p.prompt(Input::new("Input Message").as_mut());
+        assert_eq!(result.unwrap(), "a");
+        p.finish().unwrap();
+    }
+
+    assert!(!term.output().contains("Operation canceled"));
+}
+
+#[test]
+fn test_timeout_cancel() {
+    let mut term = fake_term::Term::new(&[(KeyCode::Char('a'), KeyModifiers::NONE)]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_timeout(Duration::from_secs(1)).begin().unwrap();
+
+        let result = p.prompt(Input::new("Input Message").as_mut());
+        assert!(matches!(result, Err(Error::Cancel)));
+    }
+
+    assert!(!term.is_raw());
+}
+
+#[test]
+fn test_timeout_submit() {
+    let mut term = fake_term::Term::new(&[(KeyCode::Char('a'), KeyModifiers::NONE)]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_timeout(Duration::from_secs(1))
+            .with_submit_on_timeout(true)
+            .begin()
+            .unwrap();
+
+        let result = p.prompt(Input::new("Input Message").as_mut());
+        assert_eq!(result.unwrap(), "a");
+
+        let result = p.prompt(
+            Input::new("Input Message")
+                .with_validator(|value: &String| {
+                    if value.is_empty() {
+                        Err("Please enter a value.")
+                    } else {
+                        Ok(())
+                    }
+                })
+                .as_mut(),
+        );
+        assert!(matches!(result, Err(Error::Cancel)));
+    }
+}
+
+#[test]
+fn test_prompt_flow_back() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+        (KeyCode::BackTab, KeyModifiers::SHIFT),
+        (KeyCode::Char('b'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+        (KeyCode::Char('y'), KeyModifiers::NONE),
+    ]);
+    let mut theme = MinimalTheme::default();
+    let mut steps = Vec::new();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.begin().unwrap();
+
+        let mut step = 0;
+        while step < 2 {
+            let back = match step {
+                0 => match p.prompt_flow(Input::new("Name").as_mut()).unwrap() {
+                    Flow::Next(value) => {
+                        steps.push(value);
+                        false
+                    }
+                    Flow::Back => true,
+                },
+                _ => match p.prompt_flow(Confirm::new("Publish?").as_mut()).unwrap() {
+                    Flow::Next(value) => {
+                        steps.push(value.to_string());
+                        false
+                    }
+                    Flow::Back => true,
+                },
+            };
+            step = if back { step - 1 } else { step + 1 };
+        }
+        p.finish().unwrap();
+    }
+
+    assert_eq!(steps, vec!["a", "b", "true"]);
+    assert!(!term.output().contains("Operation canceled"));
+}
+
+#[test]
+fn test_prompt_back_keys() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::BackTab, KeyModifiers::SHIFT),
+        (KeyCode::Enter, KeyModifiers::NONE),
+        (KeyCode::BackTab, KeyModifiers::SHIFT),
+        (KeyCode::Tab, KeyModifiers::NONE),
+    ]);
+    let mut theme = MinimalTheme::default();
+    let mut p = Promptuity::new(&mut term, &mut theme);
+    p.with_back_keys(&[KeyBinding::any(KeyCode::Tab)]);
+    p.begin().unwrap();
+
+    let result = p.prompt(Input::new("Name").as_mut()).unwrap();
+    assert_eq!(result, "a");
+
+    let result = p.prompt_flow(Input::new("Name").as_mut()).unwrap();
+    assert_eq!(result, Flow::Back);
+}
+
+#[test]
+fn test_prompt_flow_back_render() {
+    let mut term = fake_term::Term::new(&[(KeyCode::BackTab, KeyModifiers::SHIFT)]);
+    let mut theme = PlainTheme::new();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.begin().unwrap();
+
+        let result = p.prompt_flow(Input::new("Name").as_mut()).unwrap();
+        assert_eq!(result, Flow::Back);
+
+        p.finish().unwrap();
+    }
+    assert!(term.output().starts_with("Name: (back)\n"));
+}
+
+#[test]
+fn test_cancel_keys() {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Esc, KeyModifiers::NONE),
+        (KeyCode::Char('a'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+        (KeyCode::Esc, KeyModifiers::NONE),
+        (KeyCode::Char('q'), KeyModifiers::CONTROL),
+    ]);
+    let mut theme = MinimalTheme::default();
+    let mut p = Promptuity::new(&mut term, &mut theme);
+    p.with_cancel_keys(&[KeyBinding::ctrl('q')])
+        .with_finish_on_cancel(false)
+        .begin()
+        .unwrap();
+
+    let result = p.prompt(Input::new("Input Message").as_mut());
+    assert_eq!(result.unwrap(), "a");
+
+    let result = p.prompt(Confirm::new("Confirm Message").as_mut());
+    assert!(matches!(result, Err(Error::Cancel)));
+}
+
+#[test]
+fn test_skip_key_release() {
+    let key = |code, kind| Event::Key(KeyEvent::new_with_kind(code, KeyModifiers::NONE, kind));
+    let mut term = fake_term::Term::with_events(&[
+        key(KeyCode::Char('a'), KeyEventKind::Press),
+        key(KeyCode::Char('a'), KeyEventKind::Release),
+        key(KeyCode::Char('b'), KeyEventKind::Press),
+        key(KeyCode::Char('b'), KeyEventKind::Repeat),
+        key(KeyCode::Char('b'), KeyEventKind::Release),
+        key(KeyCode::Enter, KeyEventKind::Press),
+    ]);
+    let mut theme = MinimalTheme::default();
+    let mut p = Promptuity::new(&mut term, &mut theme);
+    p.begin().unwrap();
+
+    let result = p.prompt(Input::new("Input Message").as_mut());
+    assert_eq!(result.unwrap(), "abb");
+}
+
+#[test]
+fn test_paste() {
+    let mut term = fake_term::Term::with_events(&[
+        Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)),
+        Event::Paste("b\tc\nd".into()),
+        Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+        Event::Paste("yes".into()),
+    ]);
+    let mut theme = MinimalTheme::default();
+    let mut p = Promptuity::new(&mut term, &mut theme);
+    p.begin().unwrap();
+
+    let result = p.prompt(Input::new("Input Message").as_mut());
+    assert_eq!(result.unwrap(), "ab    cd");
+
+    let result = p.prompt(Confirm::new("Confirm Message").as_mut());
+    assert!(result.unwrap());
+}
+
+#[test]
+fn test_paste_with_timeout() {
+    let mut term = fake_term::Term::with_events(&[
+        Event::Paste("abc".into()),
+        Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+    ]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_timeout(Duration::from_secs(10)).begin().unwrap();
+
+        let result = p.prompt(Input::new("Input Message").as_mut());
+        assert_eq!(result.unwrap(), "abc");
+
+        p.finish().unwrap();
+    }
+    assert_eq!(term.paste_history(), &[true, false]);
+}
+
+#[test]
+fn test_validation_debounce() {
+    let calls = Rc::new(Cell::new(0));
+    let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    let mut term = fake_term::Term::with_events(&[
+        key(KeyCode::Char('a')),
+        key(KeyCode::Char('b')),
+        Event::FocusGained,
+        key(KeyCode::Char('c')),
+        key(KeyCode::Enter),
+    ]);
+    let mut theme = PlainTheme::new();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.begin().unwrap();
+
+        let counter = Rc::clone(&calls);
+        let result = p.prompt(
+            Input::new("Name")
+                .with_live_validation(true)
+                .with_validation_debounce(Duration::from_secs(3600))
+                .with_validator(move |_: &String| {
+                    counter.set(counter.get() + 1);
+                    Ok::<_, ValidationError>(())
+                })
+                .as_mut(),
+        );
+        assert_eq!(result.unwrap(), "abc");
+
+        p.finish().unwrap();
+    }
+    // Once after the quiet period and once on submit.
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn test_mouse() {
+    let mouse = |kind, row| {
+        Event::Mouse(MouseEvent {
+            kind,
+            column: 4,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    // The fake terminal reports the cursor on row 20, below the 3 options rendered on rows 17-19.
+    let mut term = fake_term::Term::with_events(&[
+        mouse(MouseEventKind::Moved, 18),
+        mouse(MouseEventKind::Down(MouseButton::Left), 18),
+        mouse(MouseEventKind::ScrollDown, 0),
+        mouse(MouseEventKind::Down(MouseButton::Left), 16),
+        mouse(MouseEventKind::Down(MouseButton::Left), 19),
+        Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+    ]);
+    let mut theme = MinimalTheme::default();
+    let mut p = Promptuity::new(&mut term, &mut theme);
+    p.with_mouse(true).begin().unwrap();
+
+    let options = || {
+        ["a", "b", "c"]
+            .iter()
+            .map(|v| SelectOption::new(v.to_uppercase(), v.to_string()))
+            .collect::<Vec<_>>()
+    };
+    let result = p.prompt(Select::new("Select Message", options()).as_mut());
+    assert_eq!(result.unwrap(), "b");
+
+    let result = p.prompt(
+        MultiSelect::new(
+            "MultiSelect Message",
+            ["a", "b", "c"]
+                .iter()
+                .map(|v| MultiSelectOption::new(v.to_uppercase(), v.to_string()))
+                .collect(),
+        )
+        .with_required(false)
+        .as_mut(),
+    );
+    assert_eq!(result.unwrap(), vec!["c"]);
+
+    p.finish().unwrap();
+    drop(p);
+    assert_eq!(term.mouse_history(), &[true, false]);
+}
+
+#[test]
+fn test_resize() {
+    let mut term = fake_term::Term::with_events(&[
+        Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)),
+        Event::Resize(10, 40),
+        Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+    ]);
+    let mut theme = MinimalTheme::default();
+    let mut p = Promptuity::new(&mut term, &mut theme);
+    p.begin().unwrap();
+
+    let result = p.prompt(Input::new("Input Message").as_mut());
+    assert_eq!(result.unwrap(), "a");
+
+    drop(p);
+    // The message wraps at the new width, so one more line is erased after the resize.
+    assert_eq!(term.previous_line_history(), &[1, 2, 2]);
+}
@@ -1,8 +1,12 @@
 use promptuity::event::*;
-use promptuity::themes::{FancyTheme, MinimalTheme};
+use promptuity::prompts::{MultiSelect, MultiSelectOption};
+use promptuity::style::{Color, Symbol};
+use promptuity::themes::{FancySymbols, FancyTheme, MinimalTheme, PlainTheme, ThemePalette};
 use promptuity::{
-    InputCursor, Prompt, PromptBody, PromptInput, PromptState, Promptuity, RenderPayload, Theme,
+    Error, InputCursor, IntroMetadata, MiddlewareTheme, Prompt, PromptBody, PromptInput,
+    PromptState, Promptuity, RenderPayload, RenderSnapshot, Terminal, Theme, ThemeMiddleware,
 };
+use unicode_width::UnicodeWidthStr;
 
 mod fake_term;
 
@@ -22,6 +26,8 @@ struct DummyPrompt {
     body: DummyBodyKind,
     hint: bool,
     placeholder: bool,
+    description: bool,
+    validation_hint: bool,
 }
 
 impl Prompt for DummyPrompt {
@@ -56,9 +62,21 @@ impl Prompt for DummyPrompt {
             DummyBodyKind::None => PromptBody::None,
             DummyBodyKind::Raw => PromptBody::Raw("Raw1\nRaw2\nRaw3".into()),
         };
+        let description = if self.description {
+            Some("Description1\nDescription2".into())
+        } else {
+            None
+        };
+        let validation_hint = if self.validation_hint {
+            Some("Validation Message".into())
+        } else {
+            None
+        };
         Ok(RenderPayload::new("DummyPrompt".into(), hint, placeholder)
             .input(input)
-            .body(body))
+            .body(body)
+            .description(description)
+            .validation_hint(validation_hint))
     }
 }
 
@@ -78,6 +96,8 @@ fn run_theme(theme: &mut dyn Theme<Vec<u8>>) -> String {
             body: DummyBodyKind::None,
             hint: false,
             placeholder: false,
+            description: false,
+            validation_hint: false,
         });
         // Input::Raw + Body::None
         let _ = p.prompt(&mut DummyPrompt {
@@ -85,6 +105,8 @@ fn run_theme(theme: &mut dyn Theme<Vec<u8>>) -> String {
             body: DummyBodyKind::None,
             hint: false,
             placeholder: false,
+            description: false,
+            validation_hint: false,
         });
         // Input::Cursor + Body::None
         let _ = p.prompt(&mut DummyPrompt {
@@ -92,6 +114,8 @@ fn run_theme(theme: &mut dyn Theme<Vec<u8>>) -> String {
             body: DummyBodyKind::None,
             hint: false,
             placeholder: false,
+            description: false,
+            validation_hint: false,
         });
         // Input::Raw + Body::Raw
         let _ = p.prompt(&mut DummyPrompt {
@@ -99,6 +123,8 @@ fn run_theme(theme: &mut dyn Theme<Vec<u8>>) -> String {
             body: DummyBodyKind::Raw,
             hint: false,
             placeholder: false,
+            description: false,
+            validation_hint: false,
         });
         // Input::Cursor + Body::Raw
         let _ = p.prompt(&mut DummyPrompt {
@@ -106,6 +132,64 @@ fn run_theme(theme: &mut dyn Theme<Vec<u8>>) -> String {
             body: DummyBodyKind::Raw,
             hint: false,
             placeholder: false,
+            description: false,
+            validation_hint: false,
+        });
+    }
+    term.output()
+}
+
+fn run_finish(theme: &mut dyn Theme<Vec<u8>>, state: PromptState) -> String {
+    let mut term = fake_term::Term::new(&[]);
+    theme
+        .finish(&mut term, &state, Some("Outro Message".into()))
+        .unwrap();
+    term.output()
+}
+
+fn run_theme_hint(theme: &mut dyn Theme<Vec<u8>>) -> String {
+    let mut term = fake_term::Term::new(&[(KeyCode::Enter, KeyModifiers::NONE)]);
+    {
+        let mut p = Promptuity::new(&mut term, theme);
+        let _ = p.prompt(&mut DummyPrompt {
+            input: DummyInputKind::Cursor,
+            body: DummyBodyKind::None,
+            hint: true,
+            placeholder: false,
+            description: false,
+            validation_hint: false,
+        });
+    }
+    term.output()
+}
+
+fn run_theme_description(theme: &mut dyn Theme<Vec<u8>>) -> String {
+    let mut term = fake_term::Term::new(&[(KeyCode::Enter, KeyModifiers::NONE)]);
+    {
+        let mut p = Promptuity::new(&mut term, theme);
+        let _ = p.prompt(&mut DummyPrompt {
+            input: DummyInputKind::Cursor,
+            body: DummyBodyKind::Raw,
+            hint: false,
+            placeholder: false,
+            description: true,
+            validation_hint: false,
+        });
+    }
+    term.output()
+}
+
+fn run_theme_validation_hint(theme: &mut dyn Theme<Vec<u8>>) -> String {
+    let mut term = fake_term::Term::new(&[(KeyCode::Enter, KeyModifiers::NONE)]);
+    {
+        let mut p = Promptuity::new(&mut term, theme);
+        let _ = p.prompt(&mut DummyPrompt {
+            input: DummyInputKind::Cursor,
+            body: DummyBodyKind::None,
+            hint: false,
+            placeholder: false,
+            description: false,
+            validation_hint: true,
         });
     }
     term.output()
@@ -127,3 +211,425 @@ macro_rules! test_theme {
 
 test_theme!(test_theme_minimal, &mut MinimalTheme::default());
 test_theme!(test_theme_fancy, &mut FancyTheme::default());
+test_theme!(test_theme_plain, &mut PlainTheme::new());
+test_theme!(
+    test_theme_minimal_palette,
+    MinimalTheme::default().with_palette(ThemePalette {
+        active: Color::Magenta,
+        submit: Color::Blue,
+        muted: Color::Grey,
+        ..Default::default()
+    })
+);
+test_theme!(
+    test_theme_fancy_palette,
+    FancyTheme::default().with_palette(ThemePalette {
+        active: Color::Magenta,
+        submit: Color::Blue,
+        muted: Color::Grey,
+        ..Default::default()
+    })
+);
+test_theme!(
+    test_theme_fancy_symbols,
+    FancyTheme::default().with_symbols(FancySymbols {
+        bar: Symbol("┃", "|"),
+        bar_end: Symbol("┗", "-"),
+        step_submit: Symbol("✓", "v"),
+        ..Default::default()
+    })
+);
+
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! test_right_align_hint {
+    ($name: ident, $theme: expr) => {
+        #[test]
+        fn $name() {
+            let output = crate::run_theme_hint($theme);
+            let line = output
+                .lines()
+                .map(strip_ansi_escapes::strip_str)
+                .find(|line| line.contains("Hint Message"))
+                .unwrap();
+            assert!(line.ends_with("Hint Message") || line.ends_with("(Hint Message)"));
+            assert_eq!(line.chars().count(), 80);
+            insta::with_settings!({ omit_expression => true }, {
+                insta::assert_snapshot!(output);
+            });
+        }
+    };
+}
+
+test_right_align_hint!(
+    test_right_align_hint_minimal,
+    MinimalTheme::default().with_right_align_hint(true)
+);
+test_right_align_hint!(
+    test_right_align_hint_fancy,
+    FancyTheme::default().with_right_align_hint(true)
+);
+
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! test_description {
+    ($name: ident, $theme: expr) => {
+        #[test]
+        fn $name() {
+            let output = crate::run_theme_description($theme);
+            insta::with_settings!({ omit_expression => true }, {
+                insta::assert_snapshot!(output);
+            });
+        }
+    };
+}
+
+test_description!(test_description_minimal, &mut MinimalTheme::default());
+test_description!(test_description_fancy, &mut FancyTheme::default());
+test_description!(test_description_plain, &mut PlainTheme::new());
+
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! test_validation_hint {
+    ($name: ident, $theme: expr) => {
+        #[test]
+        fn $name() {
+            let output = crate::run_theme_validation_hint($theme);
+            insta::with_settings!({ omit_expression => true }, {
+                insta::assert_snapshot!(output);
+            });
+        }
+    };
+}
+
+test_validation_hint!(test_validation_hint_minimal, &mut MinimalTheme::default());
+test_validation_hint!(test_validation_hint_fancy, &mut FancyTheme::default());
+
+fn run_page_indicators(theme: &mut dyn Theme<Vec<u8>>) -> String {
+    let mut term = fake_term::Term::new(&[
+        (KeyCode::Down, KeyModifiers::NONE),
+        (KeyCode::Down, KeyModifiers::NONE),
+        (KeyCode::Down, KeyModifiers::NONE),
+        (KeyCode::Down, KeyModifiers::NONE),
+        (KeyCode::Char(' '), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ]);
+    {
+        let mut p = Promptuity::new(&mut term, theme);
+        let options = (1..=10)
+            .map(|i| MultiSelectOption::new(format!("Value{}", i), i))
+            .collect::<Vec<_>>();
+        let _ = p.prompt(MultiSelect::new("MultiSelect", options).with_page_size(5));
+    }
+    term.output()
+}
+
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! test_page_indicators {
+    ($name: ident, $theme: expr) => {
+        #[test]
+        fn $name() {
+            let output = crate::run_page_indicators($theme);
+            insta::with_settings!({ omit_expression => true }, {
+                insta::assert_snapshot!(output);
+            });
+        }
+    };
+}
+
+test_page_indicators!(test_page_indicators_minimal, &mut MinimalTheme::default());
+test_page_indicators!(test_page_indicators_fancy, &mut FancyTheme::default());
+
+fn run_step_hint(theme: &mut dyn Theme<Vec<u8>>) -> String {
+    let mut term = fake_term::Term::new(&[]);
+    theme.step(&mut term, "Step Message".into()).unwrap();
+    theme
+        .step_with_hint(&mut term, "Step Message".into(), Some("Step Hint".into()))
+        .unwrap();
+    term.output()
+}
+
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! test_step_hint {
+    ($name: ident, $theme: expr) => {
+        #[test]
+        fn $name() {
+            let output = crate::run_step_hint($theme);
+            let lines = output
+                .lines()
+                .map(strip_ansi_escapes::strip_str)
+                .collect::<Vec<_>>();
+            assert!(!lines[0].contains("Step Hint"));
+            assert!(lines[1].contains("Step Message") && lines[1].contains("Step Hint"));
+            insta::with_settings!({ omit_expression => true }, {
+                insta::assert_snapshot!(output);
+            });
+        }
+    };
+}
+
+test_step_hint!(test_step_hint_minimal, &mut MinimalTheme::default());
+test_step_hint!(test_step_hint_fancy, &mut FancyTheme::default());
+test_step_hint!(test_step_hint_plain, &mut PlainTheme::new());
+
+fn run_spinner(theme: &mut dyn Theme<Vec<u8>>) -> String {
+    let mut term = fake_term::Term::new(&[]);
+    {
+        let mut p = Promptuity::new(&mut term, theme);
+        let mut spinner = p.spinner("Spinner Message").unwrap();
+        spinner.tick().unwrap();
+        spinner.set_message("Updated Message").unwrap();
+        spinner.finish("Finish Message").unwrap();
+
+        let mut spinner = p.spinner("Spinner Message").unwrap();
+        spinner.tick().unwrap();
+        spinner.fail("Fail Message").unwrap();
+    }
+    term.output()
+}
+
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! test_spinner {
+    ($name: ident, $theme: expr) => {
+        #[test]
+        fn $name() {
+            let output = crate::run_spinner($theme);
+            insta::with_settings!({ omit_expression => true }, {
+                insta::assert_snapshot!(output);
+            });
+        }
+    };
+}
+
+test_spinner!(test_spinner_minimal, &mut MinimalTheme::default());
+test_spinner!(test_spinner_fancy, &mut FancyTheme::default());
+test_spinner!(test_spinner_plain, &mut PlainTheme::new());
+
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! test_finish {
+    ($name: ident, $theme: expr, $state: expr) => {
+        #[test]
+        fn $name() {
+            let output = crate::run_finish($theme, $state);
+            insta::with_settings!({ omit_expression => true }, {
+                insta::assert_snapshot!(output);
+            });
+        }
+    };
+}
+
+test_finish!(
+    test_finish_minimal_submit,
+    &mut MinimalTheme::default(),
+    PromptState::Submit
+);
+test_finish!(
+    test_finish_minimal_cancel,
+    &mut MinimalTheme::default(),
+    PromptState::Cancel
+);
+test_finish!(
+    test_finish_plain_submit,
+    &mut PlainTheme::new(),
+    PromptState::Submit
+);
+test_finish!(
+    test_finish_plain_cancel,
+    &mut PlainTheme::new(),
+    PromptState::Cancel
+);
+
+#[test]
+fn test_render_snapshot_state() {
+    let snapshot = |state| RenderSnapshot {
+        state,
+        message: String::new(),
+        hint: None,
+        placeholder: None,
+        input: PromptInput::None,
+        body: PromptBody::None,
+        icon: None,
+        description: None,
+        validation_hint: None,
+        page_indicator_before: None,
+        page_indicator_after: None,
+        back: false,
+    };
+
+    let active = snapshot(&PromptState::Active);
+    assert!(active.is_active());
+    assert!(!active.is_submitted());
+    assert!(!active.is_cancelled());
+    assert_eq!(active.error_message(), None);
+
+    let submit = snapshot(&PromptState::Submit);
+    assert!(!submit.is_active());
+    assert!(submit.is_submitted());
+    assert!(!submit.is_cancelled());
+    assert_eq!(submit.error_message(), None);
+
+    let cancel = snapshot(&PromptState::Cancel);
+    assert!(!cancel.is_active());
+    assert!(!cancel.is_submitted());
+    assert!(cancel.is_cancelled());
+    assert_eq!(cancel.error_message(), None);
+
+    let error_state = PromptState::Error("Error Message".into());
+    let error = snapshot(&error_state);
+    assert!(!error.is_active());
+    assert_eq!(error.error_message(), Some("Error Message"));
+
+    let fatal_state = PromptState::Fatal("Fatal Message".into());
+    let fatal = snapshot(&fatal_state);
+    assert_eq!(fatal.error_message(), Some("Fatal Message"));
+}
+
+#[derive(Default)]
+struct TimingMiddleware {
+    renders: Vec<std::time::Duration>,
+}
+
+impl<W: std::io::Write> ThemeMiddleware<W> for TimingMiddleware {
+    fn render(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        payload: RenderSnapshot,
+    ) -> Result<(), Error> {
+        let start = std::time::Instant::now();
+        theme.render(term, payload)?;
+        self.renders.push(start.elapsed());
+        Ok(())
+    }
+}
+
+struct PrefixMiddleware(&'static str);
+
+impl<W: std::io::Write> ThemeMiddleware<W> for PrefixMiddleware {
+    fn log(
+        &mut self,
+        theme: &mut dyn Theme<W>,
+        term: &mut dyn Terminal<W>,
+        message: String,
+    ) -> Result<(), Error> {
+        theme.log(term, format!("{}{}", self.0, message))
+    }
+}
+
+#[test]
+fn test_middleware_timing() {
+    let mut theme = MiddlewareTheme::new(MinimalTheme::default(), TimingMiddleware::default());
+    let output = run_theme(&mut theme);
+
+    // 5 prompts, each rendered once as active and once as submitted.
+    assert_eq!(theme.middleware().renders.len(), 10);
+    assert_eq!(output, run_theme(&mut MinimalTheme::default()));
+}
+
+#[test]
+fn test_middleware_chain() {
+    let mut theme = MiddlewareTheme::new(MinimalTheme::default(), PrefixMiddleware("[inner] "))
+        .wrap(PrefixMiddleware("[outer] "))
+        .wrap(TimingMiddleware::default());
+    let mut term = fake_term::Term::new(&[]);
+    theme.log(&mut term, "Message".into()).unwrap();
+
+    assert_eq!(term.output(), "[inner] [outer] Message\n");
+    assert!(theme.middleware().renders.is_empty());
+}
+
+#[test]
+fn test_begin_fancy_metadata() {
+    let mut term = fake_term::Term::new(&[]);
+    let mut theme = FancyTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_intro_metadata(
+            IntroMetadata::new("Intro Title")
+                .with_version("1.2.3")
+                .with_author("Author Name"),
+        )
+        .begin()
+        .unwrap();
+    }
+    insta::with_settings!({ omit_expression => true }, {
+        insta::assert_snapshot!(term.output());
+    });
+}
+
+#[test]
+fn test_fancy_wrap_long_messages() {
+    let long = |label: &str| format!("{} {}", label, "lorem ipsum dolor sit amet ".repeat(4));
+    let mut term = fake_term::Term::new(&[(KeyCode::Enter, KeyModifiers::NONE)]);
+    let mut theme = FancyTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.with_intro(long("Intro")).begin().unwrap();
+        let _ = p.prompt(
+            promptuity::prompts::Confirm::new(long("Message"))
+                .with_hint("Hint Message")
+                .with_default(true),
+        );
+        p.with_outro(long("Outro")).finish().unwrap();
+    }
+    insta::with_settings!({ omit_expression => true }, {
+        insta::assert_snapshot!(term.output());
+    });
+}
+
+#[test]
+fn test_log_kvs_alignment() {
+    let mut term = fake_term::Term::new(&[]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.log_kvs(&[
+            ("File", "main.rs"),
+            ("Size", "1.2 KB"),
+            ("Modified at", "2024-01-01"),
+            ("名前", "日本語のファイル"),
+        ])
+        .unwrap();
+    }
+
+    let output = strip_ansi_escapes::strip_str(term.output());
+    let columns = output
+        .lines()
+        .map(|line| {
+            let (key, value) = line.split_at(line.find("  ").unwrap());
+            let padding = value.len() - value.trim_start().len();
+            (key.width() + padding, value.trim_start().to_string())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        columns,
+        vec![
+            (13, "main.rs".into()),
+            (13, "1.2 KB".into()),
+            (13, "2024-01-01".into()),
+            (13, "日本語のファイル".into()),
+        ]
+    );
+}
+
+#[test]
+fn test_log_kv_fixed_width() {
+    let mut term = fake_term::Term::new(&[]);
+    let mut theme = MinimalTheme::default();
+    {
+        let mut p = Promptuity::new(&mut term, &mut theme);
+        p.log_kv("Key", "Value").unwrap();
+        p.log_kv("全角", "Value").unwrap();
+    }
+
+    let output = strip_ansi_escapes::strip_str(term.output());
+    for line in output.lines() {
+        let value = line.rfind("Value").unwrap();
+        assert_eq!(line[..value].width(), 22);
+    }
+}